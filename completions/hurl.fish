@@ -11,6 +11,7 @@ complete -c hurl -l cookie -d 'Read cookies from FILE'
 complete -c hurl -l cookie-jar -d 'Write cookies to FILE after running the session (only for one session)'
 complete -c hurl -l curl -d 'Export each request to a list of curl commands'
 complete -c hurl -l delay -d 'Sets delay before each request'
+complete -c hurl -l empty-body-as-null -d 'Evaluate an empty response body as JSON null in JSONPath queries'
 complete -c hurl -l error-format -d 'Control the format of error messages'
 complete -c hurl -l file-root -d 'Set root directory to import files [default: input file directory]'
 complete -c hurl -l location -d 'Follow redirects'
@@ -22,6 +23,7 @@ complete -c hurl -l http1.1 -d 'Tell Hurl to use HTTP version 1.1'
 complete -c hurl -l http2 -d 'Tell Hurl to use HTTP version 2'
 complete -c hurl -l http3 -d 'Tell Hurl to use HTTP version 3'
 complete -c hurl -l ignore-asserts -d 'Ignore asserts defined in the Hurl file'
+complete -c hurl -l ignore-header-case -d 'Compare header values ignoring ASCII case in implicit asserts'
 complete -c hurl -l include -d 'Include the HTTP headers in the output'
 complete -c hurl -l insecure -d 'Allow insecure SSL connections'
 complete -c hurl -l interactive -d 'Turn on interactive mode'
@@ -29,6 +31,7 @@ complete -c hurl -l ipv4 -d 'Tell Hurl to use IPv4 addresses only when resolving
 complete -c hurl -l ipv6 -d 'Tell Hurl to use IPv6 addresses only when resolving host names, and not for example try IPv4'
 complete -c hurl -l jobs -d 'Maximum number of parallel jobs'
 complete -c hurl -l json -d 'Output each Hurl file result to JSON'
+complete -c hurl -l json-float-precision -d 'Compare floats in JSON body asserts up to DIGITS decimal places'
 complete -c hurl -l limit-rate -d 'Specify the maximum transfer rate in bytes/second, for both downloads and uploads'
 complete -c hurl -l max-filesize -d 'Specify the maximum size in bytes of a file to download'
 complete -c hurl -l max-redirs -d 'Maximum number of redirects allowed, -1 for unlimited redirects'
@@ -45,6 +48,7 @@ complete -c hurl -l path-as-is -d 'Tell Hurl to not handle sequences of /../ or
 complete -c hurl -l proxy -d 'Use proxy on given PROTOCOL/HOST/PORT'
 complete -c hurl -l repeat -d 'Repeat the input files sequence NUM times, -1 for infinite loop'
 complete -c hurl -l report-html -d 'Generate HTML report to DIR'
+complete -c hurl -l report-include-success-bodies -d 'Include response bodies of successful entries in the JSON report'
 complete -c hurl -l report-json -d 'Generate JSON report to DIR'
 complete -c hurl -l report-junit -d 'Write a JUnit XML report to FILE'
 complete -c hurl -l report-tap -d 'Write a TAP report to FILE'
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --location --max-redirs 3 tests_failed/max_redirect_loop.hurl
@@ -0,0 +1,7 @@
+from app import app
+from flask import redirect
+
+
+@app.route("/redirect-loop", methods=["GET"])
+def redirect_loop():
+    return redirect("http://localhost:8000/redirect-loop")
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --location --max-redirs 3 tests_failed/max_redirect_loop.hurl
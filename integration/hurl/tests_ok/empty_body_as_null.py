@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/empty_body_as_null")
+def empty_body_as_null():
+    return ""
@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/json_float_precision")
+def json_float_precision():
+    return Response(
+        '{"total":0.30000000000000004,"count":2,"items":[0.1,0.2]}',
+        mimetype="application/json",
+    )
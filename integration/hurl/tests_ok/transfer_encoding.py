@@ -0,0 +1,17 @@
+from app import app
+from flask import Response
+
+
+@app.route("/transfer-encoding/chunked")
+def transfer_encoding_chunked():
+    def stream():
+        yield "Hello "
+        yield "World!"
+
+    # No Content-Length: the body is sent with chunked transfer.
+    return Response(stream(), mimetype="text/plain")
+
+
+@app.route("/transfer-encoding/none")
+def transfer_encoding_none():
+    return "Hello World!"
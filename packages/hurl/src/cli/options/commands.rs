@@ -147,6 +147,14 @@ pub fn delay() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn empty_body_as_null() -> clap::Arg {
+    clap::Arg::new("empty_body_as_null")
+        .long("empty-body-as-null")
+        .help("Evaluate an empty response body as JSON null in JSONPath queries")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn error_format() -> clap::Arg {
     clap::Arg::new("error_format")
         .long("error-format")
@@ -246,6 +254,14 @@ pub fn ignore_asserts() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn ignore_header_case() -> clap::Arg {
+    clap::Arg::new("ignore_header_case")
+        .long("ignore-header-case")
+        .help("Compare header values ignoring ASCII case in implicit asserts")
+        .help_heading("Run options")
+        .action(clap::ArgAction::SetTrue)
+}
+
 pub fn include() -> clap::Arg {
     clap::Arg::new("include")
         .long("include")
@@ -310,6 +326,16 @@ pub fn json() -> clap::Arg {
         .action(clap::ArgAction::SetTrue)
 }
 
+pub fn json_float_precision() -> clap::Arg {
+    clap::Arg::new("json_float_precision")
+        .long("json-float-precision")
+        .value_name("DIGITS")
+        .value_parser(clap::value_parser!(u32).range(..=15))
+        .help("Compare floats in JSON body asserts up to DIGITS decimal places")
+        .help_heading("Run options")
+        .num_args(1)
+}
+
 pub fn limit_rate() -> clap::Arg {
     clap::Arg::new("limit_rate")
         .long("limit-rate")
@@ -466,6 +492,17 @@ pub fn report_html() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_include_success_bodies() -> clap::Arg {
+    clap::Arg::new("report_include_success_bodies")
+        .long("report-include-success-bodies")
+        .value_name("BOOL")
+        .default_value("true")
+        .value_parser(clap::value_parser!(bool))
+        .help("Include response bodies of successful entries in the JSON report")
+        .help_heading("Report options")
+        .num_args(1)
+}
+
 pub fn report_json() -> clap::Arg {
     clap::Arg::new("report_json")
         .long("report-json")
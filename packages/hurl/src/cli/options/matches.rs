@@ -23,7 +23,7 @@ use std::time::Duration;
 use std::{env, fs, io};
 
 use clap::ArgMatches;
-use hurl::runner::Value;
+use hurl::runner::{parse_variables, Value, VariablesFormat};
 use hurl_core::input::Input;
 use hurl_core::typing::{BytesPerSec, Count, DurationUnit};
 
@@ -131,6 +131,10 @@ pub fn delay(arg_matches: &ArgMatches) -> Result<Duration, CliOptionsError> {
     get_duration(&s, DurationUnit::MilliSecond)
 }
 
+pub fn empty_body_as_null(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "empty_body_as_null")
+}
+
 pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
     let error_format = get::<String>(arg_matches, "error_format");
     match error_format.as_deref() {
@@ -197,6 +201,10 @@ pub fn ignore_asserts(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "ignore_asserts")
 }
 
+pub fn ignore_header_case(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "ignore_header_case")
+}
+
 pub fn include(arg_matches: &ArgMatches) -> bool {
     has_flag(arg_matches, "include")
 }
@@ -299,6 +307,10 @@ pub fn jobs(arg_matches: &ArgMatches) -> Option<usize> {
     get::<u32>(arg_matches, "jobs").map(|m| m as usize)
 }
 
+pub fn json_float_precision(arg_matches: &ArgMatches) -> Option<u32> {
+    get::<u32>(arg_matches, "json_float_precision")
+}
+
 pub fn json_report_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, CliOptionsError> {
     if let Some(dir) = get::<String>(arg_matches, "report_json") {
         let path = Path::new(&dir);
@@ -387,6 +399,10 @@ pub fn repeat(arg_matches: &ArgMatches) -> Option<Count> {
     }
 }
 
+pub fn report_include_success_bodies(arg_matches: &ArgMatches) -> bool {
+    get::<bool>(arg_matches, "report_include_success_bodies").unwrap_or(true)
+}
+
 pub fn resolves(arg_matches: &ArgMatches) -> Vec<String> {
     get_strings(arg_matches, "resolve").unwrap_or_default()
 }
@@ -459,6 +475,26 @@ pub fn variables(matches: &ArgMatches) -> Result<HashMap<String, Value>, CliOpti
                 )));
             }
 
+            // JSON and TOML variables files keep the type of their values (numbers, booleans,
+            // lists etc...)
+            if let Some(format) = VariablesFormat::from_path(path) {
+                let content = match fs::read_to_string(path) {
+                    Ok(s) => s,
+                    Err(_) => {
+                        return Err(CliOptionsError::Error(format!(
+                            "Can not read {}",
+                            path.display()
+                        )))
+                    }
+                };
+                let file_variables = match parse_variables(&content, format) {
+                    Ok(v) => v,
+                    Err(e) => return Err(CliOptionsError::Error(e.to_string())),
+                };
+                variables.extend(file_variables);
+                continue;
+            }
+
             let file = File::open(path).unwrap();
             let reader = BufReader::new(file);
             for (index, line) in reader.lines().enumerate() {
@@ -56,6 +56,7 @@ pub struct CliOptions {
     pub cookie_output_file: Option<PathBuf>,
     pub curl_file: Option<PathBuf>,
     pub delay: Duration,
+    pub empty_body_as_null: bool,
     pub error_format: ErrorFormat,
     pub file_root: Option<String>,
     pub follow_location: bool,
@@ -64,12 +65,14 @@ pub struct CliOptions {
     pub html_dir: Option<PathBuf>,
     pub http_version: Option<HttpVersion>,
     pub ignore_asserts: bool,
+    pub ignore_header_case: bool,
     pub include: bool,
     pub input_files: Vec<Input>,
     pub insecure: bool,
     pub interactive: bool,
     pub ip_resolve: Option<IpResolve>,
     pub jobs: Option<usize>,
+    pub json_float_precision: Option<u32>,
     pub json_report_dir: Option<PathBuf>,
     pub junit_file: Option<PathBuf>,
     pub limit_rate: Option<BytesPerSec>,
@@ -86,6 +89,7 @@ pub struct CliOptions {
     pub progress_bar: bool,
     pub proxy: Option<String>,
     pub repeat: Option<Count>,
+    pub report_include_success_bodies: bool,
     pub resolves: Vec<String>,
     pub retry: Option<Count>,
     pub retry_interval: Duration,
@@ -214,10 +218,13 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         // Run options
         .arg(commands::continue_on_error())
         .arg(commands::delay())
+        .arg(commands::empty_body_as_null())
         .arg(commands::from_entry())
         .arg(commands::ignore_asserts())
+        .arg(commands::ignore_header_case())
         .arg(commands::interactive())
         .arg(commands::jobs())
+        .arg(commands::json_float_precision())
         .arg(commands::parallel())
         .arg(commands::repeat())
         .arg(commands::retry())
@@ -228,6 +235,7 @@ pub fn parse() -> Result<CliOptions, CliOptionsError> {
         .arg(commands::variables_file())
         // Report options
         .arg(commands::report_html())
+        .arg(commands::report_include_success_bodies())
         .arg(commands::report_json())
         .arg(commands::report_junit())
         .arg(commands::report_tap())
@@ -278,6 +286,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let cookie_output_file = matches::cookie_output_file(arg_matches);
     let curl_file = matches::curl_file(arg_matches);
     let delay = matches::delay(arg_matches)?;
+    let empty_body_as_null = matches::empty_body_as_null(arg_matches);
     let error_format = matches::error_format(arg_matches);
     let file_root = matches::file_root(arg_matches);
     let (follow_location, follow_location_trusted) = matches::follow_location(arg_matches);
@@ -285,11 +294,13 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let html_dir = matches::html_dir(arg_matches)?;
     let http_version = matches::http_version(arg_matches);
     let ignore_asserts = matches::ignore_asserts(arg_matches);
+    let ignore_header_case = matches::ignore_header_case(arg_matches);
     let include = matches::include(arg_matches);
     let input_files = matches::input_files(arg_matches)?;
     let insecure = matches::insecure(arg_matches);
     let interactive = matches::interactive(arg_matches);
     let ip_resolve = matches::ip_resolve(arg_matches);
+    let json_float_precision = matches::json_float_precision(arg_matches);
     let json_report_dir = matches::json_report_dir(arg_matches)?;
     let junit_file = matches::junit_file(arg_matches);
     let limit_rate = matches::limit_rate(arg_matches);
@@ -306,6 +317,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
     let output = matches::output(arg_matches);
     let output_type = matches::output_type(arg_matches);
     let repeat = matches::repeat(arg_matches);
+    let report_include_success_bodies = matches::report_include_success_bodies(arg_matches);
     let resolves = matches::resolves(arg_matches);
     let retry = matches::retry(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches)?;
@@ -335,6 +347,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         cookie_output_file,
         curl_file,
         delay,
+        empty_body_as_null,
         error_format,
         file_root,
         follow_location,
@@ -343,11 +356,13 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         html_dir,
         http_version,
         ignore_asserts,
+        ignore_header_case,
         include,
         input_files,
         insecure,
         interactive,
         ip_resolve,
+        json_float_precision,
         json_report_dir,
         junit_file,
         limit_rate,
@@ -364,6 +379,7 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<CliOptions, CliOptionsError
         output,
         output_type,
         repeat,
+        report_include_success_bodies,
         resolves,
         retry,
         retry_interval,
@@ -405,13 +421,14 @@ impl CliOptions {
             Some(f) => Path::new(f),
             None => match filename.kind() {
                 InputKind::File(path) => path.parent().unwrap(),
-                InputKind::Stdin(_) => current_dir,
+                InputKind::Stdin(_) | InputKind::Memory { .. } => current_dir,
             },
         };
         let context_dir = ContextDir::new(current_dir, file_root);
         let continue_on_error = self.continue_on_error;
         let cookie_input_file = self.cookie_input_file.clone();
         let delay = self.delay;
+        let empty_body_as_null = self.empty_body_as_null;
         let follow_location = self.follow_location;
         let follow_location_trusted = self.follow_location_trusted;
         let from_entry = self.from_entry;
@@ -420,11 +437,13 @@ impl CliOptions {
             None => RequestedHttpVersion::default(),
         };
         let ignore_asserts = self.ignore_asserts;
+        let ignore_header_case = self.ignore_header_case;
         let insecure = self.insecure;
         let ip_resolve = match self.ip_resolve {
             Some(ip) => ip.into(),
             None => http::IpResolve::default(),
         };
+        let json_float_precision = self.json_float_precision;
         let max_filesize = self.max_filesize;
         // Like curl, we don't differentiate upload and download limit rate, we have
         // only one option.
@@ -464,6 +483,7 @@ impl CliOptions {
             .client_cert_file(client_cert_file)
             .client_key_file(client_key_file)
             .delay(delay)
+            .empty_body_as_null(empty_body_as_null)
             .compressed(compressed)
             .connect_timeout(connect_timeout)
             .connects_to(&connects_to)
@@ -475,8 +495,10 @@ impl CliOptions {
             .from_entry(from_entry)
             .http_version(http_version)
             .ignore_asserts(ignore_asserts)
+            .ignore_header_case(ignore_header_case)
             .insecure(insecure)
             .ip_resolve(ip_resolve)
+            .json_float_precision(json_float_precision)
             .max_filesize(max_filesize)
             .max_recv_speed(max_recv_speed)
             .max_redirect(max_redirect)
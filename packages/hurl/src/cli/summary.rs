@@ -81,6 +81,7 @@ pub mod tests {
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                warnings: vec![],
             };
             HurlRun {
                 content: String::new(),
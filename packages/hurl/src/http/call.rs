@@ -28,4 +28,17 @@ pub struct Call {
     pub response: Response,
     /// Timings of the exchange, see <https://hurl.dev/docs/response.html#timings>
     pub timings: Timings,
+    /// HTTP/2 frames sent and received during the exchange, only traced in very verbose mode
+    pub h2_frames: Vec<H2Frame>,
+}
+
+/// An HTTP/2 frame, as traced by libcurl.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct H2Frame {
+    /// Type of the frame (ex: `HEADERS`, `DATA`), or its numeric value for unknown types
+    pub frame_type: String,
+    /// Identifier of the stream of the frame, `0` for the connection control frames
+    pub stream_id: u32,
+    /// Length of the frame payload, if traced by libcurl
+    pub length: Option<u32>,
 }
@@ -39,7 +39,7 @@ use crate::http::options::ClientOptions;
 use crate::http::timings::Timings;
 use crate::http::url::Url;
 use crate::http::{
-    easy_ext, Call, Cookie, FileParam, Header, HttpError, HttpVersion, IpResolve, Method,
+    easy_ext, Call, Cookie, FileParam, H2Frame, Header, HttpError, HttpVersion, IpResolve, Method,
     MultipartParam, Param, Request, RequestCookie, RequestSpec, RequestedHttpVersion, Response,
     Verbosity,
 };
@@ -62,6 +62,8 @@ pub struct Client {
     http3: bool,
     /// Certificates cache to get SSL certificates on reused libcurl connections.
     certificates: HashMap<i64, Certificate>,
+    /// TLS versions cache to get negotiated TLS versions on reused libcurl connections.
+    tls_versions: HashMap<i64, String>,
 }
 
 /// Represents the state of the HTTP client.
@@ -100,6 +102,7 @@ impl Client {
             http2: version.feature_http2(),
             http3: version.feature_http3(),
             certificates: HashMap::new(),
+            tls_versions: HashMap::new(),
         }
     }
 
@@ -140,7 +143,10 @@ impl Client {
             redirect_count += 1;
             if let Count::Finite(max_redirect) = options.max_redirect {
                 if redirect_count > max_redirect {
-                    return Err(HttpError::TooManyRedirect);
+                    return Err(HttpError::TooManyRedirect {
+                        count: max_redirect,
+                        url: request_url.to_string(),
+                    });
                 }
             };
 
@@ -195,6 +201,13 @@ impl Client {
         // of key-value.
         let mut request_body = Vec::<u8>::new();
         let mut response_body = Vec::<u8>::new();
+        let mut tls_version = None;
+        let mut h2_frames = vec![];
+
+        // HTTP/2 frames are only traced in very verbose mode, to avoid the cost of libcurl traces.
+        if very_verbose {
+            easy_ext::trace_http2_frames();
+        }
 
         {
             let mut transfer = self.handle.transfer();
@@ -241,6 +254,14 @@ impl Client {
                 }
                 // Curl debug logs
                 easy::InfoType::Text => {
+                    if let Some(version) = parse_tls_version(data) {
+                        tls_version = Some(version);
+                    }
+                    if very_verbose {
+                        if let Some(frame) = parse_h2_frame(data) {
+                            h2_frames.push(frame);
+                        }
+                    }
                     let len = data.len();
                     if very_verbose && len > 0 {
                         let text = str::from_utf8(&data[..len - 1]);
@@ -300,6 +321,7 @@ impl Client {
         let length = response_body.len();
 
         let certificate = self.cert_info(logger)?;
+        let tls_version = self.tls_version(tls_version);
         let duration = start.elapsed();
         let stop_dt = start_dt + duration;
         let timings = Timings::new(&mut self.handle, start_dt, stop_dt);
@@ -319,6 +341,7 @@ impl Client {
             duration,
             url,
             certificate,
+            tls_version,
         );
 
         if verbose {
@@ -357,6 +380,7 @@ impl Client {
             request,
             response,
             timings,
+            h2_frames,
         })
     }
 
@@ -804,6 +828,24 @@ impl Client {
             }
         }
     }
+
+    /// Returns the TLS version negotiated for this call, given the `tls_version` logged by libcurl
+    /// during the TLS handshake.
+    ///
+    /// As libcurl doesn't perform a new handshake on reused connections, TLS versions are cached
+    /// by connection id, like certificates.
+    fn tls_version(&mut self, tls_version: Option<String>) -> Option<String> {
+        let conn_id = easy_ext::conn_id(&self.handle).ok();
+        match (tls_version, conn_id) {
+            (Some(version), Some(conn_id)) => {
+                self.tls_versions.insert(conn_id, version.clone());
+                Some(version)
+            }
+            (Some(version), None) => Some(version),
+            (None, Some(conn_id)) => self.tls_versions.get(&conn_id).cloned(),
+            (None, None) => None,
+        }
+    }
 }
 
 /// Returns the method used for redirecting a request/response with `response_status`.
@@ -862,6 +904,45 @@ impl Header {
     }
 }
 
+/// Parses the TLS version from a libcurl debug `data` text, logged at the end of a TLS handshake.
+///
+/// For instance, libcurl logs `SSL connection using TLSv1.3 / TLS_AES_256_GCM_SHA384 / ...` on a
+/// new TLS connection.
+fn parse_tls_version(data: &[u8]) -> Option<String> {
+    let text = str::from_utf8(data).ok()?;
+    let text = text.trim_end().strip_prefix("SSL connection using ")?;
+    let version = text.split(" / ").next()?.trim();
+    if version.is_empty() {
+        return None;
+    }
+    Some(version.to_string())
+}
+
+/// Parses an HTTP/2 frame from a libcurl debug `data` text, logged when a frame is sent or received.
+///
+/// For instance, libcurl logs `[HTTP/2] [1] <- FRAME[HEADERS, len=47, hend=1, eos=0]` when
+/// receiving the headers of the stream 1. Some frames have no length, like
+/// `[HTTP/2] [0] -> FRAME[WINDOW_UPDATE, incr=1048510]`.
+fn parse_h2_frame(data: &[u8]) -> Option<H2Frame> {
+    let text = str::from_utf8(data).ok()?.trim_end();
+    let (prefix, frame) = text.split_once("FRAME[")?;
+    let prefix = prefix
+        .strip_suffix("<- ")
+        .or_else(|| prefix.strip_suffix("-> "))?;
+    let (_, stream_id) = prefix.trim_end().strip_suffix(']')?.rsplit_once('[')?;
+    let stream_id = stream_id.parse().ok()?;
+    let mut fields = frame.strip_suffix(']')?.split(", ");
+    let frame_type = fields.next()?.to_string();
+    let length = fields
+        .find_map(|field| field.strip_prefix("len="))
+        .and_then(|length| length.parse().ok());
+    Some(H2Frame {
+        frame_type,
+        stream_id,
+        length,
+    })
+}
+
 /// Splits an array of bytes into HTTP lines (\r\n separator).
 fn split_lines(data: &[u8]) -> Vec<String> {
     let mut lines = vec![];
@@ -990,6 +1071,66 @@ mod tests {
         assert!(Header::parse("Foo").is_none());
     }
 
+    #[test]
+    fn test_parse_tls_version() {
+        assert_eq!(
+            parse_tls_version(
+                b"SSL connection using TLSv1.3 / TLS_AES_256_GCM_SHA384 / X25519 / RSASSA-PSS\n"
+            ),
+            Some("TLSv1.3".to_string())
+        );
+        assert_eq!(
+            parse_tls_version(b"SSL connection using TLSv1.1 / ECDHE-RSA-AES256-SHA\n"),
+            Some("TLSv1.1".to_string())
+        );
+        assert_eq!(
+            parse_tls_version(b"Connected to localhost (::1) port 8000\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_h2_frame() {
+        // Traces of a GET request over HTTP/2, logged by libcurl.
+        let traces: &[&[u8]] = &[
+            b"[HTTP/2] [0] created h2 session\n",
+            b"[HTTP/2] [0] -> FRAME[SETTINGS, len=18]\n",
+            b"[HTTP/2] [0] -> FRAME[WINDOW_UPDATE, incr=1048510]\n",
+            b"[HTTP/2] [1] OPENED stream for https://localhost:8001/hello\n",
+            b"[HTTP/2] [1] -> FRAME[HEADERS, len=33, hend=1, eos=1]\n",
+            b"[HTTP/2] [0] <- FRAME[SETTINGS, ack=1]\n",
+            b"[HTTP/2] [1] <- FRAME[HEADERS, len=47, hend=1, eos=0]\n",
+            b"[HTTP/2] [1] <- FRAME[DATA, len=13, eos=1, padlen=0]\n",
+            b"Connection #0 to host localhost left intact\n",
+        ];
+        let frames = traces
+            .iter()
+            .filter_map(|data| parse_h2_frame(data))
+            .collect::<Vec<_>>();
+        let frame = |frame_type: &str, stream_id: u32, length: Option<u32>| H2Frame {
+            frame_type: frame_type.to_string(),
+            stream_id,
+            length,
+        };
+        assert_eq!(
+            frames,
+            vec![
+                frame("SETTINGS", 0, Some(18)),
+                frame("WINDOW_UPDATE", 0, None),
+                frame("HEADERS", 1, Some(33)),
+                frame("SETTINGS", 0, None),
+                frame("HEADERS", 1, Some(47)),
+                frame("DATA", 1, Some(13)),
+            ]
+        );
+
+        // Frames of unknown types are traced with their numeric type.
+        assert_eq!(
+            parse_h2_frame(b"[HTTP/2] [3] <- FRAME[16, len=4, flags=0]\n"),
+            Some(frame("16", 3, Some(4)))
+        );
+    }
+
     #[test]
     fn test_split_lines_header() {
         let data = b"GET /hello HTTP/1.1\r\nHost: localhost:8000\r\n\r\n";
@@ -16,6 +16,8 @@
  *
  */
 
+use chrono::{DateTime, NaiveDateTime, Utc};
+
 ///
 /// This module defines a HTTP ResponseCookie,
 /// namely the cookie returned from the response Set-Cookie header
@@ -54,6 +56,15 @@ const SAME_SITE: &str = "SameSite";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie#secure>
 const SECURE: &str = "Secure";
 
+/// Date formats accepted for the Expires attribute, once the leading day name is removed:
+/// RFC 1123, RFC 850, asctime and the Netscape variant of RFC 1123 (with dashes).
+const EXPIRES_DATE_FORMATS: [&str; 4] = [
+    "%d %b %Y %H:%M:%S GMT",
+    "%d-%b-%y %H:%M:%S GMT",
+    "%b %e %H:%M:%S %Y",
+    "%d-%b-%Y %H:%M:%S GMT",
+];
+
 impl ResponseCookie {
     /// Parses value from Set-Cookie header into a `ResponseCookie`.
     ///
@@ -83,6 +94,23 @@ impl ResponseCookie {
         self.attr_as_str(EXPIRES)
     }
 
+    /// Returns the optional Expires attribute as a UTC date.
+    ///
+    /// If the value can't be parsed as a cookie date, `None` is returned. Like browsers, the day
+    /// name is ignored and doesn't have to match the date.
+    pub fn expires_date(&self) -> Option<DateTime<Utc>> {
+        let expires = self.expires()?;
+        let expires = expires
+            .trim()
+            .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+            .trim_start_matches(',')
+            .trim_start();
+        EXPIRES_DATE_FORMATS
+            .iter()
+            .find_map(|fmt| NaiveDateTime::parse_from_str(expires, fmt).ok())
+            .map(|date| date.and_utc())
+    }
+
     /// Returns the optional Max-Age attribute as `i64` type.
     ///
     /// If the value is not a valid integer, the attribute is simply ignored
@@ -390,4 +418,37 @@ pub mod tests {
         assert!(!cookie.has_httponly());
         assert_eq!(cookie.samesite(), None);
     }
+
+    #[test]
+    fn test_expires_date() {
+        let expires_date = |header: &str| {
+            ResponseCookie::parse(header)
+                .unwrap()
+                .expires_date()
+                .map(|d| d.to_rfc3339())
+        };
+        let expected = Some("2030-10-21T07:28:00+00:00".to_string());
+        assert_eq!(
+            expires_date("id=a3fWa; Expires=Wed, 21 Oct 2030 07:28:00 GMT"),
+            expected
+        );
+        assert_eq!(
+            expires_date("id=a3fWa; Expires=Monday, 21-Oct-30 07:28:00 GMT"),
+            expected
+        );
+        assert_eq!(
+            expires_date("id=a3fWa; Expires=Mon Oct 21 07:28:00 2030"),
+            expected
+        );
+        assert_eq!(
+            expires_date("id=a3fWa; Expires=Mon Oct  1 07:28:00 2030"),
+            Some("2030-10-01T07:28:00+00:00".to_string())
+        );
+        assert_eq!(
+            expires_date("id=a3fWa; Expires=Mon, 21-Oct-2030 07:28:00 GMT"),
+            expected
+        );
+        assert_eq!(expires_date("id=a3fWa; Expires=tomorrow"), None);
+        assert_eq!(expires_date("id=a3fWa"), None);
+    }
 }
@@ -16,12 +16,14 @@
  *
  */
 use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
 use std::ptr;
+use std::sync::Once;
 use std::time::Duration;
 
 use curl::easy::Easy;
 use curl::Error;
-use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLINFO, CURLOPT_NETRC_FILE};
+use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLcode, CURLINFO, CURLOPT_NETRC_FILE};
 
 /// Some definitions not present in curl-sys
 const CURLINFO_OFF_T: CURLINFO = 0x600000;
@@ -34,6 +36,10 @@ const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 const CURLINFO_CONN_ID: CURLINFO = CURLINFO_OFF_T + 64;
 
+extern "C" {
+    fn curl_global_trace(config: *const c_char) -> CURLcode;
+}
+
 /// Represents certificate information.
 /// `data` has format "name:content";
 #[derive(Clone)]
@@ -191,6 +197,19 @@ pub fn netrc_file(easy: &mut Easy, filename: &str) -> Result<(), Error> {
     })
 }
 
+/// Enables the libcurl traces of the HTTP/2 frames, logged as texts in the debug function of the
+/// verbose handles.
+///
+/// See <https://curl.se/libcurl/c/curl_global_trace.html>. The traces are enabled for the whole
+/// process, so this is only done once. If libcurl has been built without verbose strings, the
+/// frames are not traced.
+pub fn trace_http2_frames() {
+    static TRACE_HTTP2: Once = Once::new();
+    TRACE_HTTP2.call_once(|| unsafe {
+        curl_global_trace(c"http/2".as_ptr());
+    });
+}
+
 /// Converts an instance of libcurl linked list [`curl_slist`] to a vec of [`String`].
 fn to_list(slist: *mut curl_slist) -> Vec<String> {
     let mut data = vec![];
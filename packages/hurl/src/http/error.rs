@@ -38,7 +38,11 @@ pub enum HttpError {
         option: String,
         minimum_version: String,
     },
-    TooManyRedirect,
+    /// The maximum number of redirects `count` has been exceeded, `url` is the last URL reached.
+    TooManyRedirect {
+        count: usize,
+        url: String,
+    },
     UnsupportedContentEncoding {
         description: String,
     },
@@ -70,7 +74,7 @@ impl HttpError {
             HttpError::InvalidUrl(..) => "Invalid URL".to_string(),
             HttpError::Libcurl { .. } => "HTTP connection".to_string(),
             HttpError::LibcurlUnknownOption { .. } => "HTTP connection".to_string(),
-            HttpError::TooManyRedirect => "HTTP connection".to_string(),
+            HttpError::TooManyRedirect { .. } => "Too many redirects".to_string(),
             HttpError::UnsupportedContentEncoding { .. } => "Decompression error".to_string(),
             HttpError::UnsupportedHttpVersion(_) => "Unsupported HTTP version".to_string(),
         }
@@ -99,7 +103,9 @@ impl HttpError {
                 option,
                 minimum_version,
             } => format!("Option {option} requires libcurl version {minimum_version} or higher"),
-            HttpError::TooManyRedirect => "too many redirect".to_string(),
+            HttpError::TooManyRedirect { count, url } => {
+                format!("maximum of {count} redirects exceeded, last URL reached <{url}>")
+            }
             HttpError::UnsupportedHttpVersion(version) => {
                 format!("{version} is not supported, check --version").to_string()
             }
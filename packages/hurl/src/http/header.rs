@@ -34,6 +34,8 @@ pub const EXPECT: &str = "Expect";
 pub const LOCATION: &str = "Location";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Set-Cookie>
 pub const SET_COOKIE: &str = "Set-Cookie";
+/// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Transfer-Encoding>
+pub const TRANSFER_ENCODING: &str = "Transfer-Encoding";
 /// See <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/User-Agent>
 pub const USER_AGENT: &str = "User-Agent";
 
@@ -19,7 +19,7 @@
 //!
 //! The Hurl HTTP engine is not public. It's a wrapper around libcurl and only the models
 //! returned by an HTTP exchange are exposed.
-pub use self::call::Call;
+pub use self::call::{Call, H2Frame};
 pub use self::certificate::Certificate;
 pub(crate) use self::client::Client;
 pub use self::cookie::{CookieAttribute, ResponseCookie};
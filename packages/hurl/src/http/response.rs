@@ -19,6 +19,7 @@ use std::fmt;
 use std::time::Duration;
 
 use crate::http::certificate::Certificate;
+use crate::http::header::TRANSFER_ENCODING;
 use crate::http::{HeaderVec, Url};
 
 /// Represents a runtime HTTP response.
@@ -33,10 +34,13 @@ pub struct Response {
     pub url: Url,
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
+    /// The TLS version negotiated for this response (ex: "TLSv1.3"), `None` for plain HTTP
+    pub tls_version: Option<String>,
 }
 
 impl Response {
     /// Creates a new HTTP response
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         version: HttpVersion,
         status: u32,
@@ -45,6 +49,7 @@ impl Response {
         duration: Duration,
         url: Url,
         certificate: Option<Certificate>,
+        tls_version: Option<String>,
     ) -> Self {
         Response {
             version,
@@ -54,8 +59,38 @@ impl Response {
             duration,
             url,
             certificate,
+            tls_version,
         }
     }
+
+    /// Returns the transfer encodings applied to the body on the wire, as listed by the
+    /// `Transfer-Encoding` headers (`"chunked"` or `"gzip, chunked"` for instance).
+    ///
+    /// Returns `None` if the body has been sent as is, which is always the case in HTTP/2 and
+    /// HTTP/3 where chunked transfer is not used.
+    pub fn transfer_encoding(&self) -> Option<String> {
+        let values = self.headers.values(TRANSFER_ENCODING);
+        if values.is_empty() {
+            None
+        } else {
+            Some(values.join(", "))
+        }
+    }
+
+    /// Returns an approximation of the size in bytes of the response headers, as sent on the
+    /// wire, from the start of the status line to the empty line before the body (included).
+    ///
+    /// We don't keep the raw headers, so the size is computed from an HTTP/1.x serialization of
+    /// the status line (without reason phrase) and the headers.
+    pub fn headers_size(&self) -> usize {
+        let status_line = format!("{} {}\r\n", self.version, self.status).len();
+        let headers = self
+            .headers
+            .iter()
+            .map(|h| h.name.len() + ": ".len() + h.value.len() + "\r\n".len())
+            .sum::<usize>();
+        status_line + headers + "\r\n".len()
+    }
 }
 
 /// Represents the HTTP version of a HTTP transaction.
@@ -97,8 +132,65 @@ mod tests {
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            tls_version: None,
         };
         assert_eq!(response.headers.values("Content-Length"), vec!["12"]);
         assert!(response.headers.values("Unknown").is_empty());
     }
+
+    #[test]
+    fn get_transfer_encoding() {
+        // Headers of a chunked response, as received from the server.
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "text/plain"));
+        headers.push(Header::new("transfer-encoding", "chunked"));
+        let mut response = Response {
+            version: HttpVersion::Http11,
+            status: 200,
+            headers,
+            body: b"Hello World!".to_vec(),
+            duration: Default::default(),
+            url: "http://localhost".parse().unwrap(),
+            certificate: None,
+            tls_version: None,
+        };
+        assert_eq!(response.transfer_encoding(), Some("chunked".to_string()));
+
+        response.headers = HeaderVec::new();
+        response
+            .headers
+            .push(Header::new("Transfer-Encoding", "gzip"));
+        response
+            .headers
+            .push(Header::new("Transfer-Encoding", "chunked"));
+        assert_eq!(
+            response.transfer_encoding(),
+            Some("gzip, chunked".to_string())
+        );
+
+        response.headers = HeaderVec::new();
+        response.headers.push(Header::new("Content-Length", "12"));
+        assert_eq!(response.transfer_encoding(), None);
+    }
+
+    #[test]
+    fn get_headers_size() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Length", "12"));
+        let mut response = Response {
+            version: HttpVersion::Http11,
+            status: 200,
+            headers,
+            body: b"Hello World!".to_vec(),
+            duration: Default::default(),
+            url: "http://localhost".parse().unwrap(),
+            certificate: None,
+            tls_version: None,
+        };
+        // "HTTP/1.1 200\r\n" + "Content-Length: 12\r\n" + "\r\n"
+        assert_eq!(response.headers_size(), 14 + 20 + 2);
+
+        response.headers = HeaderVec::new();
+        assert_eq!(response.headers_size(), 14 + 2);
+    }
 }
@@ -62,6 +62,26 @@ impl ContentEncoding {
             ContentEncoding::Brotli => uncompress_brotli(data),
         }
     }
+
+    /// Returns a reader decompressing incrementally the bytes read from `reader`.
+    fn decoder<'a>(&self, reader: Box<dyn Read + 'a>) -> Result<Box<dyn Read + 'a>, HttpError> {
+        match self {
+            ContentEncoding::Identity => Ok(reader),
+            ContentEncoding::Gzip => match libflate::gzip::Decoder::new(reader) {
+                Ok(decoder) => Ok(Box::new(decoder)),
+                Err(_) => Err(HttpError::CouldNotUncompressResponse {
+                    description: "gzip".to_string(),
+                }),
+            },
+            ContentEncoding::Deflate => match libflate::zlib::Decoder::new(reader) {
+                Ok(decoder) => Ok(Box::new(decoder)),
+                Err(_) => Err(HttpError::CouldNotUncompressResponse {
+                    description: "zlib".to_string(),
+                }),
+            },
+            ContentEncoding::Brotli => Ok(Box::new(brotli::Decompressor::new(reader, 4096))),
+        }
+    }
 }
 
 impl Response {
@@ -106,6 +126,19 @@ impl Response {
         }
         Ok(data)
     }
+
+    /// Returns a reader over the HTTP body response, decompressed incrementally.
+    ///
+    /// Contrary to [`Response::uncompress_body`], the decompressed body is never fully held in
+    /// memory: it can be used to scan large bodies.
+    pub fn body_reader(&self) -> Result<Box<dyn Read + '_>, HttpError> {
+        let encodings = self.headers.content_encoding()?;
+        let mut reader: Box<dyn Read> = Box::new(self.body.as_slice());
+        for encoding in &encodings {
+            reader = encoding.decoder(reader)?;
+        }
+        Ok(reader)
+    }
 }
 
 /// Decompresses Brotli compressed `data`.
@@ -173,6 +206,7 @@ pub mod tests {
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            tls_version: None,
         }
     }
 
@@ -270,6 +304,41 @@ pub mod tests {
         assert_eq!(response.uncompress_body().unwrap(), b"Hello World!");
     }
 
+    #[test]
+    fn test_body_reader() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "deflate, identity"));
+        let response = Response {
+            headers,
+            body: vec![
+                0x78, 0x9c, 0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0x08, 0xcf, 0x2f, 0xca, 0x49, 0x51,
+                0x04, 0x00, 0x1c, 0x49, 0x04, 0x3e,
+            ],
+            ..default_response()
+        };
+        let mut body = vec![];
+        response
+            .body_reader()
+            .unwrap()
+            .read_to_end(&mut body)
+            .unwrap();
+        assert_eq!(body, b"Hello World!");
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Encoding", "gzip"));
+        let response = Response {
+            headers,
+            body: vec![0x21],
+            ..default_response()
+        };
+        assert_eq!(
+            response.body_reader().err().unwrap(),
+            HttpError::CouldNotUncompressResponse {
+                description: "gzip".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_uncompress_brotli() {
         let data = [
@@ -18,7 +18,8 @@
 use std::str::FromStr;
 
 use crate::http::{
-    Header, HeaderVec, HttpVersion, Method, Param, RequestCookie, RequestSpec, Response, Url,
+    Header, HeaderVec, HttpVersion, Method, Param, Request, RequestCookie, RequestSpec, Response,
+    Url,
 };
 
 /// Some Request Response to be used by tests
@@ -32,9 +33,16 @@ fn default_response() -> Response {
         duration: Default::default(),
         url: Url::from_str("http://localhost").unwrap(),
         certificate: None,
+        tls_version: None,
     }
 }
 
+/// Returns a sent GET request, with no headers and no body.
+pub fn default_http_request() -> Request {
+    let url = Url::from_str("http://localhost:8000/hello").unwrap();
+    Request::new("GET", url, HeaderVec::new(), vec![])
+}
+
 pub fn hello_http_request() -> RequestSpec {
     RequestSpec {
         method: Method("GET".to_string()),
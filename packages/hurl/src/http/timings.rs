@@ -36,6 +36,8 @@ pub struct Timings {
     pub pre_transfer: Duration,
     pub start_transfer: Duration,
     pub total: Duration,
+    /// Identifier of the connection used by this transfer, if available (libcurl >= 8.2.0).
+    pub connection_id: Option<i64>,
 }
 
 impl Timings {
@@ -61,6 +63,7 @@ impl Timings {
         let total = easy_ext::total_time_t(easy)
             .or(easy.total_time())
             .unwrap_or_default();
+        let connection_id = easy_ext::conn_id(easy).ok().filter(|id| *id >= 0);
         Timings {
             begin_call,
             end_call,
@@ -70,6 +73,13 @@ impl Timings {
             pre_transfer,
             start_transfer,
             total,
+            connection_id,
         }
     }
+
+    /// Returns `true` if this transfer has reused an existing connection, i.e. there has been no
+    /// TCP connect nor TLS handshake.
+    pub fn connection_reused(&self) -> bool {
+        self.connect.is_zero() && self.app_connect.is_zero()
+    }
 }
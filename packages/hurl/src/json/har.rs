@@ -0,0 +1,492 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::time::Duration;
+
+use base64::engine::general_purpose;
+use base64::Engine;
+use chrono::SecondsFormat;
+use serde::Serialize;
+
+use crate::http::{Call, Header, Param, Request, RequestCookie, Response, ResponseCookie, Timings};
+use crate::runner::{EntryResult, HurlResult};
+
+impl HurlResult {
+    /// Serializes an [`HurlResult`] to a [HAR 1.2] `log` object.
+    ///
+    /// Each entry result is exported as one HAR entry, using its last HTTP call (i.e. the final
+    /// response, after redirects and retries). Entry results without any HTTP call (for instance,
+    /// when the request couldn't be sent) are ignored.
+    ///
+    /// [HAR 1.2]: http://www.softwareishard.com/blog/har-12-spec/
+    pub fn to_har(&self) -> serde_json::Value {
+        let har = HarJson::from_result(self);
+        serde_json::to_value(har).unwrap()
+    }
+}
+
+/// These structures represent the HAR 1.2 schema used to export an [`HurlResult`].
+#[derive(Serialize)]
+struct HarJson {
+    log: LogJson,
+}
+
+#[derive(Serialize)]
+struct LogJson {
+    version: String,
+    creator: CreatorJson,
+    entries: Vec<EntryJson>,
+}
+
+#[derive(Serialize)]
+struct CreatorJson {
+    name: String,
+    version: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EntryJson {
+    started_date_time: String,
+    time: f64,
+    request: RequestJson,
+    response: ResponseJson,
+    /// Cache usage is not recorded: always an empty object.
+    cache: serde_json::Map<String, serde_json::Value>,
+    timings: TimingsJson,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestJson {
+    method: String,
+    url: String,
+    http_version: String,
+    cookies: Vec<CookieJson>,
+    headers: Vec<NameValueJson>,
+    query_string: Vec<NameValueJson>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_data: Option<PostDataJson>,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostDataJson {
+    mime_type: String,
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseJson {
+    status: u32,
+    status_text: String,
+    http_version: String,
+    cookies: Vec<CookieJson>,
+    headers: Vec<NameValueJson>,
+    content: ContentJson,
+    #[serde(rename = "redirectURL")]
+    redirect_url: String,
+    headers_size: i64,
+    body_size: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContentJson {
+    size: usize,
+    mime_type: String,
+    text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CookieJson {
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    http_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secure: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct NameValueJson {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct TimingsJson {
+    blocked: f64,
+    dns: f64,
+    connect: f64,
+    send: f64,
+    wait: f64,
+    receive: f64,
+    ssl: f64,
+}
+
+impl HarJson {
+    fn from_result(result: &HurlResult) -> Self {
+        let entries = result
+            .entries
+            .iter()
+            .filter_map(EntryJson::from_entry)
+            .collect();
+        HarJson {
+            log: LogJson {
+                version: "1.2".to_string(),
+                creator: CreatorJson {
+                    name: "hurl".to_string(),
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                },
+                entries,
+            },
+        }
+    }
+}
+
+impl EntryJson {
+    fn from_entry(entry: &EntryResult) -> Option<Self> {
+        let Call {
+            request,
+            response,
+            timings,
+            ..
+        } = entry.calls.last()?;
+        Some(EntryJson {
+            started_date_time: timings
+                .begin_call
+                .to_rfc3339_opts(SecondsFormat::Millis, true),
+            time: millis(timings.total),
+            // The HTTP version of the request is not kept, we use the one of the response.
+            request: RequestJson::from_request(request, &response.version.to_string()),
+            response: ResponseJson::from_response(response),
+            cache: serde_json::Map::new(),
+            timings: TimingsJson::from_timings(timings),
+        })
+    }
+}
+
+impl RequestJson {
+    fn from_request(request: &Request, http_version: &str) -> Self {
+        let post_data = if request.body.is_empty() {
+            None
+        } else {
+            Some(PostDataJson {
+                mime_type: request
+                    .headers
+                    .content_type()
+                    .unwrap_or_default()
+                    .to_string(),
+                text: String::from_utf8_lossy(&request.body).to_string(),
+            })
+        };
+        RequestJson {
+            method: request.method.clone(),
+            url: request.url.to_string(),
+            http_version: http_version.to_string(),
+            cookies: request
+                .cookies()
+                .iter()
+                .map(CookieJson::from_request_cookie)
+                .collect(),
+            headers: request
+                .headers
+                .iter()
+                .map(NameValueJson::from_header)
+                .collect(),
+            query_string: request
+                .url
+                .query_params()
+                .iter()
+                .map(NameValueJson::from_param)
+                .collect(),
+            post_data,
+            headers_size: -1,
+            body_size: request.body.len() as i64,
+        }
+    }
+}
+
+impl ResponseJson {
+    fn from_response(response: &Response) -> Self {
+        // The content is the decompressed body: textual bodies are decoded, others are exported
+        // as base64. The raw body is only used if it can't be decompressed.
+        let body = response
+            .uncompress_body()
+            .unwrap_or_else(|_| response.body.clone());
+        let (text, encoding) = match response.text() {
+            Ok(text) => (text, None),
+            Err(_) => (
+                general_purpose::STANDARD.encode(&body),
+                Some("base64".to_string()),
+            ),
+        };
+        let size = body.len();
+        let redirect_url = response
+            .headers
+            .get("Location")
+            .map(|h| h.value.clone())
+            .unwrap_or_default();
+        ResponseJson {
+            status: response.status,
+            status_text: String::new(),
+            http_version: response.version.to_string(),
+            cookies: response
+                .cookies()
+                .iter()
+                .map(CookieJson::from_response_cookie)
+                .collect(),
+            headers: response
+                .headers
+                .iter()
+                .map(NameValueJson::from_header)
+                .collect(),
+            content: ContentJson {
+                size,
+                mime_type: response
+                    .headers
+                    .content_type()
+                    .unwrap_or_default()
+                    .to_string(),
+                text,
+                encoding,
+            },
+            redirect_url,
+            headers_size: -1,
+            body_size: response.body.len() as i64,
+        }
+    }
+}
+
+impl CookieJson {
+    fn from_request_cookie(c: &RequestCookie) -> Self {
+        CookieJson {
+            name: c.name.clone(),
+            value: c.value.clone(),
+            path: None,
+            domain: None,
+            expires: None,
+            http_only: None,
+            secure: None,
+        }
+    }
+
+    fn from_response_cookie(c: &ResponseCookie) -> Self {
+        CookieJson {
+            name: c.name.clone(),
+            value: c.value.clone(),
+            path: c.path(),
+            domain: c.domain(),
+            expires: c
+                .expires_date()
+                .map(|d| d.to_rfc3339_opts(SecondsFormat::Millis, true)),
+            http_only: if c.has_httponly() { Some(true) } else { None },
+            secure: if c.has_secure() { Some(true) } else { None },
+        }
+    }
+}
+
+impl NameValueJson {
+    fn from_header(h: &Header) -> Self {
+        NameValueJson {
+            name: h.name.clone(),
+            value: h.value.clone(),
+        }
+    }
+
+    fn from_param(p: &Param) -> Self {
+        NameValueJson {
+            name: p.name.clone(),
+            value: p.value.clone(),
+        }
+    }
+}
+
+impl TimingsJson {
+    /// Converts libcurl timings, which are durations since the start of the transfer, into HAR
+    /// timings, which are the durations of each non-overlapping phase of the transfer.
+    fn from_timings(timings: &Timings) -> Self {
+        let connected = timings.connect.max(timings.app_connect);
+        let ssl = if timings.app_connect.is_zero() {
+            -1.0
+        } else {
+            millis(timings.app_connect.saturating_sub(timings.connect))
+        };
+        TimingsJson {
+            blocked: -1.0,
+            dns: millis(timings.name_lookup),
+            connect: millis(connected.saturating_sub(timings.name_lookup)),
+            send: millis(timings.pre_transfer.saturating_sub(connected)),
+            wait: millis(timings.start_transfer.saturating_sub(timings.pre_transfer)),
+            receive: millis(timings.total.saturating_sub(timings.start_transfer)),
+            ssl,
+        }
+    }
+}
+
+/// Returns a `duration` in milliseconds, as used by HAR.
+fn millis(duration: Duration) -> f64 {
+    duration.as_micros() as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use chrono::{DateTime, Utc};
+
+    use super::*;
+    use crate::http::{xml_two_users_http_response, HeaderVec, HttpVersion};
+
+    #[test]
+    fn test_to_har() {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "application/json"));
+        headers.push(Header::new("Cookie", "session=1234"));
+        let url = "http://localhost:8000/users?id=1".parse().unwrap();
+        let request = Request::new("POST", url, headers, b"{\"name\":\"Bob\"}".to_vec());
+        let mut response = xml_two_users_http_response();
+        response.version = HttpVersion::Http11;
+        response
+            .headers
+            .push(Header::new("Set-Cookie", "lang=en; Path=/; HttpOnly"));
+        let begin_call = DateTime::parse_from_rfc3339("2024-01-10T08:29:52.123Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let timings = Timings {
+            begin_call,
+            end_call: begin_call,
+            name_lookup: Duration::from_millis(2),
+            connect: Duration::from_millis(5),
+            app_connect: Duration::from_millis(0),
+            pre_transfer: Duration::from_millis(6),
+            start_transfer: Duration::from_millis(16),
+            total: Duration::from_millis(20),
+            connection_id: Some(0),
+        };
+        let result = HurlResult {
+            entries: vec![
+                EntryResult {
+                    calls: vec![Call {
+                        request,
+                        response,
+                        timings,
+                        h2_frames: vec![],
+                    }],
+                    ..Default::default()
+                },
+                EntryResult::default(),
+            ],
+            duration: Duration::from_millis(30),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+
+        let har = result.to_har();
+        let log = &har["log"];
+        assert_eq!(log["version"], "1.2");
+        assert_eq!(log["creator"]["name"], "hurl");
+        assert_eq!(log["creator"]["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(log["entries"].as_array().unwrap().len(), 1);
+
+        let entry = &log["entries"][0];
+        assert_eq!(entry["startedDateTime"], "2024-01-10T08:29:52.123Z");
+        assert_eq!(entry["time"], 20.0);
+        assert_eq!(
+            entry["timings"],
+            serde_json::json!({
+                "blocked": -1.0,
+                "dns": 2.0,
+                "connect": 3.0,
+                "send": 1.0,
+                "wait": 10.0,
+                "receive": 4.0,
+                "ssl": -1.0,
+            })
+        );
+
+        let request = &entry["request"];
+        assert_eq!(request["method"], "POST");
+        assert_eq!(request["url"], "http://localhost:8000/users?id=1");
+        assert_eq!(request["httpVersion"], "HTTP/1.1");
+        assert_eq!(
+            request["cookies"],
+            serde_json::json!([{"name": "session", "value": "1234"}])
+        );
+        assert_eq!(
+            request["queryString"],
+            serde_json::json!([{"name": "id", "value": "1"}])
+        );
+        assert_eq!(
+            request["postData"],
+            serde_json::json!({"mimeType": "application/json", "text": "{\"name\":\"Bob\"}"})
+        );
+        assert_eq!(request["bodySize"], 14);
+
+        let response = &entry["response"];
+        assert_eq!(response["status"], 200);
+        assert_eq!(response["httpVersion"], "HTTP/1.1");
+        assert_eq!(
+            response["cookies"],
+            serde_json::json!([{"name": "lang", "value": "en", "path": "/", "httpOnly": true}])
+        );
+        assert_eq!(response["content"]["mimeType"], "text/html; charset=utf-8");
+        assert!(response["content"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("<user id=\"1\">Bob</user>"));
+        assert!(response["content"].get("encoding").is_none());
+        assert_eq!(response["redirectURL"], "");
+    }
+
+    #[test]
+    fn test_to_har_compressed_binary_content() {
+        let png = vec![0x89, b'P', b'N', b'G', 0xff, 0xfe];
+        let mut encoder = libflate::gzip::Encoder::new(Vec::new()).unwrap();
+        encoder.write_all(&png).unwrap();
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "image/png"));
+        headers.push(Header::new("Content-Encoding", "gzip"));
+        let response = Response {
+            headers,
+            body: encoder.finish().into_result().unwrap(),
+            ..xml_two_users_http_response()
+        };
+
+        // The content is the decompressed body, in base64.
+        let content =
+            &serde_json::to_value(ResponseJson::from_response(&response)).unwrap()["content"];
+        assert_eq!(content["mimeType"], "image/png");
+        assert_eq!(content["size"], 6);
+        assert_eq!(content["encoding"], "base64");
+        assert_eq!(content["text"], general_purpose::STANDARD.encode(&png));
+    }
+}
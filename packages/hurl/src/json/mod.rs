@@ -16,5 +16,25 @@
  *
  */
 //! Serialize / Deserialize a [`crate::runner::HurlResult`] to JSON.
+mod har;
 mod result;
+mod schema;
 mod value;
+
+pub(crate) use self::result::VersionedResult;
+
+/// Version of the JSON serialization of a [`crate::runner::HurlResult`], exported as the
+/// `schemaVersion` field of the JSON result.
+///
+/// This version is bumped whenever the structure of the entries, asserts or timings changes, so
+/// consumers can detect incompatible results.
+///
+/// History:
+/// - `1.0`: initial version
+/// - `1.1`: `bodySize` and `headersSize` of the responses
+/// - `1.2`: `connectionReused` and `connectionId` of the calls
+/// - `1.3`: `errors` of the asserts
+/// - `1.4`: `transferEncoding` of the responses
+/// - `1.5`: `warnings` of the entries and `tlsVersion` of the responses
+/// - `1.6`: `h2Frames` of the calls
+pub const JSON_SCHEMA_VERSION: &str = "1.6";
@@ -20,18 +20,25 @@ use std::io;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
+use base64::engine::general_purpose;
+use base64::Engine;
 use chrono::SecondsFormat;
 use hurl_core::ast::SourceInfo;
 use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
 use uuid::Uuid;
 
 use crate::http::{
-    Call, Certificate, Cookie, Header, HttpVersion, Param, Request, RequestCookie, Response,
-    ResponseCookie, Timings,
+    Call, Certificate, Cookie, H2Frame, Header, HttpVersion, Param, Request, RequestCookie,
+    Response, ResponseCookie, Timings,
+};
+use crate::json::JSON_SCHEMA_VERSION;
+use crate::runner::{
+    AssertResult, CaptureResult, DurationBucket, EntryResult, HurlResult, Warning,
+    DEFAULT_DURATION_BUCKETS,
 };
-use crate::runner::{AssertResult, CaptureResult, EntryResult, HurlResult};
 
 impl HurlResult {
     /// Serializes an [`HurlResult`] to a JSON representation.
@@ -39,14 +46,24 @@ impl HurlResult {
     /// Note: `content` is passed to this method to save asserts and errors messages (with lines
     /// and columns). This parameter will be removed soon and the original content will be
     /// accessible through the [`HurlResult`] instance.
-    /// An optional directory `response_dir` can be used to save HTTP response.
+    /// An optional directory `response_dir` can be used to save HTTP response. Without
+    /// `response_dir`, response bodies are exported inline. If `include_success_bodies` is
+    /// `false`, only the response bodies of entries that have failed are saved or exported inline,
+    /// the `body` of other responses is `null`.
     pub fn to_json(
         &self,
         content: &str,
         filename: &Input,
         response_dir: Option<&Path>,
+        include_success_bodies: bool,
     ) -> Result<serde_json::Value, io::Error> {
-        let result = HurlResultJson::from_result(self, content, filename, response_dir)?;
+        let result = HurlResultJson::from_result(
+            self,
+            content,
+            filename,
+            response_dir,
+            include_success_bodies,
+        )?;
         let value = serde_json::to_value(result).unwrap();
         Ok(value)
     }
@@ -59,14 +76,52 @@ impl HurlResult {
     }
 }
 
+/// A JSON representation of an [`HurlResult`], serialized with its `schemaVersion` field first.
+///
+/// The other fields are serialized with their keys sorted, so consumers can read the version of
+/// the schema before any other field.
+pub struct VersionedResult<'a>(pub &'a serde_json::Value);
+
+impl Serialize for VersionedResult<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let serde_json::Value::Object(map) = self.0 else {
+            return self.0.serialize(serializer);
+        };
+        let Some(version) = map.get("schemaVersion") else {
+            return self.0.serialize(serializer);
+        };
+        let mut state = serializer.serialize_map(Some(map.len()))?;
+        state.serialize_entry("schemaVersion", version)?;
+        for (key, value) in map.iter().filter(|(key, _)| *key != "schemaVersion") {
+            state.serialize_entry(key, value)?;
+        }
+        state.end()
+    }
+}
+
 /// These structures represent the JSON schema used to serialize an [`HurlResult`] to JSON.
 #[derive(Deserialize, Serialize)]
 struct HurlResultJson {
+    #[serde(default, rename = "schemaVersion")]
+    schema_version: String,
     filename: String,
     entries: Vec<EntryResultJson>,
     success: bool,
     time: u64,
     cookies: Vec<CookieJson>,
+    #[serde(default, rename = "durationHistogram")]
+    duration_histogram: Vec<DurationBucketJson>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct DurationBucketJson {
+    min: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max: Option<u64>,
+    count: usize,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -78,6 +133,8 @@ struct EntryResultJson {
     asserts: Vec<AssertJson>,
     time: u64,
     curl_cmd: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<WarningJson>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -96,6 +153,28 @@ struct CallJson {
     request: RequestJson,
     response: ResponseJson,
     timings: TimingsJson,
+    #[serde(default, rename = "connectionReused")]
+    connection_reused: bool,
+    #[serde(default, rename = "connectionId")]
+    connection_id: Option<i64>,
+    #[serde(default, rename = "h2Frames", skip_serializing_if = "Vec::is_empty")]
+    h2_frames: Vec<H2FrameJson>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct H2FrameJson {
+    #[serde(rename = "type")]
+    frame_type: String,
+    #[serde(rename = "streamId")]
+    stream_id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    length: Option<u32>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct WarningJson {
+    code: String,
+    message: String,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -109,7 +188,30 @@ struct AssertJson {
     success: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     message: Option<String>,
+    /// All the errors of the assert, the first one being also the `message`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+    line: usize,
+    #[serde(default, rename = "sourceInfo")]
+    source_info: AssertSourceInfoJson,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct AssertSourceInfoJson {
+    predicate: SourceInfoJson,
+    filters: Vec<SourceInfoJson>,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct SourceInfoJson {
+    start: PosJson,
+    end: PosJson,
+}
+
+#[derive(Default, Deserialize, Serialize)]
+struct PosJson {
     line: usize,
+    column: usize,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -119,6 +221,22 @@ struct RequestJson {
     headers: Vec<HeaderJson>,
     cookies: Vec<RequestCookieJson>,
     query_string: Vec<ParamJson>,
+    /// Body sent over the wire, as text or base64 depending on `body_encoding`.
+    #[serde(default)]
+    body: String,
+    #[serde(default, rename = "bodyEncoding")]
+    body_encoding: BodyEncodingJson,
+}
+
+/// Encoding of a request or response `body` in JSON.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum BodyEncodingJson {
+    /// The body is a valid UTF-8 text, exported as is.
+    #[default]
+    Text,
+    /// The body is binary, exported in base64.
+    Base64,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -129,8 +247,33 @@ struct ResponseJson {
     cookies: Vec<ResponseCookieJson>,
     #[serde(skip_serializing_if = "Option::is_none")]
     certificate: Option<CertificateJson>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Body of the response: the relative path of the saved response file when the response is
+    /// saved in a directory, or the body itself, as text or base64 depending on `body_encoding`.
+    /// The body is `null` when it's not exported.
+    #[serde(default)]
     body: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "bodyEncoding"
+    )]
+    body_encoding: Option<BodyEncodingJson>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "transferEncoding")]
+    transfer_encoding: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tlsVersion")]
+    tls_version: Option<String>,
+    /// Size in bytes of the response body as received, before any decompression (`-1` if
+    /// unknown, like HAR).
+    #[serde(default = "unknown_size", rename = "bodySize")]
+    body_size: i64,
+    /// Approximate size in bytes of the response headers, like HAR.
+    #[serde(default = "unknown_size", rename = "headersSize")]
+    headers_size: i64,
+}
+
+/// Default value for an unknown size, in HAR semantics.
+fn unknown_size() -> i64 {
+    -1
 }
 
 #[derive(Deserialize, Serialize)]
@@ -169,9 +312,15 @@ struct ResponseCookieJson {
     value: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     expires: Option<String>,
-    // FIXME: maybe max_age should be u64
+    /// Expires as an ISO 8601 UTC date, absent if the raw value can't be parsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_iso: Option<String>,
+    /// Max-Age as a string, kept for compatibility (see `max_age_seconds`).
     #[serde(skip_serializing_if = "Option::is_none")]
     max_age: Option<String>,
+    /// Max-Age in seconds, zero or negative values meaning that the cookie expires immediately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_age_seconds: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     domain: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -180,8 +329,12 @@ struct ResponseCookieJson {
     secure: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "httponly")]
     http_only: Option<bool>,
+    /// SameSite normalized to `Strict`, `Lax` or `None`, unknown values being kept unchanged.
     #[serde(skip_serializing_if = "Option::is_none", rename = "same_site")]
     same_site: Option<String>,
+    /// SameSite as sent by the server.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "same_site_raw")]
+    same_site_raw: Option<String>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -193,29 +346,56 @@ struct CertificateJson {
     serial_number: String,
 }
 
+/// How response bodies are exported in JSON.
+#[derive(Clone, Copy)]
+enum ResponseBody<'a> {
+    /// Bodies are exported inline, in the `body` field.
+    Inline,
+    /// Bodies are saved in a directory, and referenced by path in the `body` field.
+    File(&'a Path),
+    /// Bodies are not exported, the `body` field is `null`.
+    Skip,
+}
+
 impl HurlResultJson {
     fn from_result(
         result: &HurlResult,
         content: &str,
         filename: &Input,
         response_dir: Option<&Path>,
+        include_success_bodies: bool,
     ) -> Result<Self, io::Error> {
         let entries = result
             .entries
             .iter()
-            .map(|e| EntryResultJson::from_entry(e, content, filename, response_dir))
+            .map(|e| {
+                EntryResultJson::from_entry(
+                    e,
+                    content,
+                    filename,
+                    response_dir,
+                    include_success_bodies,
+                )
+            })
             .collect::<Result<Vec<_>, _>>()?;
         let cookies = result
             .cookies
             .iter()
             .map(CookieJson::from_cookie)
             .collect::<Vec<_>>();
+        let duration_histogram = result
+            .duration_histogram(&DEFAULT_DURATION_BUCKETS)
+            .iter()
+            .map(DurationBucketJson::from_bucket)
+            .collect::<Vec<_>>();
         Ok(HurlResultJson {
+            schema_version: JSON_SCHEMA_VERSION.to_string(),
             filename: filename.to_string(),
             entries,
             success: result.success,
             time: result.duration.as_millis() as u64,
             cookies,
+            duration_histogram,
         })
     }
 }
@@ -226,11 +406,19 @@ impl EntryResultJson {
         content: &str,
         filename: &Input,
         response_dir: Option<&Path>,
+        include_success_bodies: bool,
     ) -> Result<Self, io::Error> {
+        // Without a response directory, bodies are exported inline. In both cases, responses of
+        // successful entries are only exported if `include_success_bodies` is set.
+        let response_body = match response_dir {
+            _ if !include_success_bodies && entry.errors.is_empty() => ResponseBody::Skip,
+            None => ResponseBody::Inline,
+            Some(dir) => ResponseBody::File(dir),
+        };
         let calls = entry
             .calls
             .iter()
-            .map(|c| CallJson::from_call(c, response_dir))
+            .map(|c| CallJson::from_call(c, response_body))
             .collect::<Result<Vec<_>, _>>()?;
         let captures = entry
             .captures
@@ -242,6 +430,11 @@ impl EntryResultJson {
             .iter()
             .map(|a| AssertJson::from_assert(a, content, filename, entry.source_info))
             .collect::<Vec<_>>();
+        let warnings = entry
+            .warnings
+            .iter()
+            .map(WarningJson::from_warning)
+            .collect::<Vec<_>>();
         Ok(EntryResultJson {
             index: entry.entry_index,
             line: entry.source_info.start.line,
@@ -250,6 +443,7 @@ impl EntryResultJson {
             asserts,
             time: entry.transfer_duration.as_millis() as u64,
             curl_cmd: entry.curl_cmd.to_string(),
+            warnings,
         })
     }
 }
@@ -268,19 +462,42 @@ impl CookieJson {
     }
 }
 
+impl DurationBucketJson {
+    fn from_bucket(bucket: &DurationBucket) -> Self {
+        DurationBucketJson {
+            min: bucket.min_ms,
+            max: bucket.max_ms,
+            count: bucket.count,
+        }
+    }
+}
+
 impl CallJson {
-    fn from_call(call: &Call, response_dir: Option<&Path>) -> Result<Self, io::Error> {
+    fn from_call(call: &Call, response_body: ResponseBody) -> Result<Self, io::Error> {
         let request = RequestJson::from_request(&call.request);
-        let response = ResponseJson::from_response(&call.response, response_dir)?;
+        let response = ResponseJson::from_response(&call.response, response_body)?;
         let timings = TimingsJson::from_timings(&call.timings);
         Ok(CallJson {
             request,
             response,
             timings,
+            connection_reused: call.timings.connection_reused(),
+            connection_id: call.timings.connection_id,
+            h2_frames: call.h2_frames.iter().map(H2FrameJson::from_frame).collect(),
         })
     }
 }
 
+impl H2FrameJson {
+    fn from_frame(frame: &H2Frame) -> Self {
+        H2FrameJson {
+            frame_type: frame.frame_type.clone(),
+            stream_id: frame.stream_id,
+            length: frame.length,
+        }
+    }
+}
+
 impl RequestJson {
     fn from_request(request: &Request) -> Self {
         let headers = request
@@ -299,18 +516,27 @@ impl RequestJson {
             .iter()
             .map(ParamJson::from_param)
             .collect::<Vec<_>>();
+        let (body, body_encoding) = match std::str::from_utf8(&request.body) {
+            Ok(text) => (text.to_string(), BodyEncodingJson::Text),
+            Err(_) => (
+                general_purpose::STANDARD.encode(&request.body),
+                BodyEncodingJson::Base64,
+            ),
+        };
         RequestJson {
             method: request.method.clone(),
             url: request.url.to_string(),
             headers,
             cookies,
             query_string,
+            body,
+            body_encoding,
         }
     }
 }
 
 impl ResponseJson {
-    fn from_response(response: &Response, response_dir: Option<&Path>) -> Result<Self, io::Error> {
+    fn from_response(response: &Response, response_body: ResponseBody) -> Result<Self, io::Error> {
         let http_version = match response.version {
             HttpVersion::Http10 => "HTTP/1.0",
             HttpVersion::Http11 => "HTTP/1.1",
@@ -331,8 +557,8 @@ impl ResponseJson {
             .certificate
             .as_ref()
             .map(CertificateJson::from_certificate);
-        let body = match response_dir {
-            Some(response_dir) => {
+        let (body, body_encoding) = match response_body {
+            ResponseBody::File(response_dir) => {
                 // FIXME: we save the filename and the parent dir: this feature is used in the
                 // context of the JSON report where the response are stored:
                 //
@@ -350,9 +576,21 @@ impl ResponseJson {
                 let file = write_response(response, response_dir)?;
                 let parent = response_dir.components().last().unwrap();
                 let parent: &Path = parent.as_ref();
-                Some(format!("{}/{}", parent.display(), file.display()))
+                (
+                    Some(format!("{}/{}", parent.display(), file.display())),
+                    None,
+                )
             }
-            None => None,
+            // Like the request body, the body received over the wire is exported as is if it's a
+            // valid UTF-8 text, otherwise as base64.
+            ResponseBody::Inline => match std::str::from_utf8(&response.body) {
+                Ok(text) => (Some(text.to_string()), Some(BodyEncodingJson::Text)),
+                Err(_) => (
+                    Some(general_purpose::STANDARD.encode(&response.body)),
+                    Some(BodyEncodingJson::Base64),
+                ),
+            },
+            ResponseBody::Skip => (None, None),
         };
         Ok(ResponseJson {
             http_version: http_version.to_string(),
@@ -361,10 +599,26 @@ impl ResponseJson {
             cookies,
             certificate,
             body,
+            body_encoding,
+            transfer_encoding: response.transfer_encoding(),
+            tls_version: response.tls_version.clone(),
+            // The response body is fully read and kept without decompression, so its size is
+            // always known here.
+            body_size: response.body.len() as i64,
+            headers_size: response.headers_size() as i64,
         })
     }
 }
 
+impl WarningJson {
+    fn from_warning(warning: &Warning) -> Self {
+        WarningJson {
+            code: warning.code().to_string(),
+            message: warning.message(),
+        }
+    }
+}
+
 impl TimingsJson {
     fn from_timings(timings: &Timings) -> Self {
         TimingsJson {
@@ -417,16 +671,30 @@ impl ResponseCookieJson {
             name: c.name.clone(),
             value: c.value.clone(),
             expires: c.expires(),
+            expires_iso: c
+                .expires_date()
+                .map(|d| d.to_rfc3339_opts(SecondsFormat::Secs, true)),
             max_age: c.max_age().map(|m| m.to_string()),
+            max_age_seconds: c.max_age(),
             domain: c.domain(),
             path: c.path(),
             secure: if c.has_secure() { Some(true) } else { None },
             http_only: if c.has_httponly() { Some(true) } else { None },
-            same_site: c.samesite(),
+            same_site: c.samesite().map(|s| normalize_same_site(&s)),
+            same_site_raw: c.samesite(),
         }
     }
 }
 
+/// Normalizes a SameSite cookie attribute `value` to its canonical casing.
+fn normalize_same_site(value: &str) -> String {
+    let value = value.trim();
+    ["Strict", "Lax", "None"]
+        .iter()
+        .find(|canonical| canonical.eq_ignore_ascii_case(value))
+        .map_or(value.to_string(), |canonical| canonical.to_string())
+}
+
 impl CertificateJson {
     fn from_certificate(c: &Certificate) -> Self {
         CertificateJson {
@@ -448,6 +716,24 @@ impl CaptureJson {
     }
 }
 
+impl CaptureResult {
+    /// Serializes a capture to JSON, its value being wrapped in an object with its type (see
+    /// [`crate::runner::Value::to_json_typed`]).
+    ///
+    /// The captures of [`HurlResult::to_json`] are serialized with natural JSON types, which can't
+    /// distinguish a date from a string for instance. This method can be used by consumers that
+    /// need the type of the captured values.
+    pub fn to_json_typed(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "name".to_string(),
+            serde_json::Value::String(self.name.clone()),
+        );
+        map.insert("value".to_string(), self.value.to_json_typed());
+        serde_json::Value::Object(map)
+    }
+}
+
 impl AssertJson {
     fn from_assert(
         a: &AssertResult,
@@ -455,18 +741,80 @@ impl AssertJson {
         filename: &Input,
         entry_src_info: SourceInfo,
     ) -> Self {
-        let message = a.error().map(|err| {
-            err.to_string(
-                &filename.to_string(),
-                content,
-                Some(entry_src_info),
-                OutputFormat::Plain,
-            )
-        });
+        let errors = a
+            .errors()
+            .iter()
+            .map(|err| {
+                let message = err.to_string(
+                    &filename.to_string(),
+                    content,
+                    Some(entry_src_info),
+                    OutputFormat::Plain,
+                );
+                strip_control_chars(&message)
+            })
+            .collect::<Vec<_>>();
         AssertJson {
-            success: a.error().is_none(),
-            message,
+            success: errors.is_empty(),
+            message: errors.first().cloned(),
+            errors,
             line: a.line(),
+            source_info: AssertSourceInfoJson::from_assert(a),
+        }
+    }
+}
+
+/// Removes ANSI escape sequences and control characters (except newlines and tabs) from `text`.
+///
+/// Error messages can embed values coming from the HTTP response or upstream libraries, with
+/// terminal escape sequences that we don't want to leak in a JSON report.
+fn strip_control_chars(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // CSI sequence: ESC [ parameters and intermediate bytes, terminated by a final byte
+            // in the range @ to ~.
+            '\x1b' if chars.peek() == Some(&'[') => {
+                chars.next();
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            '\n' | '\t' => stripped.push(c),
+            c if c.is_control() => {}
+            c => stripped.push(c),
+        }
+    }
+    stripped
+}
+
+impl AssertSourceInfoJson {
+    fn from_assert(a: &AssertResult) -> Self {
+        AssertSourceInfoJson {
+            predicate: SourceInfoJson::from_source_info(a.source_info()),
+            filters: a
+                .filters_source_info()
+                .iter()
+                .map(|s| SourceInfoJson::from_source_info(*s))
+                .collect(),
+        }
+    }
+}
+
+impl SourceInfoJson {
+    fn from_source_info(source_info: SourceInfo) -> Self {
+        SourceInfoJson {
+            start: PosJson {
+                line: source_info.start.line,
+                column: source_info.start.column,
+            },
+            end: PosJson {
+                line: source_info.end.line,
+                column: source_info.end.column,
+            },
         }
     }
 }
@@ -494,3 +842,647 @@ fn write_response(response: &Response, dir: &Path) -> Result<PathBuf, io::Error>
     file.write_all(&response.body)?;
     Ok(relative_path)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use hurl_core::ast::SectionValue;
+    use hurl_core::parser::parse_hurl_file;
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::http::{xml_two_users_http_response, HeaderVec, Timings};
+    use crate::runner::{RunnerError, RunnerErrorKind, WarningKind};
+
+    fn entry_result(entry_index: usize, errors: Vec<RunnerError>) -> EntryResult {
+        let url = "http://localhost:8000/users".parse().unwrap();
+        EntryResult {
+            entry_index,
+            source_info: SourceInfo::new(Pos::new(entry_index, 1), Pos::new(entry_index, 1)),
+            calls: vec![Call {
+                request: Request::new("GET", url, HeaderVec::new(), vec![]),
+                response: xml_two_users_http_response(),
+                timings: Timings::default(),
+                h2_frames: vec![],
+            }],
+            errors,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_to_json_success_bodies() {
+        let error = RunnerError::new(
+            SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9)),
+            RunnerErrorKind::AssertStatus {
+                actual: "200".to_string(),
+            },
+            true,
+        );
+        let result = HurlResult {
+            entries: vec![entry_result(1, vec![]), entry_result(2, vec![error])],
+            duration: Duration::from_millis(100),
+            success: false,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let content =
+            "GET http://localhost:8000/users\nGET http://localhost:8000/users\nHTTP 201\n";
+        let filename = Input::new("test.hurl");
+        let response_dir = std::env::temp_dir().join(format!("hurl-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&response_dir).unwrap();
+        let body = |json: &serde_json::Value, index: usize| {
+            json["entries"][index]["calls"][0]["response"]
+                .get("body")
+                .cloned()
+        };
+
+        let json = result
+            .to_json(content, &filename, Some(&response_dir), true)
+            .unwrap();
+        assert!(body(&json, 0).unwrap().is_string());
+        assert!(body(&json, 1).unwrap().is_string());
+
+        // Only the body of the failed entry is saved, the body of the successful one is null.
+        let json = result
+            .to_json(content, &filename, Some(&response_dir), false)
+            .unwrap();
+        assert_eq!(body(&json, 0), Some(serde_json::Value::Null));
+        assert!(body(&json, 1).unwrap().is_string());
+
+        // Bodies exported inline are filtered the same way.
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        assert_eq!(
+            body(&json, 0),
+            Some(serde_json::Value::String(
+                String::from_utf8(xml_two_users_http_response().body).unwrap()
+            ))
+        );
+        let json = result.to_json(content, &filename, None, false).unwrap();
+        assert_eq!(body(&json, 0), Some(serde_json::Value::Null));
+        assert!(body(&json, 1).unwrap().is_string());
+        assert!(HurlResult::is_deserializable(&json));
+
+        std::fs::remove_dir_all(&response_dir).unwrap();
+    }
+
+    #[test]
+    fn test_to_json_warnings() {
+        // An entry served by an old TLS 1.1 server.
+        let url = "https://localhost:8001/hello".parse().unwrap();
+        let mut response = xml_two_users_http_response();
+        response.tls_version = Some("TLSv1.1".to_string());
+        let calls = vec![Call {
+            request: Request::new("GET", url, HeaderVec::new(), vec![]),
+            response,
+            timings: Timings::default(),
+            h2_frames: vec![],
+        }];
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let warnings = vec![Warning::new(
+            source_info,
+            WarningKind::DeprecatedTlsVersion {
+                version: "TLSv1.1".to_string(),
+            },
+        )];
+        let result = HurlResult {
+            entries: vec![
+                EntryResult {
+                    calls,
+                    warnings,
+                    ..Default::default()
+                },
+                entry_result(2, vec![]),
+            ],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        assert_eq!(result.warnings().len(), 1);
+
+        let content = "GET https://localhost:8001/hello\nGET http://localhost:8000/users\n";
+        let filename = Input::new("test.hurl");
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        assert_eq!(
+            json["entries"][0]["warnings"],
+            serde_json::json!([{
+                "code": "deprecated_tls_version",
+                "message": "deprecated TLS version TLSv1.1 negotiated, TLS 1.2 or later should be used"
+            }])
+        );
+        assert_eq!(
+            json["entries"][0]["calls"][0]["response"]["tlsVersion"],
+            "TLSv1.1"
+        );
+        assert!(json["entries"][1].get("warnings").is_none());
+        assert!(HurlResult::is_deserializable(&json));
+    }
+
+    #[test]
+    fn test_capture_to_json_typed() {
+        let capture = CaptureResult {
+            name: "count".to_string(),
+            value: crate::runner::Value::Number(crate::runner::Number::Integer(3)),
+        };
+        assert_eq!(
+            capture.to_json_typed(),
+            serde_json::json!({"name": "count", "value": {"type": "number", "value": 3}})
+        );
+        // The default serialization is unchanged.
+        assert_eq!(
+            serde_json::to_value(CaptureJson::from_capture(&capture)).unwrap(),
+            serde_json::json!({"name": "count", "value": 3})
+        );
+    }
+
+    #[test]
+    fn test_to_json_schema_version() {
+        let result = HurlResult {
+            entries: vec![entry_result(1, vec![])],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let content = "GET http://localhost:8000/users\n";
+        let filename = Input::new("test.hurl");
+
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        assert_eq!(json["schemaVersion"], JSON_SCHEMA_VERSION);
+        assert!(HurlResult::is_deserializable(&json));
+
+        // The schema version is serialized first.
+        let serialized = serde_json::to_string(&VersionedResult(&json)).unwrap();
+        assert!(serialized.starts_with(&format!(
+            r#"{{"schemaVersion":"{JSON_SCHEMA_VERSION}","cookies":[],"#
+        )));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&serialized).unwrap(),
+            json
+        );
+
+        // Results serialized before the schema version was introduced are still readable.
+        let mut json = json;
+        json.as_object_mut().unwrap().remove("schemaVersion");
+        assert!(HurlResult::is_deserializable(&json));
+    }
+
+    #[test]
+    fn test_to_json_connection_reused() {
+        let url = "http://localhost:8000/users"
+            .parse::<crate::http::Url>()
+            .unwrap();
+        let call = |connect: u64| Call {
+            request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+            response: xml_two_users_http_response(),
+            timings: Timings {
+                connect: Duration::from_millis(connect),
+                connection_id: Some(7),
+                ..Default::default()
+            },
+            h2_frames: vec![],
+        };
+        // The second call reuses the connection opened by the first call.
+        let result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                calls: vec![call(5), call(0)],
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let content = "GET http://localhost:8000/users\n";
+        let filename = Input::new("test.hurl");
+
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        let calls = &json["entries"][0]["calls"];
+        assert_eq!(calls[0]["connectionReused"], false);
+        assert_eq!(calls[0]["connectionId"], 7);
+        assert_eq!(calls[1]["connectionReused"], true);
+        assert_eq!(calls[1]["connectionId"], 7);
+        assert!(HurlResult::is_deserializable(&json));
+    }
+
+    #[test]
+    fn test_to_json_h2_frames() {
+        let url = "https://localhost:8001/users"
+            .parse::<crate::http::Url>()
+            .unwrap();
+        let frame = |frame_type: &str, stream_id: u32, length: Option<u32>| H2Frame {
+            frame_type: frame_type.to_string(),
+            stream_id,
+            length,
+        };
+        let call = |h2_frames: Vec<H2Frame>| Call {
+            request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+            response: xml_two_users_http_response(),
+            timings: Timings::default(),
+            h2_frames,
+        };
+        // Frames of an HTTP/2 call traced in very verbose mode, followed by a call without frames.
+        let frames = vec![
+            frame("SETTINGS", 0, Some(18)),
+            frame("WINDOW_UPDATE", 0, None),
+            frame("HEADERS", 1, Some(33)),
+            frame("HEADERS", 1, Some(47)),
+            frame("DATA", 1, Some(13)),
+        ];
+        let result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                calls: vec![call(frames), call(vec![])],
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let content = "GET https://localhost:8001/users\n";
+        let filename = Input::new("test.hurl");
+
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        let calls = &json["entries"][0]["calls"];
+        assert_eq!(
+            calls[0]["h2Frames"],
+            serde_json::json!([
+                {"type": "SETTINGS", "streamId": 0, "length": 18},
+                {"type": "WINDOW_UPDATE", "streamId": 0},
+                {"type": "HEADERS", "streamId": 1, "length": 33},
+                {"type": "HEADERS", "streamId": 1, "length": 47},
+                {"type": "DATA", "streamId": 1, "length": 13},
+            ])
+        );
+        assert!(calls[1].get("h2Frames").is_none());
+        assert!(HurlResult::is_deserializable(&json));
+    }
+
+    #[test]
+    fn test_to_json_transfer_encoding() {
+        let url = "http://localhost:8000/users"
+            .parse::<crate::http::Url>()
+            .unwrap();
+        let mut chunked_response = xml_two_users_http_response();
+        chunked_response
+            .headers
+            .push(Header::new("Transfer-Encoding", "chunked"));
+        let call = |response: Response| Call {
+            request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+            response,
+            timings: Timings::default(),
+            h2_frames: vec![],
+        };
+        let result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                calls: vec![call(chunked_response), call(xml_two_users_http_response())],
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let content = "GET http://localhost:8000/users\n";
+        let filename = Input::new("test.hurl");
+
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        let calls = &json["entries"][0]["calls"];
+        assert_eq!(calls[0]["response"]["transferEncoding"], "chunked");
+        assert!(calls[1]["response"].get("transferEncoding").is_none());
+        assert!(HurlResult::is_deserializable(&json));
+    }
+
+    #[test]
+    fn test_to_json_bodies() {
+        let url = "http://localhost:8000/users"
+            .parse::<crate::http::Url>()
+            .unwrap();
+        // A text request body with an XML response, and a binary request body with a binary
+        // response.
+        let text_call = Call {
+            request: Request::new(
+                "POST",
+                url.clone(),
+                HeaderVec::new(),
+                b"{\"name\":\"Bob\"}".to_vec(),
+            ),
+            response: xml_two_users_http_response(),
+            timings: Timings::default(),
+            h2_frames: vec![],
+        };
+        let binary_call = Call {
+            request: Request::new("POST", url, HeaderVec::new(), vec![0xff, 0xfe, 0x00]),
+            response: Response {
+                body: vec![0xca, 0xfe, 0xba, 0xbe],
+                ..xml_two_users_http_response()
+            },
+            timings: Timings::default(),
+            h2_frames: vec![],
+        };
+        let empty_call = Call {
+            request: Request::new(
+                "GET",
+                "http://localhost:8000/empty".parse().unwrap(),
+                HeaderVec::new(),
+                vec![],
+            ),
+            response: Response {
+                body: vec![],
+                ..xml_two_users_http_response()
+            },
+            timings: Timings::default(),
+            h2_frames: vec![],
+        };
+        // A latin-1 text response: the body received is not valid UTF-8.
+        let mut latin1_response = Response {
+            body: b"caf\xe9".to_vec(),
+            ..xml_two_users_http_response()
+        };
+        latin1_response.headers = HeaderVec::new();
+        latin1_response.headers.push(Header::new(
+            "Content-Type",
+            "text/plain; charset=iso-8859-1",
+        ));
+        let latin1_call = Call {
+            request: Request::new(
+                "GET",
+                "http://localhost:8000/latin1".parse().unwrap(),
+                HeaderVec::new(),
+                vec![],
+            ),
+            response: latin1_response,
+            timings: Timings::default(),
+            h2_frames: vec![],
+        };
+        let result = HurlResult {
+            entries: vec![EntryResult {
+                calls: vec![text_call, binary_call, empty_call, latin1_call],
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let content = "POST http://localhost:8000/users\n";
+        let filename = Input::new("test.hurl");
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        let call = |index: usize| json["entries"][0]["calls"][index].clone();
+
+        assert_eq!(call(0)["request"]["body"], "{\"name\":\"Bob\"}");
+        assert_eq!(call(0)["request"]["bodyEncoding"], "text");
+        assert_eq!(
+            call(0)["response"]["body"],
+            String::from_utf8(xml_two_users_http_response().body).unwrap()
+        );
+        assert_eq!(call(0)["response"]["bodyEncoding"], "text");
+
+        assert_eq!(call(1)["request"]["body"], "//4A");
+        assert_eq!(call(1)["request"]["bodyEncoding"], "base64");
+        assert_eq!(call(1)["response"]["body"], "yv66vg==");
+        assert_eq!(call(1)["response"]["bodyEncoding"], "base64");
+
+        // Empty bodies are always present.
+        assert_eq!(call(2)["request"]["body"], "");
+        assert_eq!(call(2)["request"]["bodyEncoding"], "text");
+        assert_eq!(call(2)["response"]["body"], "");
+        assert_eq!(call(2)["response"]["bodyEncoding"], "text");
+
+        // Sizes are exported in bytes, like HAR.
+        assert_eq!(call(1)["response"]["bodySize"], 4);
+        assert_eq!(call(2)["response"]["bodySize"], 0);
+        assert_eq!(
+            call(2)["response"]["headersSize"],
+            xml_two_users_http_response().headers_size() as i64
+        );
+
+        // Bodies are exported as received, not decoded with their charset.
+        assert_eq!(call(3)["response"]["body"], "Y2Fm6Q==");
+        assert_eq!(call(3)["response"]["bodyEncoding"], "base64");
+        assert_eq!(call(3)["response"]["bodySize"], 4);
+
+        assert!(HurlResult::is_deserializable(&json));
+    }
+
+    #[test]
+    fn test_to_json_memory_input() {
+        let content = "GET http://localhost:8000/users\nHTTP 200\n[Asserts]\nbody == \"red\"\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let source_info = SourceInfo::new(Pos::new(4, 6), Pos::new(4, 8));
+        let error = RunnerError::new(
+            source_info,
+            RunnerErrorKind::AssertFailure {
+                actual: "string <blue>".to_string(),
+                expected: "string <red>".to_string(),
+                type_mismatch: false,
+                actual_value: None,
+            },
+            true,
+        );
+        let result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: hurl_file.entries[0].source_info(),
+                asserts: vec![AssertResult::Explicit {
+                    actual: Ok(None),
+                    source_info,
+                    filters_source_info: vec![],
+                    predicate_result: Some(Err(error)),
+                }],
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(100),
+            success: false,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let filename = Input::from_memory("suite/users", content);
+
+        let json = result.to_json(content, &filename, None, true).unwrap();
+        assert_eq!(json["filename"], "suite/users");
+        let message = json["entries"][0]["asserts"][0]["message"]
+            .as_str()
+            .unwrap();
+        assert!(message.starts_with("Assert failure\n  --> suite/users:4:6\n"));
+        assert!(HurlResult::is_deserializable(&json));
+    }
+
+    #[test]
+    fn test_assert_source_info() {
+        let content = "GET http://localhost:8000/users\nHTTP 200\n[Asserts]\njsonpath \"$.users\" nth 0 count == 2\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let response = hurl_file.entries[0].response.as_ref().unwrap();
+        let SectionValue::Asserts(asserts) = &response.sections[0].value else {
+            panic!("expected asserts section");
+        };
+        let assert = &asserts[0];
+        let result = AssertResult::Explicit {
+            actual: Ok(None),
+            source_info: assert.predicate.predicate_func.source_info,
+            filters_source_info: assert.filters.iter().map(|(_, f)| f.source_info).collect(),
+            predicate_result: Some(Ok(())),
+        };
+        let filename = Input::new("test.hurl");
+        let entry_src_info = hurl_file.entries[0].source_info();
+        let json = AssertJson::from_assert(&result, content, &filename, entry_src_info);
+        let json = serde_json::to_value(json).unwrap();
+
+        assert_eq!(
+            json["sourceInfo"],
+            serde_json::json!({
+                "predicate": {"start": {"line": 4, "column": 32}, "end": {"line": 4, "column": 36}},
+                "filters": [
+                    {"start": {"line": 4, "column": 20}, "end": {"line": 4, "column": 25}},
+                    {"start": {"line": 4, "column": 26}, "end": {"line": 4, "column": 31}}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_assert_message_without_control_chars() {
+        let content = "GET http://localhost:8000/users\nHTTP 200\n[Asserts]\nbody == \"red\"\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let source_info = SourceInfo::new(Pos::new(4, 6), Pos::new(4, 8));
+        let error = RunnerError::new(
+            source_info,
+            RunnerErrorKind::AssertFailure {
+                actual: "string <\x1b[31mred\x1b[0m\x07>".to_string(),
+                expected: "string <red>".to_string(),
+                type_mismatch: false,
+                actual_value: None,
+            },
+            true,
+        );
+        let result = AssertResult::Explicit {
+            actual: Ok(None),
+            source_info,
+            filters_source_info: vec![],
+            predicate_result: Some(Err(error)),
+        };
+        let filename = Input::new("test.hurl");
+        let entry_src_info = hurl_file.entries[0].source_info();
+        let json = AssertJson::from_assert(&result, content, &filename, entry_src_info);
+
+        let message = json.message.unwrap();
+        assert!(message.contains("actual:   string <red>\n"));
+        assert!(!message.chars().any(|c| c.is_control() && c != '\n'));
+    }
+
+    #[test]
+    fn test_assert_multiple_errors() {
+        let content = "GET http://localhost:8000/users\nHTTP 200\n[Asserts]\njsonpath \"$.name\" toInt == 3\n";
+        let hurl_file = parse_hurl_file(content).unwrap();
+        let filter_source_info = SourceInfo::new(Pos::new(4, 19), Pos::new(4, 24));
+        let filter_error = RunnerError::new(
+            filter_source_info,
+            RunnerErrorKind::FilterInvalidInput("string <Bob>".to_string()),
+            true,
+        );
+        let predicate_error = RunnerError::new(
+            SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0)),
+            RunnerErrorKind::AssertFailure {
+                actual: "none".to_string(),
+                expected: "int <3>".to_string(),
+                type_mismatch: false,
+                actual_value: None,
+            },
+            true,
+        );
+        let result = AssertResult::Explicit {
+            actual: Err(filter_error),
+            source_info: SourceInfo::new(Pos::new(4, 25), Pos::new(4, 27)),
+            filters_source_info: vec![filter_source_info],
+            predicate_result: Some(Err(predicate_error)),
+        };
+        let filename = Input::new("test.hurl");
+        let entry_src_info = hurl_file.entries[0].source_info();
+        let json = AssertJson::from_assert(&result, content, &filename, entry_src_info);
+
+        assert!(!json.success);
+        assert_eq!(json.errors.len(), 2);
+        assert!(json.errors[0].starts_with("Filter error"));
+        assert!(json.errors[1].starts_with("Assert failure"));
+        assert_eq!(json.message.as_ref(), Some(&json.errors[0]));
+    }
+
+    #[test]
+    fn test_strip_control_chars() {
+        assert_eq!(strip_control_chars("Hello"), "Hello");
+        assert_eq!(
+            strip_control_chars("\x1b[1;31merror\x1b[0m: line 1\n\tline 2\r"),
+            "error: line 1\n\tline 2"
+        );
+        assert_eq!(strip_control_chars("bell\x07 \u{9b}"), "bell ");
+    }
+
+    #[test]
+    fn test_response_cookie_max_age() {
+        let cookie = ResponseCookie::parse("id=a3fWa; Max-Age=3600; Path=/").unwrap();
+        let json = serde_json::to_value(ResponseCookieJson::from_cookie(&cookie)).unwrap();
+        assert_eq!(json["max_age"], serde_json::json!("3600"));
+        assert_eq!(json["max_age_seconds"], serde_json::json!(3600));
+
+        let cookie = ResponseCookie::parse("id=a3fWa; Max-Age=-1").unwrap();
+        let json = serde_json::to_value(ResponseCookieJson::from_cookie(&cookie)).unwrap();
+        assert_eq!(json["max_age_seconds"], serde_json::json!(-1));
+
+        let cookie = ResponseCookie::parse("id=a3fWa").unwrap();
+        let json = serde_json::to_value(ResponseCookieJson::from_cookie(&cookie)).unwrap();
+        assert!(json.get("max_age_seconds").is_none());
+    }
+
+    #[test]
+    fn test_response_cookie_expires_iso() {
+        let cookie =
+            ResponseCookie::parse("id=a3fWa; Expires=Wed, 21 Oct 2030 07:28:00 GMT").unwrap();
+        let json = serde_json::to_value(ResponseCookieJson::from_cookie(&cookie)).unwrap();
+        assert_eq!(
+            json["expires"],
+            serde_json::json!("Wed, 21 Oct 2030 07:28:00 GMT")
+        );
+        assert_eq!(
+            json["expires_iso"],
+            serde_json::json!("2030-10-21T07:28:00Z")
+        );
+
+        let cookie = ResponseCookie::parse("id=a3fWa; Expires=never").unwrap();
+        let json = serde_json::to_value(ResponseCookieJson::from_cookie(&cookie)).unwrap();
+        assert_eq!(json["expires"], serde_json::json!("never"));
+        assert!(json.get("expires_iso").is_none());
+    }
+
+    #[test]
+    fn test_response_cookie_same_site() {
+        let same_site = |header: &str| {
+            let cookie = ResponseCookie::parse(header).unwrap();
+            let json = serde_json::to_value(ResponseCookieJson::from_cookie(&cookie)).unwrap();
+            (json["same_site"].clone(), json["same_site_raw"].clone())
+        };
+        assert_eq!(
+            same_site("id=a3fWa; SameSite=lax"),
+            (serde_json::json!("Lax"), serde_json::json!("lax"))
+        );
+        assert_eq!(
+            same_site("id=a3fWa; SameSite=STRICT"),
+            (serde_json::json!("Strict"), serde_json::json!("STRICT"))
+        );
+        assert_eq!(
+            same_site("id=a3fWa; SameSite=none; Secure"),
+            (serde_json::json!("None"), serde_json::json!("none"))
+        );
+        assert_eq!(
+            same_site("id=a3fWa; SameSite=Relaxed"),
+            (serde_json::json!("Relaxed"), serde_json::json!("Relaxed"))
+        );
+    }
+}
@@ -0,0 +1,342 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::runner::{Number, Value, VariableSet};
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Describes the shape of a [`Value`] with a [JSON Schema](https://json-schema.org).
+///
+/// The schema matches the JSON serialization of the value (see [`Value::to_json`]): for instance,
+/// bytes are described as base64 encoded strings and nodesets as objects with a `type` and a `size`
+/// property.
+impl Value {
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        match self {
+            Value::Bool(_) => schema_type("boolean"),
+            Value::Bytes(_) => {
+                let mut map = type_map("string");
+                map.insert(
+                    "contentEncoding".to_string(),
+                    serde_json::Value::String("base64".to_string()),
+                );
+                serde_json::Value::Object(map)
+            }
+            // Dates are serialized as `2024-01-10 12:45:15 UTC`, which is not a RFC 3339
+            // `date-time` format, so we don't set a `format` keyword.
+            Value::Date(_) => schema_type("string"),
+            Value::List(values) => {
+                let mut map = type_map("array");
+                let mut items: Vec<serde_json::Value> = vec![];
+                for value in values {
+                    let schema = value.to_json_schema();
+                    if !items.contains(&schema) {
+                        items.push(schema);
+                    }
+                }
+                match items.len() {
+                    0 => {}
+                    1 => {
+                        map.insert("items".to_string(), items.remove(0));
+                    }
+                    _ => {
+                        let mut any_of = serde_json::Map::new();
+                        any_of.insert("anyOf".to_string(), serde_json::Value::Array(items));
+                        map.insert("items".to_string(), serde_json::Value::Object(any_of));
+                    }
+                }
+                serde_json::Value::Object(map)
+            }
+            Value::Nodeset(_) => tagged_object_schema("nodeset", true),
+            Value::Null => schema_type("null"),
+            Value::Number(n) => n.to_json_schema(),
+            Value::Object(key_values) => {
+                let properties = key_values
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_json_schema()))
+                    .collect::<Vec<_>>();
+                object_schema(properties)
+            }
+            Value::Regex(_) => schema_type("string"),
+            Value::String(_) => schema_type("string"),
+            Value::Unit => tagged_object_schema("unit", false),
+        }
+    }
+}
+
+impl Number {
+    /// Describes the shape of a number with a JSON Schema.
+    ///
+    /// Big integers are serialized as JSON numbers, so they are described as `number`.
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        match self {
+            Number::Integer(_) => schema_type("integer"),
+            Number::Float(_) | Number::BigInteger(_) => schema_type("number"),
+        }
+    }
+}
+
+impl VariableSet {
+    /// Describes the shape of this variable set with a JSON Schema.
+    ///
+    /// The variable set is described as an object, each variable being a required property. This
+    /// can be used to validate captured data by tools consuming Hurl results.
+    pub fn to_json_schema(&self) -> serde_json::Value {
+        let properties = self
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_json_schema()))
+            .collect::<Vec<_>>();
+        let mut schema = object_schema(properties);
+        if let serde_json::Value::Object(map) = &mut schema {
+            map.insert(
+                "$schema".to_string(),
+                serde_json::Value::String(JSON_SCHEMA_DIALECT.to_string()),
+            );
+        }
+        schema
+    }
+}
+
+fn type_map(name: &str) -> serde_json::Map<String, serde_json::Value> {
+    let mut map = serde_json::Map::new();
+    map.insert(
+        "type".to_string(),
+        serde_json::Value::String(name.to_string()),
+    );
+    map
+}
+
+fn schema_type(name: &str) -> serde_json::Value {
+    serde_json::Value::Object(type_map(name))
+}
+
+/// Returns an object schema with `properties`, all the properties being required.
+fn object_schema(properties: Vec<(String, serde_json::Value)>) -> serde_json::Value {
+    let mut names = properties
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    names.sort();
+    let properties = properties.into_iter().collect::<serde_json::Map<_, _>>();
+
+    let mut map = type_map("object");
+    map.insert(
+        "properties".to_string(),
+        serde_json::Value::Object(properties),
+    );
+    map.insert(
+        "required".to_string(),
+        serde_json::Value::Array(names.into_iter().map(serde_json::Value::String).collect()),
+    );
+    serde_json::Value::Object(map)
+}
+
+/// Returns the schema of values without native JSON representation, serialized as an object with
+/// a `type` field (and optionally a `size` field).
+fn tagged_object_schema(tag: &str, has_size: bool) -> serde_json::Value {
+    let mut tag_schema = type_map("string");
+    tag_schema.insert(
+        "const".to_string(),
+        serde_json::Value::String(tag.to_string()),
+    );
+    let mut properties = vec![("type".to_string(), serde_json::Value::Object(tag_schema))];
+    if has_size {
+        properties.push(("size".to_string(), schema_type("integer")));
+    }
+    object_schema(properties)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde_json::json;
+
+    use crate::runner::{Number, Value, VariableSet};
+
+    /// Validates a JSON `instance` against a `schema`, supporting only the keywords generated
+    /// by [`Value::to_json_schema`].
+    fn is_valid(schema: &serde_json::Value, instance: &serde_json::Value) -> bool {
+        let type_ok = match schema["type"].as_str() {
+            Some("string") => instance.is_string(),
+            Some("integer") => instance.is_i64() || instance.is_u64(),
+            Some("number") => instance.is_number(),
+            Some("boolean") => instance.is_boolean(),
+            Some("null") => instance.is_null(),
+            Some("array") => instance.is_array(),
+            Some("object") => instance.is_object(),
+            _ => true,
+        };
+        if !type_ok {
+            return false;
+        }
+        if let Some(value) = schema.get("const") {
+            if value != instance {
+                return false;
+            }
+        }
+        if schema["format"] == "date-time" {
+            let date = instance.as_str().unwrap_or_default();
+            if DateTime::parse_from_rfc3339(date).is_err() {
+                return false;
+            }
+        }
+        if let Some(required) = schema["required"].as_array() {
+            if required.iter().any(|name| {
+                let name = name.as_str().unwrap_or_default();
+                instance.get(name).is_none()
+            }) {
+                return false;
+            }
+        }
+        if let Some(properties) = schema["properties"].as_object() {
+            if properties.iter().any(|(name, property)| {
+                instance
+                    .get(name)
+                    .is_some_and(|value| !is_valid(property, value))
+            }) {
+                return false;
+            }
+        }
+        if let Some(items) = schema.get("items") {
+            let values = instance.as_array().cloned().unwrap_or_default();
+            if values.iter().any(|value| !is_valid(items, value)) {
+                return false;
+            }
+        }
+        if let Some(any_of) = schema["anyOf"].as_array() {
+            if !any_of.iter().any(|schema| is_valid(schema, instance)) {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn test_value_json_schema() {
+        assert_eq!(
+            Value::Number(Number::Integer(1)).to_json_schema(),
+            json!({"type": "integer"})
+        );
+        assert_eq!(
+            Value::List(vec![
+                Value::Number(Number::Integer(1)),
+                Value::Number(Number::Integer(2)),
+            ])
+            .to_json_schema(),
+            json!({"type": "array", "items": {"type": "integer"}})
+        );
+        assert_eq!(
+            Value::List(vec![
+                Value::Number(Number::Integer(1)),
+                Value::String("a".to_string()),
+            ])
+            .to_json_schema(),
+            json!({"type": "array", "items": {"anyOf": [{"type": "integer"}, {"type": "string"}]}})
+        );
+        assert_eq!(
+            Value::Nodeset(3).to_json_schema(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "size": {"type": "integer"},
+                    "type": {"type": "string", "const": "nodeset"}
+                },
+                "required": ["size", "type"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_variable_set_json_schema() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("id".to_string(), Value::Number(Number::Integer(1234)))
+            .unwrap();
+        variables
+            .insert("name".to_string(), Value::String("Bob".to_string()))
+            .unwrap();
+        variables
+            .insert("ratio".to_string(), Value::Number(Number::Float(0.5)))
+            .unwrap();
+        variables.insert("token".to_string(), Value::Null).unwrap();
+        variables
+            .insert("data".to_string(), Value::Bytes(vec![0xff]))
+            .unwrap();
+        variables
+            .insert(
+                "user".to_string(),
+                Value::Object(vec![
+                    ("active".to_string(), Value::Bool(true)),
+                    ("tags".to_string(), Value::List(vec![])),
+                ]),
+            )
+            .unwrap();
+
+        assert_eq!(
+            variables.to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "data": {"type": "string", "contentEncoding": "base64"},
+                    "id": {"type": "integer"},
+                    "name": {"type": "string"},
+                    "ratio": {"type": "number"},
+                    "token": {"type": "null"},
+                    "user": {
+                        "type": "object",
+                        "properties": {
+                            "active": {"type": "boolean"},
+                            "tags": {"type": "array"}
+                        },
+                        "required": ["active", "tags"]
+                    }
+                },
+                "required": ["data", "id", "name", "ratio", "token", "user"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_date_capture_json_schema() {
+        let date = Utc.with_ymd_and_hms(2024, 1, 10, 12, 45, 15).unwrap();
+        let mut variables = VariableSet::new();
+        variables
+            .insert("created".to_string(), Value::Date(date))
+            .unwrap();
+        variables
+            .insert(
+                "dates".to_string(),
+                Value::List(vec![Value::Date(date), Value::Null]),
+            )
+            .unwrap();
+
+        let schema = variables.to_json_schema();
+        assert_eq!(schema["properties"]["created"], json!({"type": "string"}));
+
+        let instance = variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_json()))
+            .collect::<serde_json::Map<_, _>>();
+        let instance = serde_json::Value::Object(instance);
+        assert!(is_valid(&schema, &instance));
+
+        // The validation fails if dates are declared with a RFC 3339 format.
+        let schema = json!({"type": "string", "format": "date-time"});
+        assert!(!is_valid(&schema, &Value::Date(date).to_json()));
+    }
+}
@@ -83,6 +83,42 @@ impl Value {
     }
 }
 
+impl Value {
+    /// Serializes a [`Value`] to JSON, wrapped in an object with its type.
+    ///
+    /// Contrary to [`Value::to_json`], the type of the value can be recovered: for instance, a
+    /// date and a string are both serialized as JSON strings, but with a different `type` field:
+    ///
+    /// ```json
+    /// {
+    ///   "type": "date",
+    ///   "value": "2024-01-10 12:45:15.234 UTC"
+    /// }
+    /// ```
+    pub fn to_json_typed(&self) -> serde_json::Value {
+        let _type = match self {
+            Value::Bool(_) => "boolean",
+            Value::Bytes(_) => "bytes",
+            Value::Date(_) => "date",
+            Value::List(_) => "list",
+            Value::Nodeset(_) => "node",
+            Value::Null => "null",
+            Value::Number(_) => "number",
+            Value::Object(_) => "object",
+            Value::Regex(_) => "regex",
+            Value::String(_) => "string",
+            Value::Unit => "unit",
+        };
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "type".to_string(),
+            serde_json::Value::String(_type.to_string()),
+        );
+        map.insert("value".to_string(), self.to_json());
+        serde_json::Value::Object(map)
+    }
+}
+
 impl Number {
     /// Serializes a number to JSON.
     ///
@@ -101,3 +137,48 @@ impl Number {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_to_json_typed() {
+        assert_eq!(
+            Value::String("Bob".to_string()).to_json_typed(),
+            json!({"type": "string", "value": "Bob"})
+        );
+        assert_eq!(
+            Value::Number(Number::Integer(42)).to_json_typed(),
+            json!({"type": "number", "value": 42})
+        );
+        assert_eq!(
+            Value::Number(Number::Float(1.5)).to_json_typed(),
+            json!({"type": "number", "value": 1.5})
+        );
+        assert_eq!(
+            Value::Bool(true).to_json_typed(),
+            json!({"type": "boolean", "value": true})
+        );
+        assert_eq!(
+            Value::List(vec![Value::Number(Number::Integer(1)), Value::Null]).to_json_typed(),
+            json!({"type": "list", "value": [1, null]})
+        );
+        assert_eq!(
+            Value::Nodeset(2).to_json_typed(),
+            json!({"type": "node", "value": {"type": "nodeset", "size": 2}})
+        );
+        assert_eq!(
+            Value::Bytes(b"Hello".to_vec()).to_json_typed(),
+            json!({"type": "bytes", "value": "SGVsbG8="})
+        );
+        let date = Utc.with_ymd_and_hms(2024, 1, 10, 12, 45, 15).unwrap();
+        assert_eq!(
+            Value::Date(date).to_json_typed(),
+            json!({"type": "date", "value": "2024-01-10 12:45:15 UTC"})
+        );
+    }
+}
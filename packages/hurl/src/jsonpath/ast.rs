@@ -22,14 +22,22 @@
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Query {
     pub selectors: Vec<Selector>,
+    pub function: Option<Function>, // applied to the selected nodes
+}
+
+/// A function applied to the nodes selected by a query (for instance `$.items.length()`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Function {
+    Length,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Selector {
     Wildcard,
     NameChild(String),
-    ArrayIndex(usize),        // one unique index
-    ArrayIndices(Vec<usize>), // two or more indexes (separated by comma)
+    NameChildren(Vec<String>), // two or more keys (separated by comma)
+    ArrayIndex(i64),           // one unique index, negative from the end of the array
+    ArrayIndices(Vec<i64>),    // two or more indexes (separated by comma)
     ArraySlice(Slice),
     ArrayWildcard,
     Filter(Predicate),
@@ -37,12 +45,12 @@ pub enum Selector {
     RecursiveKey(String),
 }
 
-// For the time-being
-// use simple slice start:end (without the step)
+// Slice start:end:step, bounds are optional and can be negative (counted from the end of the array)
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Slice {
     pub start: Option<i64>,
     pub end: Option<i64>,
+    pub step: Option<i64>, // never zero
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -63,6 +71,13 @@ pub enum PredicateFunc {
     GreaterThanOrEqual(Number),
     LessThan(Number),
     LessThanOrEqual(Number),
+    // Comparisons against another key of the current node (`@.price<@.limit`)
+    EqualKey(Vec<String>),
+    NotEqualKey(Vec<String>),
+    GreaterThanKey(Vec<String>),
+    GreaterThanOrEqualKey(Vec<String>),
+    LessThanKey(Vec<String>),
+    LessThanOrEqualKey(Vec<String>),
 }
 
 // Number
@@ -16,6 +16,11 @@
  *
  */
 
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::jsonpath::ast::{Function, Slice};
+
 pub mod query;
 mod selector;
 
@@ -24,3 +29,184 @@ pub enum JsonpathResult {
     SingleEntry(serde_json::Value),     // returned by a "definite" path
     Collection(Vec<serde_json::Value>), // returned by a "indefinite" path
 }
+
+/// Error raised when the `function` of a query can't be applied to a value of type `value_type`
+/// (for instance, the length of a number).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionError {
+    pub function: Function,
+    pub value_type: String,
+}
+
+/// Error raised when a query can't be evaluated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvalError {
+    /// The function of the query can't be applied to the selected nodes.
+    Function(FunctionError),
+    /// The query has a function, while the locations of the selected nodes are expected.
+    UnexpectedFunction(Function),
+    /// The evaluation has exceeded its `timeout`.
+    Timeout { timeout: Duration },
+}
+
+impl From<FunctionError> for EvalError {
+    fn from(error: FunctionError) -> Self {
+        EvalError::Function(error)
+    }
+}
+
+/// Deadline of a query evaluation, checked for each JSON node visited by a selector.
+///
+/// A pathological query (nested recursive descents on a large document for instance) is aborted
+/// once its deadline has passed instead of running indefinitely.
+#[derive(Clone, Debug)]
+struct Deadline {
+    timeout: Duration,
+    /// The instant of the deadline, `None` if it can't be represented (no deadline).
+    instant: Option<Instant>,
+}
+
+impl Deadline {
+    /// Creates a deadline `timeout` from now.
+    fn new(timeout: Duration) -> Self {
+        Deadline {
+            timeout,
+            instant: Instant::now().checked_add(timeout),
+        }
+    }
+
+    /// Returns an error if this deadline has passed.
+    fn check(&self) -> Result<(), EvalError> {
+        match self.instant {
+            Some(instant) if Instant::now() >= instant => Err(EvalError::Timeout {
+                timeout: self.timeout,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Function::Length => write!(f, "length()"),
+        }
+    }
+}
+
+/// Resolves an array `index` for an array of length `len`: a negative index is counted from the
+/// end of the array (`-1` is the last element). Returns `None` if the index is out of range.
+fn array_index(index: i64, len: usize) -> Option<usize> {
+    let index = if index < 0 { len as i64 + index } else { index };
+    if index < 0 || index >= len as i64 {
+        None
+    } else {
+        Some(index as usize)
+    }
+}
+
+/// Returns the indices selected by `slice` in an array of length `len`, in selection order.
+/// Out-of-range bounds are clamped to the array; a negative step selects backwards.
+fn slice_indices(slice: &Slice, len: usize) -> Vec<usize> {
+    let len = len as i64;
+    let step = slice.step.unwrap_or(1);
+    let normalize = |n: i64| if n < 0 { len + n } else { n };
+    let mut indices = vec![];
+    if step > 0 {
+        let lower = slice.start.map_or(0, normalize).clamp(0, len);
+        let upper = slice.end.map_or(len, normalize).clamp(0, len);
+        let mut i = lower;
+        while i < upper {
+            indices.push(i as usize);
+            i = match i.checked_add(step) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    } else if step < 0 {
+        let upper = slice.start.map_or(len - 1, normalize).clamp(-1, len - 1);
+        let lower = slice.end.map_or(-1, normalize).clamp(-1, len - 1);
+        let mut i = upper;
+        while lower < i {
+            indices.push(i as usize);
+            i = match i.checked_add(step) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_array_index() {
+        assert_eq!(array_index(0, 3), Some(0));
+        assert_eq!(array_index(2, 3), Some(2));
+        assert_eq!(array_index(3, 3), None);
+        assert_eq!(array_index(-1, 3), Some(2));
+        assert_eq!(array_index(-3, 3), Some(0));
+        assert_eq!(array_index(-4, 3), None);
+        assert_eq!(array_index(-1, 0), None);
+    }
+
+    fn slice(start: Option<i64>, end: Option<i64>, step: Option<i64>) -> Slice {
+        Slice { start, end, step }
+    }
+
+    #[test]
+    fn test_slice_indices() {
+        assert_eq!(slice_indices(&slice(None, None, None), 4), vec![0, 1, 2, 3]);
+        assert_eq!(slice_indices(&slice(Some(1), Some(3), None), 4), vec![1, 2]);
+        assert_eq!(slice_indices(&slice(Some(-2), None, None), 4), vec![2, 3]);
+        assert_eq!(
+            slice_indices(&slice(None, Some(-1), None), 4),
+            vec![0, 1, 2]
+        );
+        assert_eq!(slice_indices(&slice(None, None, Some(2)), 5), vec![0, 2, 4]);
+        assert_eq!(slice_indices(&slice(Some(1), None, Some(2)), 5), vec![1, 3]);
+        assert_eq!(
+            slice_indices(&slice(None, None, Some(-1)), 3),
+            vec![2, 1, 0]
+        );
+        assert_eq!(
+            slice_indices(&slice(Some(-1), Some(0), Some(-2)), 5),
+            vec![4, 2]
+        );
+
+        // Out-of-range bounds are clamped
+        assert_eq!(
+            slice_indices(&slice(Some(-10), Some(10), None), 3),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            slice_indices(&slice(Some(10), Some(-10), Some(-1)), 3),
+            vec![2, 1, 0]
+        );
+        assert_eq!(
+            slice_indices(&slice(Some(5), Some(8), None), 3),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            slice_indices(&slice(None, None, None), 0),
+            Vec::<usize>::new()
+        );
+        assert_eq!(
+            slice_indices(&slice(None, None, Some(-1)), 0),
+            Vec::<usize>::new()
+        );
+
+        // Extreme steps don't overflow.
+        assert_eq!(
+            slice_indices(&slice(Some(1), None, Some(i64::MAX)), 5),
+            vec![1]
+        );
+        assert_eq!(
+            slice_indices(&slice(Some(-1), None, Some(i64::MIN)), 5),
+            vec![4]
+        );
+    }
+}
@@ -16,45 +16,161 @@
  *
  */
 
-use crate::jsonpath::ast::Query;
+use std::time::Duration;
+
+use crate::jsonpath::ast::{Function, Query};
+use crate::jsonpath::eval::selector::{Node, NodeResult};
+use crate::jsonpath::eval::{Deadline, EvalError, FunctionError};
 use crate::jsonpath::JsonpathResult;
 
 impl Query {
     /// Eval a JSONPath `Query` for a `serde_json::Value` input.
     /// It returns an Option<`JsonResultPath`>.
+    ///
+    /// If the query function can't be applied to the selected nodes, `None` is returned; use
+    /// [`Query::try_eval`] to get the error.
+    #[cfg(test)]
     pub fn eval(&self, value: &serde_json::Value) -> Option<JsonpathResult> {
-        let mut result = JsonpathResult::SingleEntry(value.clone());
+        self.try_eval(value, Duration::MAX).ok().flatten()
+    }
+
+    /// Eval a JSONPath `Query` for a `serde_json::Value` input, and returns an error if the query
+    /// function can't be applied to the selected nodes.
+    ///
+    /// The evaluation is aborted with an [`EvalError::Timeout`] error if it lasts more than
+    /// `timeout`.
+    pub fn try_eval(
+        &self,
+        value: &serde_json::Value,
+        timeout: Duration,
+    ) -> Result<Option<JsonpathResult>, EvalError> {
+        let deadline = Deadline::new(timeout);
+        let Some(result) = self.eval_nodes(value, &deadline)? else {
+            return Ok(None);
+        };
+        let result = result.into();
+        match self.function {
+            None => Ok(Some(result)),
+            Some(function) => {
+                let value = function.eval(&result)?;
+                Ok(Some(JsonpathResult::SingleEntry(value)))
+            }
+        }
+    }
+
+    /// Evaluates this query against `value` and returns the locations of the matching nodes, as
+    /// JSON pointers (see [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901)), instead of
+    /// their values.
+    ///
+    /// A function computes a value that is not a node of `value`: a query with a function is
+    /// rejected with an [`EvalError::UnexpectedFunction`] error. The evaluation is aborted with
+    /// an [`EvalError::Timeout`] error if it lasts more than `timeout`.
+    pub fn eval_pointers(
+        &self,
+        value: &serde_json::Value,
+        timeout: Duration,
+    ) -> Result<Vec<String>, EvalError> {
+        if let Some(function) = self.function {
+            return Err(EvalError::UnexpectedFunction(function));
+        }
+        let deadline = Deadline::new(timeout);
+        let pointers = self
+            .eval_nodes(value, &deadline)?
+            .map(NodeResult::pointers)
+            .unwrap_or_default();
+        Ok(pointers)
+    }
+
+    /// Evaluates the selectors of this query for a `serde_json::Value` input, until `deadline`,
+    /// and returns the selected nodes.
+    fn eval_nodes<'a>(
+        &self,
+        value: &'a serde_json::Value,
+        deadline: &Deadline,
+    ) -> Result<Option<NodeResult<'a>>, EvalError> {
+        let mut result = NodeResult::SingleEntry(Node::root(value));
         for selector in &self.selectors {
-            match result.clone() {
-                JsonpathResult::SingleEntry(value) => {
-                    result = selector.eval(&value)?;
+            match result {
+                NodeResult::SingleEntry(node) => {
+                    let Some(new_result) = selector.eval_nodes(&node, deadline)? else {
+                        return Ok(None);
+                    };
+                    result = new_result;
                 }
-                JsonpathResult::Collection(values) => {
+                NodeResult::Collection(nodes) => {
                     let mut elements = vec![];
-                    for value in values {
-                        match selector.eval(&value)? {
-                            JsonpathResult::SingleEntry(new_value) => {
-                                elements.push(new_value);
+                    for node in nodes {
+                        let Some(new_result) = selector.eval_nodes(&node, deadline)? else {
+                            return Ok(None);
+                        };
+                        match new_result {
+                            NodeResult::SingleEntry(new_node) => {
+                                elements.push(new_node);
                             }
-                            JsonpathResult::Collection(mut new_values) => {
-                                elements.append(&mut new_values);
+                            NodeResult::Collection(mut new_nodes) => {
+                                elements.append(&mut new_nodes);
                             }
                         }
-                        result = JsonpathResult::Collection(elements.clone());
                     }
+                    result = NodeResult::Collection(elements);
                 }
             }
         }
-        Some(result)
+        Ok(Some(result))
+    }
+}
+
+impl Function {
+    /// Applies this function to the `result` of the query selectors.
+    ///
+    /// `length()` returns the number of elements of an array, the number of keys of an object,
+    /// the number of characters of a string or the number of nodes of a collection.
+    fn eval(&self, result: &JsonpathResult) -> Result<serde_json::Value, FunctionError> {
+        match self {
+            Function::Length => {
+                let length = match result {
+                    JsonpathResult::Collection(values) => values.len(),
+                    JsonpathResult::SingleEntry(value) => match value {
+                        serde_json::Value::Array(values) => values.len(),
+                        serde_json::Value::Object(map) => map.len(),
+                        serde_json::Value::String(s) => s.chars().count(),
+                        _ => {
+                            return Err(FunctionError {
+                                function: *self,
+                                value_type: json_type(value).to_string(),
+                            })
+                        }
+                    },
+                };
+                Ok(serde_json::Value::from(length))
+            }
+        }
+    }
+}
+
+/// Returns the JSON type name of `value`.
+fn json_type(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use serde_json::json;
 
-    use crate::jsonpath::ast::{Number, Predicate, PredicateFunc, Query, Selector};
-    use crate::jsonpath::JsonpathResult;
+    use crate::jsonpath::ast::{
+        Function, Number, Predicate, PredicateFunc, Query, Selector, Slice,
+    };
+    use crate::jsonpath::eval::{EvalError, FunctionError};
+    use crate::jsonpath::{self, JsonpathResult};
 
     pub fn json_root() -> serde_json::Value {
         json!({ "store": json_store() })
@@ -122,13 +238,19 @@ mod tests {
     #[test]
     pub fn test_query() {
         assert_eq!(
-            Query { selectors: vec![] }.eval(&json_root()).unwrap(),
+            Query {
+                selectors: vec![],
+                function: None
+            }
+            .eval(&json_root())
+            .unwrap(),
             JsonpathResult::SingleEntry(json_root())
         );
 
         assert_eq!(
             Query {
-                selectors: vec![Selector::NameChild("store".to_string())]
+                selectors: vec![Selector::NameChild("store".to_string())],
+                function: None
             }
             .eval(&json_root())
             .unwrap(),
@@ -142,6 +264,7 @@ mod tests {
                 Selector::ArrayIndex(0),
                 Selector::NameChild("title".to_string()),
             ],
+            function: None,
         };
         assert_eq!(
             query.eval(&json_root()).unwrap(),
@@ -162,6 +285,7 @@ mod tests {
                 }),
                 Selector::NameChild("title".to_string()),
             ],
+            function: None,
         };
         assert_eq!(
             query.eval(&json_root()).unwrap(),
@@ -179,6 +303,7 @@ mod tests {
                 }),
                 Selector::NameChild("title".to_string()),
             ],
+            function: None,
         };
         assert_eq!(
             query.eval(&json_root()).unwrap(),
@@ -196,6 +321,7 @@ mod tests {
                 }),
                 Selector::NameChild("title".to_string()),
             ],
+            function: None,
         };
         assert_eq!(
             query.eval(&json_root()).unwrap(),
@@ -209,6 +335,7 @@ mod tests {
         // $..author
         let query = Query {
             selectors: vec![Selector::RecursiveKey("author".to_string())],
+            function: None,
         };
         assert_eq!(
             query.eval(&json_root()).unwrap(),
@@ -228,6 +355,7 @@ mod tests {
                 Selector::ArrayWildcard,
                 Selector::NameChild("author".to_string()),
             ],
+            function: None,
         };
         assert_eq!(
             query.eval(&json_root()).unwrap(),
@@ -239,4 +367,229 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    pub fn test_query_collection_with_nulls() {
+        // $.items[*]
+        let query = Query {
+            selectors: vec![
+                Selector::NameChild("items".to_string()),
+                Selector::ArrayWildcard,
+            ],
+            function: None,
+        };
+        assert_eq!(
+            query.eval(&json!({"items": [1, null, 3]})).unwrap(),
+            JsonpathResult::Collection(vec![json!(1), json!(null), json!(3)])
+        );
+
+        // $.items[0:3]
+        let query = Query {
+            selectors: vec![
+                Selector::NameChild("items".to_string()),
+                Selector::ArraySlice(Slice {
+                    start: None,
+                    end: Some(3),
+                    step: None,
+                }),
+            ],
+            function: None,
+        };
+        assert_eq!(
+            query.eval(&json!({"items": [null, null, 3, 4]})).unwrap(),
+            JsonpathResult::Collection(vec![json!(null), json!(null), json!(3)])
+        );
+    }
+
+    #[test]
+    pub fn test_query_length() {
+        let value =
+            json!({"items": [1, 2, 3], "user": {"id": 1, "name": "Bob"}, "name": "Zoë", "id": 1});
+        let length = |selectors: Vec<Selector>| {
+            Query {
+                selectors,
+                function: Some(Function::Length),
+            }
+            .try_eval(&value, Duration::MAX)
+        };
+
+        // $.items.length()
+        assert_eq!(
+            length(vec![Selector::NameChild("items".to_string())]).unwrap(),
+            Some(JsonpathResult::SingleEntry(json!(3)))
+        );
+        // $.user.length()
+        assert_eq!(
+            length(vec![Selector::NameChild("user".to_string())]).unwrap(),
+            Some(JsonpathResult::SingleEntry(json!(2)))
+        );
+        // $.name.length()
+        assert_eq!(
+            length(vec![Selector::NameChild("name".to_string())]).unwrap(),
+            Some(JsonpathResult::SingleEntry(json!(3)))
+        );
+        // $.items[1:].length()
+        assert_eq!(
+            length(vec![
+                Selector::NameChild("items".to_string()),
+                Selector::ArraySlice(Slice {
+                    start: Some(1),
+                    end: None,
+                    step: None,
+                }),
+            ])
+            .unwrap(),
+            Some(JsonpathResult::SingleEntry(json!(2)))
+        );
+        // $.unknown.length()
+        assert_eq!(
+            length(vec![Selector::NameChild("unknown".to_string())]).unwrap(),
+            None
+        );
+        // $.id.length()
+        assert_eq!(
+            length(vec![Selector::NameChild("id".to_string())]).unwrap_err(),
+            EvalError::Function(FunctionError {
+                function: Function::Length,
+                value_type: "number".to_string()
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_query_timeout() {
+        // $..*..*
+        let query = Query {
+            selectors: vec![Selector::RecursiveWildcard, Selector::RecursiveWildcard],
+            function: None,
+        };
+        assert!(query
+            .try_eval(&json_root(), Duration::from_secs(60))
+            .unwrap()
+            .is_some());
+        assert_eq!(
+            query.try_eval(&json_root(), Duration::ZERO).unwrap_err(),
+            EvalError::Timeout {
+                timeout: Duration::ZERO
+            }
+        );
+
+        // A nested document large enough to evaluate for a long time:
+        // the evaluation must be aborted shortly after the timeout.
+        let mut value = json!([]);
+        for _ in 0..200 {
+            value = json!([value, 1, 2, 3, 4]);
+        }
+        // $..*..*..*
+        let query = Query {
+            selectors: vec![
+                Selector::RecursiveWildcard,
+                Selector::RecursiveWildcard,
+                Selector::RecursiveWildcard,
+            ],
+            function: None,
+        };
+        let timeout = Duration::from_millis(10);
+        let start = std::time::Instant::now();
+        assert_eq!(
+            query.try_eval(&value, timeout).unwrap_err(),
+            EvalError::Timeout { timeout }
+        );
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    fn eval_pointers(expr: &str, value: &serde_json::Value) -> Vec<String> {
+        jsonpath::parse(expr)
+            .unwrap()
+            .eval_pointers(value, Duration::MAX)
+            .unwrap()
+    }
+
+    #[test]
+    pub fn test_query_pointers() {
+        let value = json_root();
+        assert_eq!(eval_pointers("$", &value), vec![""]);
+        assert_eq!(
+            eval_pointers("$..price", &value),
+            vec![
+                "/store/book/0/price",
+                "/store/book/1/price",
+                "/store/book/2/price",
+                "/store/book/3/price",
+            ]
+        );
+        assert_eq!(
+            eval_pointers("$.store.book[0].author", &value),
+            vec!["/store/book/0/author"]
+        );
+        assert!(eval_pointers("$.store.unknown", &value).is_empty());
+        assert_eq!(
+            eval_pointers("$.store.book[1:3].title", &value),
+            vec!["/store/book/1/title", "/store/book/2/title"]
+        );
+        assert_eq!(
+            eval_pointers("$.store.book[?(@.price>10)]", &value),
+            vec!["/store/book/1", "/store/book/3"]
+        );
+        assert_eq!(
+            eval_pointers("$.store.book[0,3]", &value),
+            vec!["/store/book/0", "/store/book/3"]
+        );
+
+        let value = json!({ "a/b": { "m~n": 1 } });
+        assert_eq!(eval_pointers("$.*.*", &value), vec!["/a~1b/m~0n"]);
+    }
+
+    #[test]
+    pub fn test_query_pointers_match_values() {
+        // Pointers and values are selected by the same evaluation: each pointer locates the
+        // value at the same position.
+        let value = json_root();
+        for expr in [
+            "$..author",
+            "$..*",
+            "$.store.book[-1:0:-1]",
+            "$..book[?(@.isbn)]",
+        ] {
+            let query = jsonpath::parse(expr).unwrap();
+            let Some(JsonpathResult::Collection(values)) = query.eval(&value) else {
+                panic!("{expr} should return a collection");
+            };
+            let pointers = query.eval_pointers(&value, Duration::MAX).unwrap();
+            let located = pointers
+                .iter()
+                .map(|pointer| value.pointer(pointer).unwrap().clone())
+                .collect::<Vec<_>>();
+            assert_eq!(located, values, "{expr}");
+        }
+
+        // A query without result has no pointer.
+        let query = jsonpath::parse("$.store.book[*].isbn").unwrap();
+        assert_eq!(query.eval(&value), None);
+        assert!(query
+            .eval_pointers(&value, Duration::MAX)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    pub fn test_query_pointers_error() {
+        let query = jsonpath::parse("$.store.book.length()").unwrap();
+        assert_eq!(
+            query
+                .eval_pointers(&json_root(), Duration::MAX)
+                .unwrap_err(),
+            EvalError::UnexpectedFunction(Function::Length)
+        );
+
+        let query = jsonpath::parse("$..*..*").unwrap();
+        assert_eq!(
+            query
+                .eval_pointers(&json_root(), Duration::ZERO)
+                .unwrap_err(),
+            EvalError::Timeout {
+                timeout: Duration::ZERO
+            }
+        );
+    }
 }
@@ -15,132 +15,201 @@
  * limitations under the License.
  *
  */
+use std::cmp::Ordering;
 
-use crate::jsonpath::ast::{Predicate, PredicateFunc, Selector, Slice};
+use super::{array_index, slice_indices, Deadline, EvalError};
+use crate::jsonpath::ast::{Predicate, PredicateFunc, Selector};
 use crate::jsonpath::JsonpathResult;
 
+/// A node of a JSON document, with its location as a JSON pointer
+/// (see [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901)).
+#[derive(Clone, Debug)]
+pub(super) struct Node<'a> {
+    pub pointer: String,
+    pub value: &'a serde_json::Value,
+}
+
+/// Nodes selected by a selector, or by a query.
+#[derive(Clone, Debug)]
+pub(super) enum NodeResult<'a> {
+    SingleEntry(Node<'a>),     // returned by a "definite" path
+    Collection(Vec<Node<'a>>), // returned by a "indefinite" path
+}
+
+impl<'a> Node<'a> {
+    /// Creates the root node of the document `value`.
+    pub fn root(value: &'a serde_json::Value) -> Self {
+        Node {
+            pointer: String::new(),
+            value,
+        }
+    }
+
+    /// Returns the child `value` of this node, located at `token`.
+    ///
+    /// `~` and `/` characters of `token` are escaped as `~0` and `~1` in the child pointer.
+    fn child(&self, token: &str, value: &'a serde_json::Value) -> Self {
+        let token = token.replace('~', "~0").replace('/', "~1");
+        Node {
+            pointer: format!("{}/{token}", self.pointer),
+            value,
+        }
+    }
+
+    /// Returns the item `index` of this node, if it's an array.
+    fn item(&self, index: usize) -> Option<Self> {
+        let value = self.value.get(index)?;
+        Some(self.child(&index.to_string(), value))
+    }
+
+    /// Returns the direct children of this node: the items of an array or the values of an
+    /// object.
+    fn children(&self) -> Vec<Self> {
+        match self.value {
+            serde_json::Value::Array(values) => values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| self.child(&i.to_string(), value))
+                .collect(),
+            serde_json::Value::Object(map) => map
+                .iter()
+                .map(|(key, value)| self.child(key, value))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl NodeResult<'_> {
+    /// Returns the JSON pointers of the selected nodes.
+    pub fn pointers(self) -> Vec<String> {
+        match self {
+            NodeResult::SingleEntry(node) => vec![node.pointer],
+            NodeResult::Collection(nodes) => nodes.into_iter().map(|node| node.pointer).collect(),
+        }
+    }
+}
+
+impl From<NodeResult<'_>> for JsonpathResult {
+    fn from(result: NodeResult<'_>) -> Self {
+        match result {
+            NodeResult::SingleEntry(node) => JsonpathResult::SingleEntry(node.value.clone()),
+            NodeResult::Collection(nodes) => {
+                let values = nodes.into_iter().map(|node| node.value.clone()).collect();
+                JsonpathResult::Collection(values)
+            }
+        }
+    }
+}
+
 impl Selector {
+    /// Evaluates this selector against `root`, without deadline.
+    #[cfg(test)]
     pub fn eval(&self, root: &serde_json::Value) -> Option<JsonpathResult> {
-        match self {
+        let deadline = Deadline::new(std::time::Duration::MAX);
+        let result = self.eval_nodes(&Node::root(root), &deadline).ok()??;
+        Some(result.into())
+    }
+
+    /// Evaluates this selector against the node `root`, and returns an error if `deadline` passes
+    /// before the evaluation is completed.
+    pub(super) fn eval_nodes<'a>(
+        &self,
+        root: &Node<'a>,
+        deadline: &Deadline,
+    ) -> Result<Option<NodeResult<'a>>, EvalError> {
+        deadline.check()?;
+        let result = match self {
             // Selectors returning single JSON node ("finite")
             Selector::NameChild(field) => root
+                .value
                 .get(field)
-                .map(|result| JsonpathResult::SingleEntry(result.clone())),
-            Selector::ArrayIndex(index) => root
-                .get(index)
-                .map(|result| JsonpathResult::SingleEntry(result.clone())),
+                .map(|value| NodeResult::SingleEntry(root.child(field, value))),
+            Selector::ArrayIndex(index) => {
+                let serde_json::Value::Array(values) = root.value else {
+                    return Ok(None);
+                };
+                array_index(*index, values.len())
+                    .and_then(|i| root.item(i))
+                    .map(NodeResult::SingleEntry)
+            }
 
             // Selectors returning a collection ("indefinite")
+            Selector::NameChildren(fields) => {
+                // Missing keys are skipped, duplicated keys are selected as many times as listed.
+                let elements = fields
+                    .iter()
+                    .filter_map(|field| {
+                        let value = root.value.get(field)?;
+                        Some(root.child(field, value))
+                    })
+                    .collect();
+                Some(NodeResult::Collection(elements))
+            }
             Selector::Wildcard | Selector::ArrayWildcard => {
-                let mut elements = vec![];
-                if let serde_json::Value::Array(values) = root {
-                    for value in values {
-                        elements.push(value.clone());
-                    }
-                } else if let serde_json::Value::Object(key_values) = root {
-                    for value in key_values.values() {
-                        elements.push(value.clone());
-                    }
-                }
-                Some(JsonpathResult::Collection(elements))
+                Some(NodeResult::Collection(root.children()))
             }
-            Selector::ArraySlice(Slice { start, end }) => {
+            Selector::ArraySlice(slice) => {
                 let mut elements = vec![];
-                if let serde_json::Value::Array(values) = root {
-                    for (i, value) in values.iter().enumerate() {
-                        if let Some(n) = start {
-                            let n = if *n < 0 { values.len() as i64 + n } else { *n };
-                            if (i as i64) < n {
-                                continue;
-                            }
-                        }
-                        if let Some(n) = end {
-                            let n = if *n < 0 { values.len() as i64 + n } else { *n };
-                            if (i as i64) >= n {
-                                continue;
-                            }
-                        }
-                        elements.push(value.clone());
-                    }
+                if let serde_json::Value::Array(values) = root.value {
+                    let indices = slice_indices(slice, values.len());
+                    elements.extend(indices.into_iter().filter_map(|i| root.item(i)));
                 }
-                Some(JsonpathResult::Collection(elements))
+                Some(NodeResult::Collection(elements))
             }
             Selector::RecursiveKey(key) => {
                 let mut elements = vec![];
-                match root {
-                    serde_json::Value::Object(ref obj) => {
-                        if let Some(elem) = obj.get(key.as_str()) {
-                            elements.push(elem.clone());
-                        }
-                        for value in obj.values() {
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveKey(key.clone()).eval(value)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
+                if let serde_json::Value::Object(obj) = root.value {
+                    if let Some(elem) = obj.get(key.as_str()) {
+                        elements.push(root.child(key, elem));
                     }
-                    serde_json::Value::Array(values) => {
-                        for value in values {
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveKey(key.clone()).eval(value)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
+                }
+                for child in root.children() {
+                    if let Some(NodeResult::Collection(mut nodes)) =
+                        self.eval_nodes(&child, deadline)?
+                    {
+                        elements.append(&mut nodes);
                     }
-                    _ => {}
                 }
-                Some(JsonpathResult::Collection(elements))
+                Some(NodeResult::Collection(elements))
             }
             Selector::RecursiveWildcard => {
                 let mut elements = vec![];
-                match root {
-                    serde_json::Value::Object(map) => {
-                        for elem in map.values() {
-                            elements.push(elem.clone());
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveWildcard.eval(elem)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
-                    }
-                    serde_json::Value::Array(values) => {
-                        for elem in values {
-                            elements.push(elem.clone());
-                            if let Some(JsonpathResult::Collection(mut values)) =
-                                Selector::RecursiveWildcard.eval(elem)
-                            {
-                                elements.append(&mut values);
-                            }
-                        }
+                for child in root.children() {
+                    let descendants = self.eval_nodes(&child, deadline)?;
+                    elements.push(child);
+                    if let Some(NodeResult::Collection(mut nodes)) = descendants {
+                        elements.append(&mut nodes);
                     }
-                    _ => {}
                 }
-                Some(JsonpathResult::Collection(elements))
+                Some(NodeResult::Collection(elements))
             }
             Selector::Filter(predicate) => {
-                let elements = match root {
-                    serde_json::Value::Array(elements) => elements
-                        .iter()
-                        .filter(|&e| predicate.eval(e.clone()))
-                        .cloned()
+                let elements = match root.value {
+                    serde_json::Value::Array(_) => root
+                        .children()
+                        .into_iter()
+                        .filter(|node| predicate.eval(node.value.clone()))
                         .collect(),
                     _ => vec![],
                 };
-                Some(JsonpathResult::Collection(elements))
+                Some(NodeResult::Collection(elements))
             }
             Selector::ArrayIndices(indexes) => {
-                let mut values = vec![];
-                for index in indexes {
-                    if let Some(value) = root.get(index) {
-                        values.push(value.clone());
+                let mut elements = vec![];
+                if let serde_json::Value::Array(values) = root.value {
+                    for index in indexes {
+                        if let Some(node) =
+                            array_index(*index, values.len()).and_then(|i| root.item(i))
+                        {
+                            elements.push(node);
+                        }
                     }
                 }
-                Some(JsonpathResult::Collection(values))
+                Some(NodeResult::Collection(elements))
             }
-        }
+        };
+        Ok(result)
     }
 }
 
@@ -148,8 +217,13 @@ impl Predicate {
     pub fn eval(&self, elem: serde_json::Value) -> bool {
         match elem {
             serde_json::Value::Object(_) => {
-                if let Some(value) = extract_value(elem, self.key.clone()) {
-                    match (value, self.func.clone()) {
+                if let Some(value) = extract_value(elem.clone(), self.key.clone()) {
+                    // Compares the value to the key referenced in the right-hand side of the predicate
+                    let compare_key = |key: &Vec<String>| {
+                        extract_value(elem.clone(), key.clone())
+                            .and_then(|other| compare_values(&value, &other))
+                    };
+                    match (&value, self.func.clone()) {
                         (_, PredicateFunc::KeyExist) => true,
                         (serde_json::Value::Number(v), PredicateFunc::Equal(ref num)) => {
                             (v.as_f64().unwrap() - num.to_f64()).abs() < f64::EPSILON
@@ -168,15 +242,33 @@ impl Predicate {
                             v.as_f64().unwrap() <= num.to_f64()
                         }
                         (serde_json::Value::String(v), PredicateFunc::EqualString(ref s)) => {
-                            v == *s
+                            *v == *s
                         }
                         (serde_json::Value::String(v), PredicateFunc::NotEqualString(ref s)) => {
-                            v != *s
+                            *v != *s
                         }
                         (serde_json::Value::Number(v), PredicateFunc::NotEqual(ref num)) => {
                             (v.as_f64().unwrap() - num.to_f64()).abs() >= f64::EPSILON
                         }
-                        (serde_json::Value::Bool(v), PredicateFunc::EqualBool(ref s)) => v == *s,
+                        (serde_json::Value::Bool(v), PredicateFunc::EqualBool(ref s)) => *v == *s,
+                        (_, PredicateFunc::EqualKey(ref key)) => {
+                            compare_key(key) == Some(Ordering::Equal)
+                        }
+                        (_, PredicateFunc::NotEqualKey(ref key)) => {
+                            compare_key(key) != Some(Ordering::Equal)
+                        }
+                        (_, PredicateFunc::GreaterThanKey(ref key)) => {
+                            compare_key(key) == Some(Ordering::Greater)
+                        }
+                        (_, PredicateFunc::GreaterThanOrEqualKey(ref key)) => {
+                            matches!(compare_key(key), Some(Ordering::Greater | Ordering::Equal))
+                        }
+                        (_, PredicateFunc::LessThanKey(ref key)) => {
+                            compare_key(key) == Some(Ordering::Less)
+                        }
+                        (_, PredicateFunc::LessThanOrEqualKey(ref key)) => {
+                            matches!(compare_key(key), Some(Ordering::Less | Ordering::Equal))
+                        }
                         _ => false,
                     }
                 } else {
@@ -188,6 +280,21 @@ impl Predicate {
     }
 }
 
+/// Compares two JSON values of a filter predicate: numbers and strings are ordered, other values
+/// can only be equal. Returns `None` if the values can't be compared.
+fn compare_values(left: &serde_json::Value, right: &serde_json::Value) -> Option<Ordering> {
+    match (left, right) {
+        (serde_json::Value::Number(left), serde_json::Value::Number(right)) => {
+            left.as_f64()?.partial_cmp(&right.as_f64()?)
+        }
+        (serde_json::Value::String(left), serde_json::Value::String(right)) => {
+            Some(left.cmp(right))
+        }
+        (left, right) if left == right => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
 fn extract_value(obj: serde_json::Value, key_path: Vec<String>) -> Option<serde_json::Value> {
     let mut path = key_path;
     let mut value = obj;
@@ -209,7 +316,7 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::jsonpath::ast::Number;
+    use crate::jsonpath::ast::{Number, Slice};
 
     pub fn json_root() -> serde_json::Value {
         json!({ "store": json_store() })
@@ -316,11 +423,32 @@ mod tests {
             Selector::ArraySlice(Slice {
                 start: None,
                 end: Some(2),
+                step: None,
             })
             .eval(&json_books())
             .unwrap(),
             JsonpathResult::Collection(vec![json_first_book(), json_second_book(),])
         );
+        assert_eq!(
+            Selector::ArraySlice(Slice {
+                start: None,
+                end: None,
+                step: Some(2),
+            })
+            .eval(&json_books())
+            .unwrap(),
+            JsonpathResult::Collection(vec![json_first_book(), json_third_book()])
+        );
+        assert_eq!(
+            Selector::ArraySlice(Slice {
+                start: Some(-2),
+                end: Some(10),
+                step: None,
+            })
+            .eval(&json_books())
+            .unwrap(),
+            JsonpathResult::Collection(vec![json_third_book(), json_fourth_book()])
+        );
     }
 
     #[test]
@@ -409,6 +537,30 @@ mod tests {
             func: PredicateFunc::EqualBool(false),
         }
         .eval(json!({"key": false})));
+
+        assert!(Predicate {
+            key: vec!["price".to_string()],
+            func: PredicateFunc::LessThanKey(vec!["limit".to_string()]),
+        }
+        .eval(json!({"price": 5, "limit": 10.5})));
+
+        assert!(!Predicate {
+            key: vec!["price".to_string()],
+            func: PredicateFunc::LessThanKey(vec!["limit".to_string()]),
+        }
+        .eval(json!({"price": 5})));
+
+        assert!(Predicate {
+            key: vec!["name".to_string()],
+            func: PredicateFunc::EqualKey(vec!["alias".to_string()]),
+        }
+        .eval(json!({"name": "Bob", "alias": "Bob"})));
+
+        assert!(Predicate {
+            key: vec!["name".to_string()],
+            func: PredicateFunc::NotEqualKey(vec!["alias".to_string()]),
+        }
+        .eval(json!({"name": "Bob", "alias": 1})));
     }
 
     #[test]
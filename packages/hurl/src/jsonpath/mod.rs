@@ -34,6 +34,10 @@
 //! Accessing a key containing a single quote must be escape:  `$['\'']`.
 //! Key with unicode are supported: `$['✈']`
 //!
+//! Several keys can be selected at once with a union: `$['first','last']`.
+//! The values are returned in the listed order, missing keys are skipped and duplicated keys are
+//! selected as many times as they're listed.
+//!
 //! Any character within these quote won't have a specific meaning:
 //! - `$['*']` selects the element with key '*'. It is different from `$[*]` which selects all elements
 //! - `$['.']` selects the element with key '.'.
@@ -49,6 +53,9 @@
 //! For example, `.price.US` specify field 'US' in an object for the field price.
 //! The predicate if not present just checks the key existence.
 //!
+//! The `length()` function can end an expression (`$.store.book.length()`): it returns the number of
+//! elements of an array, keys of an object or characters of a string.
+//!
 //! The Hurl API for evaluating a jsonpath expression does not always return a collection (as defined in the jsonpath spec).
 //! It returns an optional value, which is either a collection or a single value (scalar).
 //! Note that other implementations (such as the Java lib <https://github.com/json-path/JsonPath>) also distinguish between node value (definite path) and collection (indefinite path).
@@ -60,8 +67,8 @@
 //! This will make testing the value a bit easier.
 //!
 
-pub use self::eval::JsonpathResult;
-pub use self::parser::parse;
+pub use self::eval::{EvalError, JsonpathResult};
+pub use self::parser::{parse, ParseError};
 
 mod ast;
 mod eval;
@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+pub use self::error::ParseError;
 pub use self::parse::parse;
 
 mod error;
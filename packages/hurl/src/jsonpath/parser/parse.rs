@@ -18,11 +18,10 @@
 use hurl_core::combinator::{choice, zero_or_more};
 use hurl_core::reader::Reader;
 
-use crate::jsonpath::ast::{Predicate, PredicateFunc, Query, Selector, Slice};
+use crate::jsonpath::ast::{Function, Predicate, PredicateFunc, Query, Selector, Slice};
 use crate::jsonpath::parser::error::{ParseError, ParseErrorKind, ParseResult};
 use crate::jsonpath::parser::primitives::{
-    boolean, integer, key_name, key_path, literal, natural, number, string_value, try_literal,
-    whitespace,
+    boolean, integer, key_name, key_path, literal, number, string_value, try_literal, whitespace,
 };
 
 pub fn parse(s: &str) -> Result<Query, ParseError> {
@@ -34,12 +33,26 @@ fn query(reader: &mut Reader) -> ParseResult<Query> {
     literal("$", reader)?;
 
     let selectors = zero_or_more(selector, reader)?;
+    let function = function(reader);
     if !reader.is_eof() {
         let kind = ParseErrorKind::Expecting("eof".to_string());
         let error = ParseError::new(reader.cursor().pos, false, kind);
         return Err(error);
     }
-    Ok(Query { selectors })
+    Ok(Query {
+        selectors,
+        function,
+    })
+}
+
+/// Parses an optional function applied to the selected nodes, like `.length()`.
+fn function(reader: &mut Reader) -> Option<Function> {
+    let save = reader.cursor();
+    if try_literal(".length()", reader).is_ok() {
+        return Some(Function::Length);
+    }
+    reader.seek(save);
+    None
 }
 
 fn selector(reader: &mut Reader) -> ParseResult<Selector> {
@@ -63,7 +76,7 @@ fn selector_array_index_or_array_indices(reader: &mut Reader) -> Result<Selector
     let initial_state = reader.cursor();
     try_left_bracket(reader)?;
     let mut indexes = vec![];
-    let i = match natural(reader) {
+    let i = match integer(reader) {
         Err(e) => {
             let error = ParseError::new(e.pos, true, e.kind);
             return Err(error);
@@ -74,7 +87,7 @@ fn selector_array_index_or_array_indices(reader: &mut Reader) -> Result<Selector
     loop {
         let start = reader.cursor();
         if try_literal(",", reader).is_ok() {
-            let i = match natural(reader) {
+            let i = match integer(reader) {
                 Err(e) => {
                     return Err(ParseError::new(e.pos, true, e.kind));
                 }
@@ -110,28 +123,34 @@ fn selector_array_wildcard(reader: &mut Reader) -> Result<Selector, ParseError>
 fn selector_array_slice(reader: &mut Reader) -> Result<Selector, ParseError> {
     try_left_bracket(reader)?;
     let save = reader.cursor();
-    let start = match integer(reader) {
-        Err(_) => {
-            reader.seek(save);
-            None
-        }
-        Ok(v) => Some(v),
-    };
+    let start = slice_bound(reader)?;
     if try_literal(":", reader).is_err() {
         let kind = ParseErrorKind::Expecting(":".to_string());
         let error = ParseError::new(save.pos, true, kind);
         return Err(error);
     };
-    let save = reader.cursor();
-    let end = match integer(reader) {
-        Err(_) => {
-            reader.seek(save);
-            None
+    let end = slice_bound(reader)?;
+    let colon = reader.cursor();
+    let step = if try_literal(":", reader).is_ok() {
+        let save = reader.cursor();
+        let step = slice_bound(reader)?;
+        if step == Some(0) {
+            let kind = ParseErrorKind::Expecting("non-zero step".to_string());
+            let error = ParseError::new(save.pos, false, kind);
+            return Err(error);
         }
-        Ok(v) => Some(v),
+        step
+    } else {
+        reader.seek(colon);
+        None
     };
     literal("]", reader)?;
-    Ok(Selector::ArraySlice(Slice { start, end }))
+    Ok(Selector::ArraySlice(Slice { start, end, step }))
+}
+
+/// Parses an optional integer bound of a slice.
+fn slice_bound(reader: &mut Reader) -> ParseResult<Option<i64>> {
+    choice(&[|p1| integer(p1).map(Some), |_| Ok(None)], reader)
 }
 
 fn selector_filter(reader: &mut Reader) -> Result<Selector, ParseError> {
@@ -151,12 +170,25 @@ fn selector_object_key_bracket(reader: &mut Reader) -> Result<Selector, ParseErr
             Err(error)
         }
         Ok(v) => {
+            let mut names = vec![v];
+            names.append(&mut zero_or_more(union_key, reader)?);
             literal("]", reader)?;
-            Ok(Selector::NameChild(v))
+            if names.len() == 1 {
+                Ok(Selector::NameChild(names.remove(0)))
+            } else {
+                Ok(Selector::NameChildren(names))
+            }
         }
     }
 }
 
+/// Parses a key following a comma, in a union of keys (`$['first','last']`).
+fn union_key(reader: &mut Reader) -> ParseResult<String> {
+    try_literal(",", reader)?;
+    whitespace(reader);
+    string_value(reader)
+}
+
 fn selector_object_key(reader: &mut Reader) -> Result<Selector, ParseError> {
     if reader.peek() != Some('.') {
         let kind = ParseErrorKind::Expecting("[ or .".to_string());
@@ -171,6 +203,12 @@ fn selector_object_key(reader: &mut Reader) -> Result<Selector, ParseError> {
         let error = ParseError::new(reader.cursor().pos, false, kind);
         return Err(error);
     }
+    // A name followed by a parenthesis is a function call, not a key.
+    if reader.peek() == Some('(') {
+        let kind = ParseErrorKind::Expecting("key".to_string());
+        let error = ParseError::new(reader.cursor().pos, true, kind);
+        return Err(error);
+    }
     Ok(Selector::NameChild(s))
 }
 
@@ -214,7 +252,14 @@ fn predicate(reader: &mut Reader) -> ParseResult<Predicate> {
     let save = reader.cursor();
     let func = match predicate_func(reader) {
         Ok(f) => f,
+        Err(e) if !e.recoverable => return Err(e),
         Err(_) => {
+            reader.seek(save);
+            // An operator without a valid value is an error, reported at the value position.
+            if comparison_operator(reader).is_some() {
+                let kind = ParseErrorKind::Expecting("value".to_string());
+                return Err(ParseError::new(reader.cursor().pos, false, kind));
+            }
             reader.seek(save);
             PredicateFunc::KeyExist
         }
@@ -222,9 +267,24 @@ fn predicate(reader: &mut Reader) -> ParseResult<Predicate> {
     Ok(Predicate { key, func })
 }
 
+/// Reads a comparison operator of a filter predicate, and the following whitespaces.
+fn comparison_operator(reader: &mut Reader) -> Option<&'static str> {
+    let start = reader.cursor();
+    ["==", "!=", "<=", ">=", "<", ">"].into_iter().find(|op| {
+        reader.seek(start);
+        try_literal(op, reader).is_ok()
+    })
+}
+
 fn predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
     choice(
         &[
+            equal_key_predicate_func,
+            notequal_key_predicate_func,
+            greater_than_or_equal_key_predicate_func,
+            greater_than_key_predicate_func,
+            less_than_or_equal_key_predicate_func,
+            less_than_key_predicate_func,
             equal_number_predicate_func,
             greater_than_predicate_func,
             greater_than_or_equal_predicate_func,
@@ -239,6 +299,48 @@ fn predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
     )
 }
 
+/// Parses a reference to a key of the current node (`@.key1.key2`).
+fn key_reference(reader: &mut Reader) -> ParseResult<Vec<String>> {
+    try_literal("@.", reader)?;
+    key_path(reader)
+}
+
+fn equal_key_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("==", reader)?;
+    let key = key_reference(reader)?;
+    Ok(PredicateFunc::EqualKey(key))
+}
+
+fn notequal_key_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("!=", reader)?;
+    let key = key_reference(reader)?;
+    Ok(PredicateFunc::NotEqualKey(key))
+}
+
+fn greater_than_or_equal_key_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal(">=", reader)?;
+    let key = key_reference(reader)?;
+    Ok(PredicateFunc::GreaterThanOrEqualKey(key))
+}
+
+fn greater_than_key_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal(">", reader)?;
+    let key = key_reference(reader)?;
+    Ok(PredicateFunc::GreaterThanKey(key))
+}
+
+fn less_than_or_equal_key_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("<=", reader)?;
+    let key = key_reference(reader)?;
+    Ok(PredicateFunc::LessThanOrEqualKey(key))
+}
+
+fn less_than_key_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
+    try_literal("<", reader)?;
+    let key = key_reference(reader)?;
+    Ok(PredicateFunc::LessThanKey(key))
+}
+
 fn equal_number_predicate_func(reader: &mut Reader) -> ParseResult<PredicateFunc> {
     try_literal("==", reader)?;
     whitespace(reader);
@@ -329,17 +431,20 @@ mod tests {
     pub fn test_query() {
         let expected_query = Query {
             selectors: vec![Selector::ArrayIndex(2)],
+            function: None,
         };
         assert_eq!(query(&mut Reader::new("$[2]")).unwrap(), expected_query);
 
         let expected_query = Query {
             selectors: vec![Selector::NameChild("key".to_string())],
+            function: None,
         };
         assert_eq!(query(&mut Reader::new("$['key']")).unwrap(), expected_query);
         assert_eq!(query(&mut Reader::new("$.key")).unwrap(), expected_query);
 
         let expected_query = Query {
             selectors: vec![Selector::NameChild("profile-id".to_string())],
+            function: None,
         };
         assert_eq!(
             query(&mut Reader::new("$['profile-id']")).unwrap(),
@@ -357,6 +462,7 @@ mod tests {
                 Selector::ArrayIndex(0),
                 Selector::NameChild("title".to_string()),
             ],
+            function: None,
         };
         assert_eq!(
             query(&mut Reader::new("$.store.book[0].title")).unwrap(),
@@ -372,11 +478,34 @@ mod tests {
                 Selector::RecursiveKey("book".to_string()),
                 Selector::ArrayIndex(2),
             ],
+            function: None,
         };
         assert_eq!(
             query(&mut Reader::new("$..book[2]")).unwrap(),
             expected_query
         );
+
+        let expected_query = Query {
+            selectors: vec![Selector::NameChild("items".to_string())],
+            function: Some(Function::Length),
+        };
+        assert_eq!(
+            query(&mut Reader::new("$.items.length()")).unwrap(),
+            expected_query
+        );
+
+        // `length` without parenthesis is still a key
+        let expected_query = Query {
+            selectors: vec![
+                Selector::NameChild("items".to_string()),
+                Selector::NameChild("length".to_string()),
+            ],
+            function: None,
+        };
+        assert_eq!(
+            query(&mut Reader::new("$.items.length")).unwrap(),
+            expected_query
+        );
     }
 
     #[test]
@@ -386,6 +515,24 @@ mod tests {
 
         let error = query(&mut Reader::new("$.store?")).err().unwrap();
         assert_eq!(error.pos, Pos { line: 1, column: 8 });
+
+        let error = query(&mut Reader::new("$.items[-a]")).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
+        assert!(!error.recoverable);
+
+        let error = query(&mut Reader::new("$.items.length().name"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 17
+            }
+        );
+
+        let error = query(&mut Reader::new("$.items.size()")).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 8 });
     }
 
     #[test]
@@ -433,6 +580,39 @@ mod tests {
             })
         );
         assert_eq!(reader.cursor().index, 15);
+
+        let mut reader = Reader::new("[?(@.price < @.limit)]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::Filter(Predicate {
+                key: vec!["price".to_string()],
+                func: PredicateFunc::LessThanKey(vec!["limit".to_string()]),
+            })
+        );
+        assert_eq!(reader.cursor().index, 22);
+    }
+
+    #[test]
+    pub fn test_selector_filter_error() {
+        // Operator without value
+        let error = selector(&mut Reader::new("[?(@.price < )]")).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 14));
+        assert!(!error.recoverable);
+
+        // Invalid operator
+        let error = selector(&mut Reader::new("[?(@.price <> 10)]")).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 13));
+        assert!(!error.recoverable);
+
+        // Invalid key reference
+        let error = selector(&mut Reader::new("[?(@.price < @.1)]")).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 17));
+        assert!(!error.recoverable);
+
+        // Missing closing parenthesis
+        let error = selector(&mut Reader::new("[?(@.price < 10]")).unwrap_err();
+        assert_eq!(error.pos, Pos::new(1, 16));
+        assert!(!error.recoverable);
     }
 
     #[test]
@@ -458,6 +638,30 @@ mod tests {
         );
         assert_eq!(reader.cursor().index, 5);
 
+        let mut reader = Reader::new("[-1]");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::ArrayIndex(-1));
+        assert_eq!(reader.cursor().index, 4);
+
+        let mut reader = Reader::new("[-0]");
+        assert_eq!(selector(&mut reader).unwrap(), Selector::ArrayIndex(0));
+
+        let mut reader = Reader::new("[-2,0]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArrayIndices(vec![-2, 0])
+        );
+
+        // A slice can still start with a negative index
+        let mut reader = Reader::new("[-1:]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(-1),
+                end: None,
+                step: None,
+            })
+        );
+
         // you don't need to keep the exact string
         // this is not part of the AST
         let mut reader = Reader::new(".[2]");
@@ -485,7 +689,8 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: Some(1),
-                end: None
+                end: None,
+                step: None,
             })
         );
         assert_eq!(reader.cursor().index, 4);
@@ -495,7 +700,8 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: Some(-1),
-                end: None
+                end: None,
+                step: None,
             })
         );
         assert_eq!(reader.cursor().index, 5);
@@ -505,10 +711,63 @@ mod tests {
             selector(&mut reader).unwrap(),
             Selector::ArraySlice(Slice {
                 start: None,
-                end: Some(2)
+                end: Some(2),
+                step: None,
             })
         );
         assert_eq!(reader.cursor().index, 4);
+
+        let mut reader = Reader::new("[0:5]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(0),
+                end: Some(5),
+                step: None,
+            })
+        );
+        assert_eq!(reader.cursor().index, 5);
+
+        let mut reader = Reader::new("[::2]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: None,
+                end: None,
+                step: Some(2),
+            })
+        );
+        assert_eq!(reader.cursor().index, 5);
+
+        let mut reader = Reader::new("[-1:0:-1]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(-1),
+                end: Some(0),
+                step: Some(-1),
+            })
+        );
+        assert_eq!(reader.cursor().index, 9);
+
+        let mut reader = Reader::new("[1:3:]");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::ArraySlice(Slice {
+                start: Some(1),
+                end: Some(3),
+                step: None,
+            })
+        );
+
+        let mut reader = Reader::new("[0:5:0]");
+        let error = selector(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 6 });
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting("non-zero step".to_string())
+        );
+        assert!(!error.recoverable);
     }
 
     #[test]
@@ -533,6 +792,29 @@ mod tests {
             Selector::NameChild("key1".to_string())
         );
         assert_eq!(reader.cursor().index, 8);
+
+        let mut reader = Reader::new("['first','last']");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChildren(vec!["first".to_string(), "last".to_string()])
+        );
+        assert_eq!(reader.cursor().index, 16);
+
+        let mut reader = Reader::new(".['name', 'email', 'name']");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChildren(vec![
+                "name".to_string(),
+                "email".to_string(),
+                "name".to_string()
+            ])
+        );
+        assert_eq!(reader.cursor().index, 26);
+
+        let mut reader = Reader::new("['first',]");
+        let error = selector(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 9 });
+        assert!(!error.recoverable);
     }
 
     #[test]
@@ -701,5 +983,42 @@ mod tests {
             PredicateFunc::LessThanOrEqual(Number { int: 5, decimal: 0 })
         );
         assert_eq!(reader.cursor().index, 3);
+
+        let mut reader = Reader::new("==@.other");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::EqualKey(vec!["other".to_string()])
+        );
+        assert_eq!(reader.cursor().index, 9);
+
+        let mut reader = Reader::new("!= @.a.b");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::NotEqualKey(vec!["a".to_string(), "b".to_string()])
+        );
+
+        let mut reader = Reader::new(">@.min");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::GreaterThanKey(vec!["min".to_string()])
+        );
+
+        let mut reader = Reader::new(">=@.min");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::GreaterThanOrEqualKey(vec!["min".to_string()])
+        );
+
+        let mut reader = Reader::new("<@.max");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::LessThanKey(vec!["max".to_string()])
+        );
+
+        let mut reader = Reader::new("<=@.max");
+        assert_eq!(
+            predicate_func(&mut reader).unwrap(),
+            PredicateFunc::LessThanOrEqualKey(vec!["max".to_string()])
+        );
     }
 }
@@ -21,6 +21,7 @@
 //! are usually located since we do not want to expose the jsonpath module to our public API.
 
 use std::fs::read_to_string;
+use std::time::Duration;
 
 use serde_json::json;
 
@@ -471,6 +472,130 @@ fn test_filter_nested_object() {
     );
 }
 
+#[test]
+fn test_filter_key_reference() {
+    let value = json!({
+        "items": [
+            {"name": "pen", "price": 2, "limit": 5},
+            {"name": "book", "price": 12, "limit": 10},
+            {"name": "bag", "price": 10, "limit": 10}
+        ]
+    });
+    let expr = jsonpath::parse("$.items[?(@.price < @.limit)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("pen")])
+    );
+    let expr = jsonpath::parse("$.items[?(@.price >= @.limit)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("book"), json!("bag")])
+    );
+    let expr = jsonpath::parse("$.items[?(@.price <= 10)].name").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("pen"), json!("bag")])
+    );
+}
+
+#[test]
+fn test_negative_array_index() {
+    let value = json!({"items": ["first", "second", "third"]});
+    let expr = jsonpath::parse("$.items[-1]").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::SingleEntry(json!("third"))
+    );
+    let expr = jsonpath::parse("$.items[-2]").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::SingleEntry(json!("second"))
+    );
+    let expr = jsonpath::parse("$.items[-0]").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::SingleEntry(json!("first"))
+    );
+    let expr = jsonpath::parse("$.items[-4]").unwrap();
+    assert_eq!(expr.eval(&value), None);
+    let expr = jsonpath::parse("$.items[-1,-4,0]").unwrap();
+    assert_eq!(
+        expr.eval(&value).unwrap(),
+        JsonpathResult::Collection(vec![json!("third"), json!("first")])
+    );
+    assert_eq!(
+        expr.eval_pointers(&value, Duration::MAX).unwrap(),
+        vec!["/items/2".to_string(), "/items/0".to_string()]
+    );
+}
+
+#[test]
+fn test_array_slice() {
+    let value = json!({"data": [0, 1, 2, 3, 4, 5, 6]});
+    let eval = |expr: &str| jsonpath::parse(expr).unwrap().eval(&value).unwrap();
+    assert_eq!(
+        eval("$.data[0:5]"),
+        JsonpathResult::Collection(vec![json!(0), json!(1), json!(2), json!(3), json!(4)])
+    );
+    assert_eq!(
+        eval("$.data[::2]"),
+        JsonpathResult::Collection(vec![json!(0), json!(2), json!(4), json!(6)])
+    );
+    assert_eq!(
+        eval("$.data[-3:]"),
+        JsonpathResult::Collection(vec![json!(4), json!(5), json!(6)])
+    );
+    assert_eq!(
+        eval("$.data[5:1:-2]"),
+        JsonpathResult::Collection(vec![json!(5), json!(3)])
+    );
+    assert_eq!(
+        eval("$.data[4:100]"),
+        JsonpathResult::Collection(vec![json!(4), json!(5), json!(6)])
+    );
+    assert_eq!(eval("$.data[10:]"), JsonpathResult::Collection(vec![]));
+    assert_eq!(
+        eval("$.data[1::9223372036854775807]"),
+        JsonpathResult::Collection(vec![json!(1)])
+    );
+
+    let expr = jsonpath::parse("$.data[1::3]").unwrap();
+    assert_eq!(
+        expr.eval_pointers(&value, Duration::MAX).unwrap(),
+        vec!["/data/1", "/data/4"]
+    );
+
+    assert!(jsonpath::parse("$.data[0:5:0]").is_err());
+}
+
+#[test]
+fn test_union_keys() {
+    let value = json!({"first": "Ada", "last": "Lovelace", "user": {"name": "Bob", "email": "bob@example.org"}});
+    let eval = |expr: &str| jsonpath::parse(expr).unwrap().eval(&value).unwrap();
+    assert_eq!(
+        eval("$['first','last']"),
+        JsonpathResult::Collection(vec![json!("Ada"), json!("Lovelace")])
+    );
+    assert_eq!(
+        eval("$.user['email','name']"),
+        JsonpathResult::Collection(vec![json!("bob@example.org"), json!("Bob")])
+    );
+    assert_eq!(
+        eval("$.user['name','unknown','name']"),
+        JsonpathResult::Collection(vec![json!("Bob"), json!("Bob")])
+    );
+    assert_eq!(
+        eval("$['unknown','other']"),
+        JsonpathResult::Collection(vec![])
+    );
+
+    let expr = jsonpath::parse("$.user['email','name']").unwrap();
+    assert_eq!(
+        expr.eval_pointers(&value, Duration::MAX).unwrap(),
+        vec!["/user/email", "/user/name"]
+    );
+}
+
 #[test]
 fn test_parsing_error() {
     // not supported yet
@@ -48,3 +48,5 @@ pub mod parallel;
 pub mod report;
 pub mod runner;
 pub mod util;
+
+pub use json::JSON_SCHEMA_VERSION;
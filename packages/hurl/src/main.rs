@@ -155,7 +155,7 @@ fn export_results(
     }
     if let Some(dir) = &opts.json_report_dir {
         logger.debug(&format!("Writing JSON report to {}", dir.display()));
-        create_json_report(runs, dir)?;
+        create_json_report(runs, dir, opts.report_include_success_bodies)?;
     }
     if let Some(file) = &opts.cookie_output_file {
         logger.debug(&format!("Writing cookies to {}", file.display()));
@@ -209,7 +209,13 @@ fn create_html_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError>
 }
 
 /// Creates an JSON report for this run.
-fn create_json_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError> {
+///
+/// If `include_success_bodies` is `false`, only the response bodies of failed entries are saved.
+fn create_json_report(
+    runs: &[HurlRun],
+    dir_path: &Path,
+    include_success_bodies: bool,
+) -> Result<(), CliError> {
     // We ensure that the containing folder exists.
     let store_path = dir_path.join("store");
     std::fs::create_dir_all(&store_path)?;
@@ -220,7 +226,7 @@ fn create_json_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), CliError>
         .collect::<Vec<_>>();
 
     let index_path = dir_path.join("report.json");
-    json::write_report(&index_path, &testcases, &store_path)?;
+    json::write_report(&index_path, &testcases, &store_path, include_success_bodies)?;
     Ok(())
 }
 
@@ -256,22 +262,12 @@ fn create_cookies_file(runs: &[HurlRun], filename: &Path) -> Result<(), CliError
         }
         Ok(file) => file,
     };
-    let mut s = r#"# Netscape HTTP Cookie File
-# This file was generated by Hurl
-
-"#
-    .to_string();
-    match runs.first() {
+    let s = match runs.first() {
         None => {
             return Err(CliError::IO("Issue fetching results".to_string()));
         }
-        Some(run) => {
-            for cookie in run.hurl_result.cookies.iter() {
-                s.push_str(&cookie.to_string());
-                s.push('\n');
-            }
-        }
-    }
+        Some(run) => run.hurl_result.to_netscape_cookie_jar(),
+    };
 
     if let Err(why) = file.write_all(s.as_bytes()) {
         return Err(CliError::IO(format!(
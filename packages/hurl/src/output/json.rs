@@ -19,6 +19,7 @@ use std::io;
 
 use hurl_core::input::Input;
 
+use crate::json::VersionedResult;
 use crate::runner::{HurlResult, Output};
 use crate::util::term::Stdout;
 
@@ -36,8 +37,8 @@ pub fn write_json(
     stdout: &mut Stdout,
     append: bool,
 ) -> Result<(), io::Error> {
-    let json_result = hurl_result.to_json(content, filename_in, None)?;
-    let serialized = serde_json::to_string(&json_result).unwrap();
+    let json_result = hurl_result.to_json(content, filename_in, None, true)?;
+    let serialized = serde_json::to_string(&VersionedResult(&json_result)).unwrap();
     let bytes = format!("{serialized}\n");
     let bytes = bytes.into_bytes();
     match filename_out {
@@ -137,6 +137,7 @@ mod tests {
             duration: Default::default(),
             url: Url::from_str("http://localhost").unwrap(),
             certificate: None,
+            tls_version: None,
         }
     }
 
@@ -162,6 +163,7 @@ mod tests {
                         },
                         response: default_response(),
                         timings: Default::default(),
+                        h2_frames: vec![],
                     }],
                     captures: vec![],
                     asserts: vec![],
@@ -169,6 +171,7 @@ mod tests {
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
                     curl_cmd: CurlCmd::default(),
+                    warnings: vec![],
                 },
                 EntryResult {
                     entry_index: 2,
@@ -182,6 +185,7 @@ mod tests {
                         },
                         response: default_response(),
                         timings: Default::default(),
+                        h2_frames: vec![],
                     }],
                     captures: vec![],
                     asserts: vec![],
@@ -189,6 +193,7 @@ mod tests {
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
                     curl_cmd: CurlCmd::default(),
+                    warnings: vec![],
                 },
                 EntryResult {
                     entry_index: 3,
@@ -208,8 +213,10 @@ mod tests {
                             duration: Default::default(),
                             url: Url::from_str("https://baz.com").unwrap(),
                             certificate: None,
+                            tls_version: None,
                         },
                         timings: Default::default(),
+                        h2_frames: vec![],
                     }],
                     captures: vec![],
                     asserts: vec![],
@@ -217,6 +224,7 @@ mod tests {
                     transfer_duration: Duration::from_millis(0),
                     compressed: false,
                     curl_cmd: CurlCmd::default(),
+                    warnings: vec![],
                 },
             ],
             duration: Duration::from_millis(100),
@@ -40,17 +40,19 @@ use std::path::Path;
 
 use hurl_core::input::Input;
 
+use crate::json::VersionedResult;
 use crate::report::ReportError;
 use crate::runner::HurlResult;
 
 /// Exports a list of [`Testcase`] to a JSON file `filename`.
 ///
 /// Response file are saved under the `response_dir` directory and referenced by path in JSON report
-/// file.
+/// file. If `include_success_bodies` is `false`, only the responses of failed entries are saved.
 pub fn write_report(
     filename: &Path,
     testcases: &[Testcase],
     response_dir: &Path,
+    include_success_bodies: bool,
 ) -> Result<(), ReportError> {
     // We parse any potential existing report.
     let mut report = deserialize::parse_json_report(filename)?;
@@ -58,10 +60,11 @@ pub fn write_report(
     // Serialize the new report, extended any exiting one.
     let json = testcases
         .iter()
-        .map(|t| t.to_json(response_dir))
+        .map(|t| t.to_json(response_dir, include_success_bodies))
         .collect::<Result<Vec<_>, _>>()?;
     report.extend(json);
 
+    let report = report.iter().map(VersionedResult).collect::<Vec<_>>();
     let serialized = serde_json::to_string(&report).unwrap();
     let bytes = format!("{serialized}\n");
     let bytes = bytes.into_bytes();
@@ -94,8 +97,16 @@ impl<'a> Testcase<'a> {
     }
 
     /// Serializes this testcase to JSON.
-    fn to_json(&self, response_dir: &Path) -> Result<serde_json::Value, io::Error> {
-        self.result
-            .to_json(self.content, self.filename, Some(response_dir))
+    fn to_json(
+        &self,
+        response_dir: &Path,
+        include_success_bodies: bool,
+    ) -> Result<serde_json::Value, io::Error> {
+        self.result.to_json(
+            self.content,
+            self.filename,
+            Some(response_dir),
+            include_success_bodies,
+        )
     }
 }
@@ -54,6 +54,7 @@
 //! </testsuites>
 //! ```
 //!
+mod suite;
 mod testcase;
 mod xml;
 use std::fs::File;
@@ -190,6 +191,7 @@ mod tests {
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                warnings: vec![],
             }],
             duration: Duration::from_millis(230),
             success: true,
@@ -217,6 +219,7 @@ mod tests {
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                warnings: vec![],
             }],
             duration: Duration::from_millis(230),
             success: true,
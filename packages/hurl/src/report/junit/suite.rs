@@ -0,0 +1,241 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::input::Input;
+
+use crate::report::junit::xml::{Element, XmlDocument};
+use crate::report::junit::Testcase;
+use crate::runner::HurlResult;
+
+impl HurlResult {
+    /// Serializes this [`HurlResult`] to a JUnit XML report.
+    ///
+    /// The Hurl file `filename` is mapped to a `<testsuite>`, each entry to a `<testcase>` and each
+    /// failed assert of an entry to a `<failure>`, runtime errors being mapped to `<error>`. Entries
+    /// that have been retried are only reported once, with their last execution. `content` is the
+    /// source of the Hurl file, used to display errors messages.
+    pub fn to_junit_xml(&self, content: &str, filename: &Input) -> String {
+        let name = filename.to_string();
+        let mut tests = 0;
+        let mut failures = 0;
+        let mut errors = 0;
+        let mut testcases = vec![];
+
+        let mut next_entries = self.entries.iter().skip(1);
+        for entry in self.entries.iter() {
+            // Retried entries are followed by an entry with the same index.
+            if let Some(next) = next_entries.next() {
+                if next.entry_index == entry.entry_index {
+                    continue;
+                }
+            }
+            let testcase = Testcase::from_entry(entry, content, &name);
+            tests += 1;
+            failures += testcase.get_fail_count();
+            errors += testcase.get_error_count();
+            testcases.push(testcase);
+        }
+
+        let time_in_seconds = format!("{:.3}", self.duration.as_millis() as f64 / 1000.0);
+        let mut element = Element::new("testsuite")
+            .attr("name", &name)
+            .attr("tests", &tests.to_string())
+            .attr("failures", &failures.to_string())
+            .attr("errors", &errors.to_string())
+            .attr("time", &time_in_seconds);
+        for testcase in testcases.iter() {
+            element = element.add_child(testcase.to_xml());
+        }
+        // Text and attributes values are escaped by the XML writer.
+        XmlDocument::new(element).to_string().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use hurl_core::ast::SourceInfo;
+    use hurl_core::input::Input;
+    use hurl_core::reader::Pos;
+
+    use crate::http::HttpError;
+    use crate::runner::{
+        AssertResult, EntryResult, HurlResult, RunnerError, RunnerErrorKind, Value,
+    };
+
+    #[test]
+    fn test_to_junit_xml() {
+        let content = "GET http://localhost:8000/hello\n\
+                       HTTP 200\n\
+                       GET http://localhost:8000/not_found\n\
+                       HTTP 200\n\
+                       [Asserts]\n\
+                       header \"Content-Type\" == \"<html>\"\n\
+                       GET http://unknown\n";
+        let filename = Input::new("test.hurl");
+        let status_assert = |actual: u64, line: usize| AssertResult::Status {
+            actual,
+            expected: 200,
+            source_info: SourceInfo::new(Pos::new(line, 6), Pos::new(line, 9)),
+        };
+        let header_error = RunnerError::new(
+            SourceInfo::new(Pos::new(6, 27), Pos::new(6, 35)),
+            RunnerErrorKind::AssertFailure {
+                actual: "string <text/html>".to_string(),
+                expected: "string <<html>>".to_string(),
+                type_mismatch: false,
+                actual_value: None,
+            },
+            true,
+        );
+        let connection_error = RunnerError::new(
+            SourceInfo::new(Pos::new(7, 5), Pos::new(7, 19)),
+            RunnerErrorKind::Http(HttpError::Libcurl {
+                code: 6,
+                description: "Could not resolve host: unknown".to_string(),
+            }),
+            false,
+        );
+        let result = HurlResult {
+            entries: vec![
+                EntryResult {
+                    entry_index: 1,
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 32)),
+                    asserts: vec![status_assert(200, 2)],
+                    transfer_duration: Duration::from_millis(12),
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 2,
+                    source_info: SourceInfo::new(Pos::new(3, 1), Pos::new(3, 36)),
+                    asserts: vec![
+                        status_assert(404, 4),
+                        AssertResult::Explicit {
+                            actual: Ok(Some(Value::String("text/html".to_string()))),
+                            source_info: SourceInfo::new(Pos::new(6, 24), Pos::new(6, 26)),
+                            filters_source_info: vec![],
+                            predicate_result: Some(Err(header_error.clone())),
+                        },
+                    ],
+                    transfer_duration: Duration::from_millis(1500),
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 3,
+                    source_info: SourceInfo::new(Pos::new(7, 1), Pos::new(7, 19)),
+                    errors: vec![connection_error],
+                    ..Default::default()
+                },
+            ],
+            duration: Duration::from_millis(1600),
+            success: false,
+            cookies: vec![],
+            timestamp: 1,
+        };
+
+        let xml = result.to_junit_xml(content, &filename);
+        assert!(xml.starts_with(
+            r#"<?xml version="1.0" encoding="UTF-8"?><testsuite name="test.hurl" tests="3" failures="2" errors="1" time="1.600">"#
+        ));
+        assert!(xml.contains(
+            r#"<testcase id="test.hurl#1" name="test.hurl:1" classname="test.hurl" time="0.012" />"#
+        ));
+        assert!(xml.contains(
+            r#"<testcase id="test.hurl#2" name="test.hurl:3" classname="test.hurl" time="1.500"><failure>"#
+        ));
+        assert_eq!(xml.matches("<failure>").count(), 2);
+        assert_eq!(xml.matches("<error>").count(), 1);
+        // XML special characters of assert messages are escaped.
+        assert!(xml.contains("actual value is &lt;404&gt;"));
+        assert!(xml.contains("expected: string &lt;&lt;html&gt;&gt;"));
+        assert!(xml.contains("(6) Could not resolve host: unknown"));
+    }
+
+    #[test]
+    fn test_to_junit_xml_assert_errors() {
+        let content = "GET http://localhost:8000/hello\n\
+                       HTTP 200\n\
+                       [Asserts]\n\
+                       header \"Content-Type\" == \"text/plain\"\n";
+        let filename = Input::new("test.hurl");
+        let query_error = RunnerError::new(
+            SourceInfo::new(Pos::new(4, 1), Pos::new(4, 22)),
+            RunnerErrorKind::QueryHeaderNotFound,
+            true,
+        );
+        let predicate_error = RunnerError::new(
+            SourceInfo::new(Pos::new(4, 23), Pos::new(4, 25)),
+            RunnerErrorKind::AssertFailure {
+                actual: "none".to_string(),
+                expected: "string <text/plain>".to_string(),
+                type_mismatch: false,
+                actual_value: None,
+            },
+            true,
+        );
+        let result = HurlResult {
+            entries: vec![EntryResult {
+                entry_index: 1,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 32)),
+                asserts: vec![AssertResult::Explicit {
+                    actual: Err(query_error),
+                    source_info: SourceInfo::new(Pos::new(4, 23), Pos::new(4, 25)),
+                    filters_source_info: vec![],
+                    predicate_result: Some(Err(predicate_error)),
+                }],
+                ..Default::default()
+            }],
+            duration: Duration::from_millis(10),
+            success: false,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        // Every error of an assert is reported as a failure.
+        let xml = result.to_junit_xml(content, &filename);
+        assert!(xml.contains(r#"tests="1" failures="2" errors="0""#));
+        assert_eq!(xml.matches("<failure>").count(), 2);
+    }
+
+    #[test]
+    fn test_to_junit_xml_retry() {
+        let content = "GET http://localhost:8000/retry\nHTTP 200\n";
+        let filename = Input::new("test.hurl");
+        let entry = |actual: u64| EntryResult {
+            entry_index: 1,
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 32)),
+            asserts: vec![AssertResult::Status {
+                actual,
+                expected: 200,
+                source_info: SourceInfo::new(Pos::new(2, 6), Pos::new(2, 9)),
+            }],
+            ..Default::default()
+        };
+        let result = HurlResult {
+            entries: vec![entry(500), entry(500), entry(200)],
+            duration: Duration::from_millis(10),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+        let xml = result.to_junit_xml(content, &filename);
+        assert_eq!(
+            xml,
+            r#"<?xml version="1.0" encoding="UTF-8"?><testsuite name="test.hurl" tests="1" failures="0" errors="0" time="0.010"><testcase id="test.hurl#1" name="test.hurl:1" classname="test.hurl" time="0.000" /></testsuite>"#
+        );
+    }
+}
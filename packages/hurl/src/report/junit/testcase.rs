@@ -19,12 +19,13 @@ use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 
 use crate::report::junit::xml::Element;
-use crate::runner::HurlResult;
+use crate::runner::{EntryResult, HurlResult, RunnerError};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
     id: String,
     name: String,
+    classname: Option<String>,
     time_in_ms: u128,
     failures: Vec<String>,
     errors: Vec<String>,
@@ -55,20 +56,60 @@ impl Testcase {
         Testcase {
             id,
             name,
+            classname: None,
             time_in_ms,
             failures,
             errors,
         }
     }
 
+    /// Creates an XML Junit &lt;testcase&gt; from an `entry` result of the Hurl file `filename`
+    /// with source `content`.
+    ///
+    /// Each error of the entry asserts is mapped to a failure, other runtime errors are mapped to
+    /// errors.
+    pub fn from_entry(entry: &EntryResult, content: &str, filename: &str) -> Testcase {
+        let message = |error: &RunnerError| {
+            error.to_string(
+                filename,
+                content,
+                Some(entry.source_info),
+                OutputFormat::Plain,
+            )
+        };
+        let failures = entry
+            .asserts
+            .iter()
+            .flat_map(|a| a.errors())
+            .map(|error| message(&error))
+            .collect::<Vec<_>>();
+        let errors = entry
+            .errors
+            .iter()
+            .filter(|error| !error.assert)
+            .map(message)
+            .collect::<Vec<_>>();
+        Testcase {
+            id: format!("{filename}#{}", entry.entry_index),
+            name: format!("{filename}:{}", entry.source_info.start.line),
+            classname: Some(filename.to_string()),
+            time_in_ms: entry.transfer_duration.as_millis(),
+            failures,
+            errors,
+        }
+    }
+
     /// Serializes this testcase to XML.
     pub fn to_xml(&self) -> Element {
         let time_in_seconds = format!("{:.3}", self.time_in_ms as f64 / 1000.0);
 
         let mut element = Element::new("testcase")
             .attr("id", &self.id)
-            .attr("name", &self.name)
-            .attr("time", &time_in_seconds);
+            .attr("name", &self.name);
+        if let Some(classname) = &self.classname {
+            element = element.attr("classname", classname);
+        }
+        element = element.attr("time", &time_in_seconds);
 
         for failure in self.failures.iter() {
             element = element.add_child(Element::new("failure").text(failure));
@@ -145,6 +186,7 @@ HTTP/1.0 200
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                warnings: vec![],
             }],
             duration: Duration::from_millis(230),
             success: true,
@@ -188,6 +230,7 @@ HTTP/1.0 200
                 transfer_duration: Duration::from_millis(0),
                 compressed: false,
                 curl_cmd: CurlCmd::default(),
+                warnings: vec![],
             }],
             duration: Duration::from_millis(230),
             success: true,
@@ -66,7 +66,6 @@ impl From<FromUtf8Error> for WriterError {
 
 impl XmlDocument {
     /// Convenient method to serialize an XML document to a string.
-    #[allow(dead_code)]
     pub fn to_string(&self) -> Result<String, WriterError> {
         let buffer = vec![];
         let buffer = self.write(buffer)?;
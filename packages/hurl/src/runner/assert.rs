@@ -15,23 +15,35 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Assert, SourceInfo};
+use hurl_core::ast::{Assert, PredicateFuncValue, PredicateValue, QueryValue, SourceInfo};
 use hurl_core::reader::Pos;
 
 use crate::http;
+use crate::runner::body_scan::eval_body_scan_assert;
 use crate::runner::cache::BodyCache;
 use crate::runner::diff::diff;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
-use crate::runner::filter::eval_filters;
+use crate::runner::eval_context::EvalContext;
+use crate::runner::filter::{eval_filters, is_optional};
 use crate::runner::predicate::eval_predicate;
+use crate::runner::predicate_value::eval_predicate_value;
 use crate::runner::query::eval_query;
-use crate::runner::result::AssertResult;
-use crate::runner::{Value, VariableSet};
+use crate::runner::result::{AssertResult, PredicateResult};
+use crate::runner::{Number, Value, VariableSet};
 use crate::util::path::ContextDir;
 
 impl AssertResult {
     /// Evaluates an assert and returns `None` if assert is succeeded or an `Error` if failed.
+    ///
+    /// If the assert has multiple errors, the first one is returned.
     pub fn error(&self) -> Option<RunnerError> {
+        self.errors().into_iter().next()
+    }
+
+    /// Evaluates an assert and returns all its errors, an empty list if assert is succeeded.
+    ///
+    /// An explicit assert can have an error in its filters chain and a predicate failure.
+    pub fn errors(&self) -> Vec<RunnerError> {
         match self {
             AssertResult::Version {
                 actual,
@@ -42,12 +54,12 @@ impl AssertResult {
                     || expected.as_str() == "HTTP/*"
                     || actual == expected
                 {
-                    None
+                    vec![]
                 } else {
                     let kind = RunnerErrorKind::AssertVersion {
                         actual: actual.to_string(),
                     };
-                    Some(RunnerError::new(*source_info, kind, false))
+                    vec![RunnerError::new(*source_info, kind, false)]
                 }
             }
             AssertResult::Status {
@@ -56,26 +68,27 @@ impl AssertResult {
                 source_info,
             } => {
                 if actual == expected {
-                    None
+                    vec![]
                 } else {
                     let kind = RunnerErrorKind::AssertStatus {
                         actual: actual.to_string(),
                     };
-                    Some(RunnerError::new(*source_info, kind, false))
+                    vec![RunnerError::new(*source_info, kind, false)]
                 }
             }
             AssertResult::Header {
                 actual,
                 expected,
                 source_info,
+                ignore_case,
             } => match actual {
-                Err(e) => Some(e.clone()),
+                Err(e) => vec![e.clone()],
                 Ok(s) => {
-                    if s == expected {
-                        None
+                    if header_value_equal(s, expected, *ignore_case) {
+                        vec![]
                     } else {
                         let kind = RunnerErrorKind::AssertHeaderValueError { actual: s.clone() };
-                        Some(RunnerError::new(*source_info, kind, false))
+                        vec![RunnerError::new(*source_info, kind, false)]
                     }
                 }
             },
@@ -84,12 +97,12 @@ impl AssertResult {
                 expected,
                 source_info,
             } => match expected {
-                Err(e) => Some(e.clone()),
+                Err(e) => vec![e.clone()],
                 Ok(expected) => match actual {
-                    Err(e) => Some(e.clone()),
+                    Err(e) => vec![e.clone()],
                     Ok(actual) => {
                         if actual == expected {
-                            None
+                            vec![]
                         } else if use_diff(expected, actual) {
                             let actual = actual.to_string();
                             let expected = expected.to_string();
@@ -107,22 +120,34 @@ impl AssertResult {
                                 Pos::new(source_info.start.line + source_line, 1),
                                 Pos::new(source_info.start.line + source_line, 1),
                             );
-                            Some(RunnerError::new(diff_source_info, kind, false))
+                            vec![RunnerError::new(diff_source_info, kind, false)]
                         } else {
-                            let actual = actual.to_string();
-                            let expected = expected.to_string();
-                            let kind = RunnerErrorKind::AssertBodyValueError { actual, expected };
-                            Some(RunnerError::new(*source_info, kind, false))
+                            let actual_value =
+                                actual.is_structured().then(|| Box::new(actual.clone()));
+                            let kind = RunnerErrorKind::AssertBodyValueError {
+                                actual: actual.to_string(),
+                                expected: expected.to_string(),
+                                actual_value,
+                            };
+                            vec![RunnerError::new(*source_info, kind, false)]
                         }
                     }
                 },
             },
-            AssertResult::Explicit { actual: Err(e), .. } => Some(e.clone()),
             AssertResult::Explicit {
-                predicate_result: Some(Err(e)),
+                actual,
+                predicate_result,
                 ..
-            } => Some(e.clone()),
-            _ => None,
+            } => {
+                let mut errors = vec![];
+                if let Err(e) = actual {
+                    errors.push(e.clone());
+                }
+                if let Some(Err(e)) = predicate_result {
+                    errors.push(e.clone());
+                }
+                errors
+            }
         }
     }
     pub fn line(&self) -> usize {
@@ -134,6 +159,38 @@ impl AssertResult {
             AssertResult::Explicit { source_info, .. } => source_info.start.line,
         }
     }
+
+    /// Returns the source info of the predicate of this assert.
+    pub fn source_info(&self) -> SourceInfo {
+        match self {
+            AssertResult::Version { source_info, .. }
+            | AssertResult::Status { source_info, .. }
+            | AssertResult::Header { source_info, .. }
+            | AssertResult::Body { source_info, .. }
+            | AssertResult::Explicit { source_info, .. } => *source_info,
+        }
+    }
+
+    /// Returns the source info of the filters of this assert.
+    pub fn filters_source_info(&self) -> &[SourceInfo] {
+        match self {
+            AssertResult::Explicit {
+                filters_source_info,
+                ..
+            } => filters_source_info,
+            _ => &[],
+        }
+    }
+}
+
+/// Returns `true` if an `actual` header value is equal to an `expected` one, ignoring ASCII case
+/// if `ignore_case` is set.
+pub(crate) fn header_value_equal(actual: &str, expected: &str, ignore_case: bool) -> bool {
+    if ignore_case {
+        actual.eq_ignore_ascii_case(expected)
+    } else {
+        actual == expected
+    }
 }
 
 fn use_diff(expected: &Value, actual: &Value) -> bool {
@@ -152,16 +209,38 @@ fn use_diff(expected: &Value, actual: &Value) -> bool {
 pub fn eval_explicit_assert(
     assert: &Assert,
     variables: &VariableSet,
+    http_request: &http::Request,
     http_response: &http::Response,
     cache: &mut BodyCache,
     context_dir: &ContextDir,
+    eval_context: &mut EvalContext,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_response, cache);
+    if let Some(result) =
+        eval_body_scan_assert(assert, variables, http_response, context_dir, eval_context)
+    {
+        return result;
+    }
+
+    let query_result = eval_query(
+        &assert.query,
+        variables,
+        http_request,
+        http_response,
+        cache,
+        eval_context,
+    );
+    let query_failed = query_result.is_err();
 
     let actual = if assert.filters.is_empty() {
         query_result
     } else if let Ok(optional_value) = query_result {
+        let filters = assert
+            .filters
+            .iter()
+            .map(|(_, f)| f.clone())
+            .collect::<Vec<_>>();
         match optional_value {
+            None if is_optional(&filters) => Ok(None),
             None => Err(RunnerError {
                 source_info: assert
                     .filters
@@ -172,47 +251,117 @@ pub fn eval_explicit_assert(
                 kind: RunnerErrorKind::FilterMissingInput,
                 assert: true,
             }),
-            Some(value) => {
-                let filters = assert
-                    .filters
-                    .iter()
-                    .map(|(_, f)| f.clone())
-                    .collect::<Vec<_>>();
-                match eval_filters(&filters, &value, variables, true) {
-                    Ok(value) => Ok(value),
-                    Err(e) => Err(e),
-                }
-            }
+            Some(value) => match eval_filters(&filters, &value, variables, eval_context, true) {
+                Ok(value) => Ok(value),
+                Err(e) => Err(e),
+            },
         }
     } else {
         query_result
     };
 
     let source_info = assert.predicate.predicate_func.source_info;
-    let predicate_result = match &actual {
-        Err(_) => None,
-        Ok(actual) => Some(eval_predicate(
+    let predicate_result = match (&actual, status_range(assert)) {
+        (Err(_), _) if query_failed => None,
+        (Ok(Some(Value::Number(Number::Integer(status)))), Some((min, max))) => {
+            Some(eval_status_range(
+                assert,
+                *status,
+                min,
+                max,
+                variables,
+                context_dir,
+                eval_context,
+            ))
+        }
+        // If the filters chain fails, the predicate is still evaluated against no value so that
+        // all the errors of the assert are reported.
+        (Err(_), _) => Some(eval_predicate(
+            &assert.predicate,
+            variables,
+            &None,
+            context_dir,
+            eval_context,
+        )),
+        (Ok(actual), _) => Some(eval_predicate(
             &assert.predicate,
             variables,
             actual,
             context_dir,
+            eval_context,
         )),
     };
 
+    let filters_source_info = assert.filters.iter().map(|(_, f)| f.source_info).collect();
+
     AssertResult::Explicit {
         actual,
         source_info,
+        filters_source_info,
         predicate_result,
     }
 }
 
+/// Returns the bounds of the range predicate if this `assert` checks the range of the response
+/// status (`status isInRange 200 299`).
+fn status_range(assert: &Assert) -> Option<(&PredicateValue, &PredicateValue)> {
+    if !assert.filters.is_empty() || assert.predicate.not {
+        return None;
+    }
+    match (&assert.query.value, &assert.predicate.predicate_func.value) {
+        (QueryValue::Status, PredicateFuncValue::IsInRange { min, max, .. }) => Some((min, max)),
+        _ => None,
+    }
+}
+
+/// Evaluates a range predicate, with bounds `min` and `max`, against the response `status`.
+///
+/// Contrary to the generic predicate evaluation, a failure is reported with the actual status and
+/// the expected range under the predicate.
+fn eval_status_range(
+    assert: &Assert,
+    status: i64,
+    min: &PredicateValue,
+    max: &PredicateValue,
+    variables: &VariableSet,
+    context_dir: &ContextDir,
+    eval_context: &mut EvalContext,
+) -> PredicateResult {
+    let min = eval_predicate_value(min, variables, context_dir)?;
+    let max = eval_predicate_value(max, variables, context_dir)?;
+    let (Value::Number(Number::Integer(min)), Value::Number(Number::Integer(max))) = (min, max)
+    else {
+        // Non integer bounds are evaluated as any other predicate.
+        return eval_predicate(
+            &assert.predicate,
+            variables,
+            &Some(Value::Number(Number::Integer(status))),
+            context_dir,
+            eval_context,
+        );
+    };
+    if min <= status && status <= max {
+        return Ok(());
+    }
+    let kind = RunnerErrorKind::AssertStatusRange {
+        actual: status.to_string(),
+        min,
+        max,
+    };
+    Err(RunnerError::new(
+        assert.predicate.predicate_func.source_info,
+        kind,
+        true,
+    ))
+}
+
 #[cfg(test)]
 pub mod tests {
     use std::path::Path;
 
     use hurl_core::ast::{
         Filter, FilterValue, LineTerminator, Predicate, PredicateFunc, PredicateFuncValue,
-        PredicateValue, SourceInfo, Whitespace,
+        PredicateValue, Query, QueryValue, SourceInfo, Whitespace,
     };
     use hurl_core::reader::Pos;
 
@@ -274,18 +423,124 @@ pub mod tests {
             eval_explicit_assert(
                 &assert_count_user(),
                 &variables,
+                &http::default_http_request(),
                 &xml_three_users_http_response(),
                 &mut cache,
-                &context_dir
+                &context_dir,
+                &mut EvalContext::default()
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(3)))),
                 source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 24)),
+                filters_source_info: vec![SourceInfo::new(Pos::new(1, 16), Pos::new(1, 21))],
                 predicate_result: Some(Ok(())),
             }
         );
     }
 
+    #[test]
+    fn test_eval_filter_error_and_predicate_failure() {
+        // `xpath "//user" toInt == 3`
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let mut cache = BodyCache::new();
+        let mut assert = assert_count_user();
+        assert.filters[0].1.value = FilterValue::ToInt;
+
+        let result = eval_explicit_assert(
+            &assert,
+            &variables,
+            &http::default_http_request(),
+            &xml_three_users_http_response(),
+            &mut cache,
+            &context_dir,
+            &mut EvalContext::default(),
+        );
+        let errors = result.errors();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(
+            errors[0].kind,
+            RunnerErrorKind::FilterInvalidInput(_)
+        ));
+        assert_eq!(
+            errors[1].kind,
+            RunnerErrorKind::AssertFailure {
+                actual: "none".to_string(),
+                expected: "integer <3>".to_string(),
+                type_mismatch: false,
+                actual_value: None,
+            }
+        );
+        assert_eq!(result.error(), Some(errors[0].clone()));
+    }
+
+    #[test]
+    fn test_eval_status_range() {
+        // `status isInRange 300 399`
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+        let mut cache = BodyCache::new();
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        };
+        let predicate_source_info = SourceInfo::new(Pos::new(1, 8), Pos::new(1, 25));
+        let mut assert = assert_count_user();
+        assert.query = Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: QueryValue::Status,
+        };
+        assert.filters = vec![];
+        assert.predicate.predicate_func = PredicateFunc {
+            source_info: predicate_source_info,
+            value: PredicateFuncValue::IsInRange {
+                space0: whitespace.clone(),
+                min: PredicateValue::Number(hurl_core::ast::Number::Integer(300)),
+                space1: whitespace,
+                max: PredicateValue::Number(hurl_core::ast::Number::Integer(399)),
+            },
+        };
+
+        let result = eval_explicit_assert(
+            &assert,
+            &variables,
+            &http::default_http_request(),
+            &xml_three_users_http_response(),
+            &mut cache,
+            &context_dir,
+            &mut EvalContext::default(),
+        );
+        assert_eq!(
+            result.error(),
+            Some(RunnerError::new(
+                predicate_source_info,
+                RunnerErrorKind::AssertStatusRange {
+                    actual: "200".to_string(),
+                    min: 300,
+                    max: 399,
+                },
+                true
+            ))
+        );
+
+        // With the `not` modifier, the generic predicate evaluation is used.
+        assert.predicate.not = true;
+        let result = eval_explicit_assert(
+            &assert,
+            &variables,
+            &http::default_http_request(),
+            &xml_three_users_http_response(),
+            &mut cache,
+            &context_dir,
+            &mut EvalContext::default(),
+        );
+        assert_eq!(result.error(), None);
+    }
+
     #[test]
     pub fn test_use_diff() {
         assert!(!use_diff(&Value::Bool(true), &Value::Bool(false)));
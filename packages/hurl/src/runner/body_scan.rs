@@ -0,0 +1,442 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::io;
+use std::io::Read;
+
+use hurl_core::ast::{Assert, FilterValue, PredicateFuncValue, QueryValue};
+
+use crate::http;
+use crate::runner::eval_context::EvalContext;
+use crate::runner::predicate::eval_predicate;
+use crate::runner::predicate_value::eval_predicate_value;
+use crate::runner::result::AssertResult;
+use crate::runner::{Number, Value, VariableSet};
+use crate::util::path::ContextDir;
+
+/// Size of the chunks read when scanning a response body.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Evaluates an explicit `assert` by scanning incrementally the HTTP response body.
+///
+/// The HTTP layer still buffers the whole response body: scanning it only avoids building the
+/// [`Value`] of the body, or searching it twice, to evaluate simple asserts:
+/// - `bytes contains <bytes>` and `rawBytes contains <bytes>` search the body read by chunks and
+///   stop searching on the first match (the body read is kept as the actual value of the assert),
+/// - `bytes count` and `rawBytes count` count the bytes read by chunks, without keeping them.
+///
+/// Other asserts (`body`, JSONPath, XPath etc...) still use the buffered body. Returns `None` if
+/// `assert` can't be evaluated by a scan, or if the scan fails: in this case, the assert is
+/// evaluated on the buffered body, to get the actual value in the error message.
+pub fn eval_body_scan_assert(
+    assert: &Assert,
+    variables: &VariableSet,
+    http_response: &http::Response,
+    context_dir: &ContextDir,
+    eval_context: &mut EvalContext,
+) -> Option<AssertResult> {
+    let reader: Box<dyn Read> = match assert.query.value {
+        QueryValue::RawBytes => Box::new(http_response.body.as_slice()),
+        QueryValue::Bytes => http_response.body_reader().ok()?,
+        _ => return None,
+    };
+    eval_scan_assert(assert, variables, reader, context_dir, eval_context)
+}
+
+/// Evaluates an explicit `assert` by scanning the body read from `reader`.
+///
+fn eval_scan_assert<R: Read>(
+    assert: &Assert,
+    variables: &VariableSet,
+    reader: R,
+    context_dir: &ContextDir,
+    eval_context: &mut EvalContext,
+) -> Option<AssertResult> {
+    let source_info = assert.predicate.predicate_func.source_info;
+    match assert.filters.as_slice() {
+        [] => {
+            let PredicateFuncValue::Contain { value, .. } = &assert.predicate.predicate_func.value
+            else {
+                return None;
+            };
+            let needle = match eval_predicate_value(value, variables, context_dir) {
+                Ok(Value::Bytes(needle)) => needle,
+                _ => return None,
+            };
+            if needle.is_empty() {
+                return None;
+            }
+            let scan = scan(reader, &needle, true).ok()?;
+            if scan.found == assert.predicate.not {
+                return None;
+            }
+            Some(AssertResult::Explicit {
+                actual: Ok(Some(Value::Bytes(scan.body))),
+                source_info,
+                filters_source_info: vec![],
+                predicate_result: Some(Ok(())),
+            })
+        }
+        [(_, filter)] if filter.value == FilterValue::Count => {
+            let scan = scan(reader, &[], false).ok()?;
+            let actual = Some(Value::Number(Number::Integer(scan.size as i64)));
+            let predicate_result = eval_predicate(
+                &assert.predicate,
+                variables,
+                &actual,
+                context_dir,
+                eval_context,
+            );
+            Some(AssertResult::Explicit {
+                actual: Ok(actual),
+                source_info,
+                filters_source_info: vec![filter.source_info],
+                predicate_result: Some(predicate_result),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// The result of a body scan.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Scan {
+    /// Number of bytes read.
+    size: usize,
+    /// Whether the needle has been found.
+    found: bool,
+    /// The bytes read, if they're kept.
+    body: Vec<u8>,
+}
+
+/// Reads `reader` by chunks of [`CHUNK_SIZE`] bytes, counting the bytes read and searching
+/// `needle` (an empty `needle` is never searched).
+///
+/// If `keep` is `true`, the bytes read are kept in the returned [`Scan`] and the whole body is read,
+/// the search stopping on the first match of `needle`. Otherwise, reading stops on the first match.
+fn scan<R: Read>(mut reader: R, needle: &[u8], keep: bool) -> io::Result<Scan> {
+    let mut chunk = vec![0; CHUNK_SIZE];
+    // A match can span two chunks: we keep the end of the previous chunk in the window.
+    let mut window = Vec::with_capacity(CHUNK_SIZE + needle.len());
+    let mut body = vec![];
+    let mut size = 0;
+    let mut found = false;
+    loop {
+        let count = match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(count) => count,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        size += count;
+        let chunk = &chunk[..count];
+        if keep {
+            body.extend_from_slice(chunk);
+        }
+        if found || needle.is_empty() {
+            continue;
+        }
+        window.extend_from_slice(chunk);
+        if window.windows(needle.len()).any(|w| w == needle) {
+            found = true;
+            if !keep {
+                break;
+            }
+        }
+        let start = window.len().saturating_sub(needle.len() - 1);
+        window.drain(..start);
+    }
+    Ok(Scan { size, found, body })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use hurl_core::ast::{
+        Filter, Hex, LineTerminator, Predicate, PredicateFunc, PredicateValue, Query, SourceInfo,
+        Template, TemplateElement, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::http::{xml_two_users_http_response, Header};
+
+    /// A reader counting the bytes read from an inner reader.
+    struct CountingReader<R> {
+        inner: R,
+        count: usize,
+    }
+
+    impl<R: Read> Read for CountingReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let count = self.inner.read(buf)?;
+            self.count += count;
+            Ok(count)
+        }
+    }
+
+    /// Returns a 16 MB fixture, with `needle` at `offset`.
+    fn large_body(needle: &[u8], offset: usize) -> Vec<u8> {
+        let mut body = vec![b'a'; 16 * 1024 * 1024];
+        body[offset..offset + needle.len()].copy_from_slice(needle);
+        body
+    }
+
+    /// Returns a response with a large gzip compressed body, with `needle` at `offset`.
+    fn large_gzip_response(needle: &[u8], offset: usize) -> http::Response {
+        let mut encoder = libflate::gzip::Encoder::new(Vec::new()).unwrap();
+        encoder.write_all(&large_body(needle, offset)).unwrap();
+        let mut response = xml_two_users_http_response();
+        response
+            .headers
+            .push(Header::new("Content-Encoding", "gzip"));
+        response.body = encoder.finish().into_result().unwrap();
+        response
+    }
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        }
+    }
+
+    /// Returns the assert `query [filter] predicate_func`.
+    fn new_assert(
+        query: QueryValue,
+        filter: Option<FilterValue>,
+        predicate_func: PredicateFuncValue,
+    ) -> Assert {
+        let filters = match filter {
+            Some(value) => vec![(
+                whitespace(),
+                Filter {
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 12)),
+                    value,
+                },
+            )],
+            None => vec![],
+        };
+        Assert {
+            line_terminators: vec![],
+            space0: whitespace(),
+            query: Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6)),
+                value: query,
+            },
+            filters,
+            space1: whitespace(),
+            predicate: Predicate {
+                not: false,
+                space0: whitespace(),
+                predicate_func: PredicateFunc {
+                    source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 21)),
+                    value: predicate_func,
+                },
+            },
+            line_terminator0: LineTerminator {
+                space0: whitespace(),
+                comment: None,
+                newline: whitespace(),
+            },
+        }
+    }
+
+    // `contains hex,cafe;`
+    fn contains_cafe() -> PredicateFuncValue {
+        PredicateFuncValue::Contain {
+            space0: whitespace(),
+            value: PredicateValue::Hex(Hex {
+                space0: whitespace(),
+                value: vec![0xca, 0xfe],
+                encoded: "cafe".to_string(),
+                space1: whitespace(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_scan_short_circuit() {
+        let body = large_body(&[0xca, 0xfe], 1000);
+        let mut reader = CountingReader {
+            inner: body.as_slice(),
+            count: 0,
+        };
+        assert_eq!(
+            scan(&mut reader, &[0xca, 0xfe], false).unwrap(),
+            Scan {
+                size: CHUNK_SIZE,
+                found: true,
+                body: vec![],
+            }
+        );
+        assert_eq!(reader.count, CHUNK_SIZE);
+
+        let mut reader = CountingReader {
+            inner: body.as_slice(),
+            count: 0,
+        };
+        assert_eq!(
+            scan(&mut reader, b"hurl", false).unwrap(),
+            Scan {
+                size: body.len(),
+                found: false,
+                body: vec![],
+            }
+        );
+        assert_eq!(reader.count, body.len());
+    }
+
+    #[test]
+    fn test_scan_across_chunks() {
+        let body = large_body(b"hurl", CHUNK_SIZE - 2);
+        assert!(scan(body.as_slice(), b"hurl", false).unwrap().found);
+        assert!(scan(b"hurl".as_slice(), b"hurl", false).unwrap().found);
+        assert!(!scan(b"hur".as_slice(), b"hurl", false).unwrap().found);
+    }
+
+    #[test]
+    fn test_scan_keep() {
+        // The kept body is fully read, even after a match.
+        let body = large_body(&[0xca, 0xfe], 1000);
+        let mut reader = CountingReader {
+            inner: body.as_slice(),
+            count: 0,
+        };
+        assert_eq!(
+            scan(&mut reader, &[0xca, 0xfe], true).unwrap(),
+            Scan {
+                size: body.len(),
+                found: true,
+                body: body.clone(),
+            }
+        );
+        assert_eq!(reader.count, body.len());
+    }
+
+    #[test]
+    fn test_eval_body_scan_assert() {
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+        let http_response = large_gzip_response(&[0xca, 0xfe], 1000);
+
+        // `bytes contains hex,cafe;` reads the decompressed body once, as the actual value.
+        let assert = new_assert(QueryValue::Bytes, None, contains_cafe());
+        let mut reader = CountingReader {
+            inner: http_response.body_reader().unwrap(),
+            count: 0,
+        };
+        assert_eq!(
+            eval_scan_assert(
+                &assert,
+                &variables,
+                &mut reader,
+                &context_dir,
+                &mut EvalContext::default()
+            ),
+            Some(AssertResult::Explicit {
+                actual: Ok(Some(Value::Bytes(large_body(&[0xca, 0xfe], 1000)))),
+                source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 21)),
+                filters_source_info: vec![],
+                predicate_result: Some(Ok(())),
+            })
+        );
+        assert_eq!(reader.count, 16 * 1024 * 1024);
+
+        // `bytes count == 16777216`
+        let assert = new_assert(
+            QueryValue::Bytes,
+            Some(FilterValue::Count),
+            PredicateFuncValue::Equal {
+                space0: whitespace(),
+                value: PredicateValue::Number(hurl_core::ast::Number::Integer(16777216)),
+                operator: true,
+            },
+        );
+        assert_eq!(
+            eval_body_scan_assert(
+                &assert,
+                &variables,
+                &http_response,
+                &context_dir,
+                &mut EvalContext::default()
+            ),
+            Some(AssertResult::Explicit {
+                actual: Ok(Some(Value::Number(Number::Integer(16777216)))),
+                source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 21)),
+                filters_source_info: vec![SourceInfo::new(Pos::new(1, 7), Pos::new(1, 12))],
+                predicate_result: Some(Ok(())),
+            })
+        );
+
+        // `body contains "café"` is evaluated on the buffered body.
+        let http_response = large_gzip_response("café".as_bytes(), 1000);
+        let assert = new_assert(
+            QueryValue::Body,
+            None,
+            PredicateFuncValue::Contain {
+                space0: whitespace(),
+                value: PredicateValue::String(Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "café".to_string(),
+                        encoded: "café".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 21)),
+                }),
+            },
+        );
+        assert_eq!(
+            eval_body_scan_assert(
+                &assert,
+                &variables,
+                &http_response,
+                &context_dir,
+                &mut EvalContext::default()
+            ),
+            None
+        );
+
+        // A failed scan is evaluated on the buffered body.
+        let http_response = large_gzip_response(b"", 0);
+        let assert = new_assert(QueryValue::Bytes, None, contains_cafe());
+        assert_eq!(
+            eval_body_scan_assert(
+                &assert,
+                &variables,
+                &http_response,
+                &context_dir,
+                &mut EvalContext::default()
+            ),
+            None
+        );
+
+        // Other queries are not scanned.
+        let assert = new_assert(QueryValue::Sha256, None, contains_cafe());
+        assert_eq!(
+            eval_body_scan_assert(
+                &assert,
+                &variables,
+                &http_response,
+                &context_dir,
+                &mut EvalContext::default()
+            ),
+            None
+        );
+    }
+}
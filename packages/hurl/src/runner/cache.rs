@@ -61,6 +61,7 @@ impl BodyCache {
 #[cfg(test)]
 mod tests {
     use crate::runner::cache::BodyCache;
+    use crate::runner::eval_context::DEFAULT_XPATH_OP_LIMIT;
     use crate::runner::xpath::{Document, Format};
     use crate::runner::Value;
 
@@ -75,7 +76,8 @@ mod tests {
                     </html>";
         let doc = Document::parse(html, Format::Html).unwrap();
         assert_eq!(
-            doc.eval_xpath("string(//h1)").unwrap(),
+            doc.eval_xpath("string(//h1)", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
             Value::String("My First Heading".to_string())
         );
 
@@ -85,7 +87,8 @@ mod tests {
         cache.set_xml(doc);
         let doc = cache.xml().unwrap();
         assert_eq!(
-            doc.eval_xpath("string(//h1)").unwrap(),
+            doc.eval_xpath("string(//h1)", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
             Value::String("My First Heading".to_string())
         );
     }
@@ -20,6 +20,7 @@ use hurl_core::ast::Capture;
 use crate::http;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::eval_context::EvalContext;
 use crate::runner::filter::eval_filters;
 use crate::runner::query::eval_query;
 use crate::runner::result::CaptureResult;
@@ -34,11 +35,20 @@ use crate::runner::VariableSet;
 pub fn eval_capture(
     capture: &Capture,
     variables: &VariableSet,
+    http_request: &http::Request,
     http_response: &http::Response,
     cache: &mut BodyCache,
+    eval_context: &mut EvalContext,
 ) -> Result<CaptureResult, RunnerError> {
     let name = eval_template(&capture.name, variables)?;
-    let value = eval_query(&capture.query, variables, http_response, cache)?;
+    let value = eval_query(
+        &capture.query,
+        variables,
+        http_request,
+        http_response,
+        cache,
+        eval_context,
+    )?;
     let value = match value {
         None => {
             return Err(RunnerError::new(
@@ -53,7 +63,7 @@ pub fn eval_capture(
                 .iter()
                 .map(|(_, f)| f.clone())
                 .collect::<Vec<_>>();
-            match eval_filters(&filters, &value, variables, false)? {
+            match eval_filters(&filters, &value, variables, eval_context, false)? {
                 None => {
                     return Err(RunnerError::new(
                         capture.query.source_info,
@@ -75,7 +85,8 @@ pub fn eval_capture(
 #[cfg(test)]
 pub mod tests {
     use hurl_core::ast::{
-        LineTerminator, Query, QueryValue, SourceInfo, Template, TemplateElement, Whitespace,
+        LineTerminator, Query, QueryValue, SectionValue, SourceInfo, Template, TemplateElement,
+        Whitespace,
     };
     use hurl_core::reader::Pos;
 
@@ -179,13 +190,20 @@ pub mod tests {
         let error = eval_capture(
             &capture,
             &variables,
+            &http::default_http_request(),
             &http::xml_three_users_http_response(),
             &mut cache,
+            &mut EvalContext::default(),
         )
         .err()
         .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 7 });
-        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidXpathEval);
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidXpathEval {
+                unbound_prefix: None
+            }
+        );
     }
 
     #[test]
@@ -242,13 +260,15 @@ pub mod tests {
             eval_capture(
                 &user_count_capture(),
                 &variables,
+                &http::default_http_request(),
                 &http::xml_three_users_http_response(),
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap(),
             CaptureResult {
                 name: "UserCount".to_string(),
-                value: Value::Number(Number::from(3.0)),
+                value: Value::Number(Number::Integer(3)),
             }
         );
 
@@ -256,8 +276,10 @@ pub mod tests {
             eval_capture(
                 &duration_capture(),
                 &variables,
+                &http::default_http_request(),
                 &http::json_http_response(),
-                &mut cache
+                &mut cache,
+                &mut EvalContext::default()
             )
             .unwrap(),
             CaptureResult {
@@ -266,4 +288,109 @@ pub mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_capture_jsonpath_collection_with_nulls() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let content = "GET http://localhost:8000/items\n\
+                       HTTP 200\n\
+                       [Captures]\n\
+                       items: jsonpath \"$.items[*]\"\n\
+                       items_count: jsonpath \"$.items[*]\" count\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = hurl_file.entries[0].response.as_ref().unwrap();
+        let SectionValue::Captures(captures) = &response.sections[0].value else {
+            panic!("expected captures section");
+        };
+        let http_response = http::Response {
+            body: br#"{"items": [1, null, 3]}"#.to_vec(),
+            ..http::json_http_response()
+        };
+
+        assert_eq!(
+            eval_capture(
+                &captures[0],
+                &variables,
+                &http::default_http_request(),
+                &http_response,
+                &mut cache,
+                &mut EvalContext::default()
+            )
+            .unwrap(),
+            CaptureResult {
+                name: "items".to_string(),
+                value: Value::List(vec![
+                    Value::Number(Number::Integer(1)),
+                    Value::Null,
+                    Value::Number(Number::Integer(3)),
+                ]),
+            }
+        );
+        assert_eq!(
+            eval_capture(
+                &captures[1],
+                &variables,
+                &http::default_http_request(),
+                &http_response,
+                &mut cache,
+                &mut EvalContext::default()
+            )
+            .unwrap(),
+            CaptureResult {
+                name: "items_count".to_string(),
+                value: Value::Number(Number::Integer(3)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_capture_jsonpath_root() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let content = "GET http://localhost:8000/user\n\
+                       HTTP 200\n\
+                       [Captures]\n\
+                       user: jsonpath \"$\"\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = hurl_file.entries[0].response.as_ref().unwrap();
+        let SectionValue::Captures(captures) = &response.sections[0].value else {
+            panic!("expected captures section");
+        };
+        let http_response = http::Response {
+            body: br#"{"id": 1, "address": {"city": "Paris", "zip": ["75001"]}}"#.to_vec(),
+            ..http::json_http_response()
+        };
+
+        let capture = eval_capture(
+            &captures[0],
+            &variables,
+            &http::default_http_request(),
+            &http_response,
+            &mut cache,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        let Value::Object(fields) = &capture.value else {
+            panic!("the whole document should be captured as an object");
+        };
+        let address = fields
+            .iter()
+            .find(|(name, _)| name == "address")
+            .map(|(_, value)| value)
+            .unwrap();
+        let Value::Object(address) = address else {
+            panic!("address should be an object");
+        };
+        assert_eq!(
+            address[0],
+            ("city".to_string(), Value::String("Paris".to_string()))
+        );
+
+        // The captured document is serialized structurally, not as a string.
+        assert_eq!(
+            capture.value.to_json(),
+            serde_json::json!({"id": 1, "address": {"city": "Paris", "zip": ["75001"]}})
+        );
+    }
 }
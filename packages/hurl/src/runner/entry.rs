@@ -18,12 +18,13 @@
 use hurl_core::ast::{Entry, SourceInfo};
 
 use crate::http;
-use crate::http::{ClientOptions, CurlCmd};
+use crate::http::{ClientOptions, CurlCmd, HttpError};
 use crate::runner::cache::BodyCache;
 use crate::runner::error::RunnerError;
+use crate::runner::eval_context::EvalContext;
 use crate::runner::result::{AssertResult, EntryResult};
 use crate::runner::runner_options::RunnerOptions;
-use crate::runner::{request, response, CaptureResult, RunnerErrorKind, VariableSet};
+use crate::runner::{request, response, warning, CaptureResult, RunnerErrorKind, VariableSet};
 use crate::util::logger::{Logger, Verbosity};
 
 /// Runs an `entry` with `http_client` and returns one [`EntryResult`].
@@ -32,10 +33,12 @@ use crate::util::logger::{Logger, Verbosity};
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
 /// `variables` are used to render values at runtime, and can be updated by captures.
+/// `eval_context` is shared by all the entries of a run to evaluate queries, filters and predicates.
 pub fn run(
     entry: &Entry,
     entry_index: usize,
     http_client: &mut http::Client,
+    eval_context: &mut EvalContext,
     variables: &mut VariableSet,
     runner_options: &RunnerOptions,
     logger: &mut Logger,
@@ -88,8 +91,13 @@ pub fn run(
             let start = entry.request.url.source_info.start;
             let end = entry.request.url.source_info.end;
             let error_source_info = SourceInfo::new(start, end);
-            let error =
-                RunnerError::new(error_source_info, RunnerErrorKind::Http(http_error), false);
+            let kind = match http_error {
+                HttpError::TooManyRedirect { count, url } => {
+                    RunnerErrorKind::TooManyRedirects { count, url }
+                }
+                _ => RunnerErrorKind::Http(http_error),
+            };
+            let error = RunnerError::new(error_source_info, kind, false);
             return EntryResult {
                 entry_index,
                 source_info,
@@ -103,16 +111,26 @@ pub fn run(
 
     // Now, we can compute capture and asserts on the last HTTP request/response chains.
     let call = calls.last().unwrap();
+    let http_request = &call.request;
     let http_response = &call.response;
 
     // `transfer_duration` represent the network time of calls, not including assert processing.
     let transfer_duration = calls.iter().map(|call| call.timings.total).sum();
 
+    let warnings = warning::eval_warnings(&calls, source_info);
+    for warning in &warnings {
+        logger.warning(&warning.message());
+    }
+
     // We proceed asserts and captures in this order:
     // 1. first, check implicit assert on status and version. If KO, test is failed
     // 2. then, we compute captures, we might need them in asserts
     // 3. finally, run the remaining asserts
     let mut cache = BodyCache::new();
+    if runner_options.empty_body_as_null && http_response.body.is_empty() {
+        // An empty body is evaluated as JSON null instead of an invalid JSON.
+        cache.set_json(serde_json::Value::Null);
+    }
     let mut asserts = vec![];
 
     if !runner_options.ignore_asserts {
@@ -133,6 +151,7 @@ pub fn run(
                     transfer_duration,
                     compressed,
                     curl_cmd,
+                    warnings,
                 };
             }
         }
@@ -141,7 +160,14 @@ pub fn run(
     let captures = match &entry.response {
         None => vec![],
         Some(response_spec) => {
-            match response::eval_captures(response_spec, http_response, &mut cache, variables) {
+            match response::eval_captures(
+                response_spec,
+                http_request,
+                http_response,
+                &mut cache,
+                variables,
+                eval_context,
+            ) {
                 Ok(captures) => captures,
                 Err(e) => {
                     return EntryResult {
@@ -154,6 +180,7 @@ pub fn run(
                         transfer_duration,
                         compressed,
                         curl_cmd,
+                        warnings,
                     };
                 }
             }
@@ -168,9 +195,13 @@ pub fn run(
             let mut other_asserts = response::eval_asserts(
                 response_spec,
                 variables,
+                http_request,
                 http_response,
                 &mut cache,
                 context_dir,
+                runner_options.json_float_precision,
+                runner_options.ignore_header_case,
+                eval_context,
             );
             asserts.append(&mut other_asserts);
         }
@@ -188,6 +219,7 @@ pub fn run(
         transfer_duration,
         compressed,
         curl_cmd,
+        warnings,
     }
 }
 
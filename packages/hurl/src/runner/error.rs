@@ -17,6 +17,7 @@
  */
 use std::cmp::max;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use hurl_core::ast::SourceInfo;
 use hurl_core::error;
@@ -25,6 +26,7 @@ use hurl_core::text::{Style, StyledString};
 
 use crate::http::HttpError;
 use crate::runner::diff::DiffHunk;
+use crate::runner::Value;
 
 /// Represents a single instance of a runtime error, usually triggered by running a
 /// [`hurl_core::ast::Entry`]. Running a Hurl content (see [`crate::runner::run`]) returns a list of
@@ -45,6 +47,27 @@ impl RunnerError {
             assert,
         }
     }
+
+    /// Returns this error with its actual value displayed on multiple lines, if it's a list or an
+    /// object. The other representations of the error (JSON, JUnit or HTML reports) use the
+    /// compact display of the actual value.
+    pub fn to_pretty(&self) -> RunnerError {
+        let mut error = self.clone();
+        if let RunnerErrorKind::AssertBodyValueError {
+            actual,
+            actual_value: Some(value),
+            ..
+        }
+        | RunnerErrorKind::AssertFailure {
+            actual,
+            actual_value: Some(value),
+            ..
+        } = &mut error.kind
+        {
+            *actual = value.to_display_pretty();
+        }
+        error
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -56,11 +79,21 @@ pub enum RunnerErrorKind {
     AssertBodyValueError {
         actual: String,
         expected: String,
+        /// The actual value if it's a list or an object, displayed on multiple lines in the terminal.
+        actual_value: Option<Box<Value>>,
     },
     AssertFailure {
         actual: String,
         expected: String,
         type_mismatch: bool,
+        /// The actual value if it's a list or an object, displayed on multiple lines in the terminal.
+        actual_value: Option<Box<Value>>,
+    },
+    /// The `actual` number is not equal to the `expected` one within the tolerance `epsilon`.
+    AssertFloatTolerance {
+        actual: String,
+        expected: String,
+        epsilon: String,
     },
     AssertHeaderValueError {
         actual: String,
@@ -68,9 +101,28 @@ pub enum RunnerErrorKind {
     AssertStatus {
         actual: String,
     },
+    /// The response status `actual` is not in the range of the predicate `isInRange min max`.
+    AssertStatusRange {
+        actual: String,
+        min: i64,
+        max: i64,
+    },
     AssertVersion {
         actual: String,
     },
+    EnvVariableNotDefined {
+        name: String,
+    },
+    /// The evaluation of the XPath expression `expr` has exceeded `op_limit` libxml2 operations.
+    EvalOpLimit {
+        expr: String,
+        op_limit: u64,
+    },
+    /// The evaluation of the JSONPath expression `expr` has exceeded `timeout`.
+    EvalTimeout {
+        expr: String,
+        timeout: Duration,
+    },
     ExpressionInvalidType {
         value: String,
         expecting: String,
@@ -88,7 +140,18 @@ pub enum RunnerErrorKind {
     FilterInvalidEncoding(String),
     FilterInvalidInput(String),
     FilterMissingInput,
+    /// The value can't be URL decoded because of a malformed percent-encoded sequence at byte
+    /// `offset`.
+    FilterUrlDecode {
+        offset: usize,
+    },
+    /// The value returned by a filter is not of the `expected` type.
+    FilterTypeMismatch {
+        expected: String,
+        actual: String,
+    },
     Http(HttpError),
+    InvalidGlob,
     InvalidJson {
         value: String,
     },
@@ -99,11 +162,24 @@ pub enum RunnerErrorKind {
     },
     NoQueryResult,
     QueryHeaderNotFound,
-    QueryInvalidJsonpathExpression {
+    QueryInvalidJsonPath {
         value: String,
     },
-    QueryInvalidXpathEval,
+    /// The JSONPath `function` can't be applied to a value of type `value_type`.
+    QueryInvalidJsonPathFunction {
+        function: String,
+        value_type: String,
+    },
+    /// The XPath expression can't be evaluated, `unbound_prefix` is the first namespace prefix
+    /// of the expression that is not bound, if any.
+    QueryInvalidXpathEval {
+        unbound_prefix: Option<String>,
+    },
     QueryInvalidXml,
+    /// The XPath expression `expr` returns a result type that is not supported.
+    QueryUnsupportedXpathResult {
+        expr: String,
+    },
     QueryInvalidJson,
     ReadOnlySecret {
         name: String,
@@ -111,6 +187,11 @@ pub enum RunnerErrorKind {
     TemplateVariableNotDefined {
         name: String,
     },
+    /// The maximum number of redirects `count` has been exceeded, `url` is the last URL reached.
+    TooManyRedirects {
+        count: usize,
+        url: String,
+    },
     UnrenderableExpression {
         value: String,
     },
@@ -118,6 +199,8 @@ pub enum RunnerErrorKind {
     UnauthorizedFileAccess {
         path: PathBuf,
     },
+    /// Regex that can't be evaluated by the regex engine (backreferences, too large regex etc...)
+    UnsupportedRegex(String),
 }
 
 /// Textual Output for runner errors
@@ -131,9 +214,16 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::AssertBodyDiffError { .. } => "Assert body value".to_string(),
             RunnerErrorKind::AssertBodyValueError { .. } => "Assert body value".to_string(),
             RunnerErrorKind::AssertFailure { .. } => "Assert failure".to_string(),
+            RunnerErrorKind::AssertFloatTolerance { .. } => "Assert failure".to_string(),
             RunnerErrorKind::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerErrorKind::AssertStatus { .. } => "Assert status code".to_string(),
+            RunnerErrorKind::AssertStatusRange { .. } => "Assert status code".to_string(),
             RunnerErrorKind::AssertVersion { .. } => "Assert HTTP version".to_string(),
+            RunnerErrorKind::EnvVariableNotDefined { .. } => {
+                "Undefined environment variable".to_string()
+            }
+            RunnerErrorKind::EvalOpLimit { .. } => "Evaluation limit".to_string(),
+            RunnerErrorKind::EvalTimeout { .. } => "Evaluation timeout".to_string(),
             RunnerErrorKind::ExpressionInvalidType { .. } => "Invalid expression type".to_string(),
             RunnerErrorKind::FileReadAccess { .. } => "File read access".to_string(),
             RunnerErrorKind::FileWriteAccess { .. } => "File write access".to_string(),
@@ -141,24 +231,31 @@ impl DisplaySourceError for RunnerError {
             RunnerErrorKind::FilterInvalidEncoding { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterInvalidInput { .. } => "Filter error".to_string(),
             RunnerErrorKind::FilterMissingInput => "Filter error".to_string(),
+            RunnerErrorKind::FilterUrlDecode { .. } => "Filter error".to_string(),
+            RunnerErrorKind::FilterTypeMismatch { .. } => "Filter error".to_string(),
             RunnerErrorKind::Http(http_error) => http_error.description(),
+            RunnerErrorKind::InvalidGlob => "Invalid glob".to_string(),
             RunnerErrorKind::InvalidJson { .. } => "Invalid JSON".to_string(),
             RunnerErrorKind::InvalidUrl { .. } => "Invalid URL".to_string(),
             RunnerErrorKind::InvalidRegex => "Invalid regex".to_string(),
             RunnerErrorKind::NoQueryResult => "No query result".to_string(),
             RunnerErrorKind::QueryHeaderNotFound => "Header not found".to_string(),
             RunnerErrorKind::QueryInvalidJson => "Invalid JSON".to_string(),
-            RunnerErrorKind::QueryInvalidJsonpathExpression { .. } => {
-                "Invalid JSONPath".to_string()
+            RunnerErrorKind::QueryInvalidJsonPath { .. } => "Invalid JSONPath".to_string(),
+            RunnerErrorKind::QueryInvalidJsonPathFunction { .. } => {
+                "Invalid JSONPath function".to_string()
             }
             RunnerErrorKind::QueryInvalidXml => "Invalid XML".to_string(),
-            RunnerErrorKind::QueryInvalidXpathEval => "Invalid XPath expression".to_string(),
+            RunnerErrorKind::QueryInvalidXpathEval { .. } => "Invalid XPath expression".to_string(),
+            RunnerErrorKind::QueryUnsupportedXpathResult { .. } => "Unsupported XPath".to_string(),
             RunnerErrorKind::ReadOnlySecret { .. } => "Readonly secret".to_string(),
             RunnerErrorKind::TemplateVariableNotDefined { .. } => "Undefined variable".to_string(),
+            RunnerErrorKind::TooManyRedirects { .. } => "Too many redirects".to_string(),
             RunnerErrorKind::UnauthorizedFileAccess { .. } => {
                 "Unauthorized file access".to_string()
             }
             RunnerErrorKind::UnrenderableExpression { .. } => "Unrenderable expression".to_string(),
+            RunnerErrorKind::UnsupportedRegex(_) => "Unsupported regex".to_string(),
         }
     }
 
@@ -189,9 +286,22 @@ impl DisplaySourceError for RunnerError {
                 } else {
                     ""
                 };
+                // Multi-line values (structured values for instance) are aligned on the first line.
+                let actual = actual.replace('\n', "\n             ");
+                let expected = expected.replace('\n', "\n             ");
                 let message = format!("   actual:   {actual}\n   expected: {expected}{additional}");
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::AssertFloatTolerance {
+                actual,
+                expected,
+                epsilon,
+            } => {
+                let message = format!(
+                    "   actual:   {actual}\n   expected: {expected} (within tolerance {epsilon})"
+                );
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::AssertHeaderValueError { actual } => {
                 let message = &format!("actual value is <{actual}>");
                 let message = error::add_carets(message, self.source_info, content);
@@ -202,11 +312,37 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::AssertStatusRange { actual, min, max } => {
+                let message =
+                    &format!("actual value is <{actual}>, expected in range [{min}, {max}]");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::AssertVersion { actual, .. } => {
                 let message = &format!("actual value is <{actual}>");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::EnvVariableNotDefined { name } => {
+                let message = &format!("you must set the environment variable {name}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::EvalOpLimit { expr, op_limit } => {
+                let message = &format!(
+                    "evaluation of <{expr}> has exceeded the limit of {op_limit} operations"
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::EvalTimeout { expr, timeout } => {
+                let message = &format!(
+                    "evaluation of <{expr}> has exceeded the timeout of {} ms",
+                    timeout.as_millis()
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::ExpressionInvalidType {
                 value, expecting, ..
             } => {
@@ -244,11 +380,27 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::FilterUrlDecode { offset } => {
+                let message = &format!("invalid percent-encoded sequence at byte {offset}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::FilterTypeMismatch { expected, actual } => {
+                let message =
+                    &format!("expecting a value of type <{expected}>, actual type is <{actual}>");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::Http(http_error) => {
                 let message = http_error.message();
                 let message = error::add_carets(&message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::InvalidGlob => {
+                let message = "glob pattern is not valid";
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::InvalidJson { value } => {
                 let message = &format!("actual value is <{value}>");
                 let message = error::add_carets(message, self.source_info, content);
@@ -279,18 +431,39 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
-            RunnerErrorKind::QueryInvalidJsonpathExpression { value } => {
+            RunnerErrorKind::QueryInvalidJsonPath { value } => {
                 let message = &format!("the JSONPath expression '{value}' is not valid");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::QueryInvalidJsonPathFunction {
+                function,
+                value_type,
+            } => {
+                let message = &format!(
+                    "the JSONPath function {function} can not be applied to a {value_type}"
+                );
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::QueryInvalidXml => {
                 let message = "the HTTP response is not a valid XML";
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
-            RunnerErrorKind::QueryInvalidXpathEval => {
-                let message = "the XPath expression is not valid";
+            RunnerErrorKind::QueryInvalidXpathEval { unbound_prefix } => {
+                let message = match unbound_prefix {
+                    Some(prefix) => format!(
+                        "the XPath expression is not valid, prefix <{prefix}> is not bound to a namespace"
+                    ),
+                    None => "the XPath expression is not valid".to_string(),
+                };
+                let message = error::add_carets(&message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
+            RunnerErrorKind::QueryUnsupportedXpathResult { expr } => {
+                let message =
+                    &format!("the result of the XPath expression <{expr}> is not supported");
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
@@ -304,6 +477,12 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::TooManyRedirects { count, url } => {
+                let message =
+                    &format!("maximum of {count} redirects exceeded, last URL reached <{url}>");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
             RunnerErrorKind::UnauthorizedFileAccess { path } => {
                 let message = &format!(
                     "unauthorized access to file {}, check --file-root option",
@@ -317,6 +496,11 @@ impl DisplaySourceError for RunnerError {
                 let message = error::add_carets(message, self.source_info, content);
                 color_red_multiline_string(&message)
             }
+            RunnerErrorKind::UnsupportedRegex(reason) => {
+                let message = &format!("regex expression is not supported: {reason}");
+                let message = error::add_carets(message, self.source_info, content);
+                color_red_multiline_string(&message)
+            }
         }
     }
 
@@ -402,6 +586,8 @@ fn hunk_string(
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use hurl_core::ast::SourceInfo;
     use hurl_core::error::{DisplaySourceError, OutputFormat};
     use hurl_core::reader::Pos;
@@ -409,7 +595,7 @@ mod tests {
 
     use crate::http::HttpError;
     use crate::runner::diff::diff;
-    use crate::runner::{RunnerError, RunnerErrorKind};
+    use crate::runner::{RunnerError, RunnerErrorKind, Value};
 
     #[test]
     fn test_error_timeout() {
@@ -446,6 +632,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_too_many_redirects() {
+        let content = "GET http://localhost:8000/redirect-loop";
+        let filename = "test.hurl";
+        let kind = RunnerErrorKind::TooManyRedirects {
+            count: 2,
+            url: "http://localhost:8000/redirect-loop".to_string(),
+        };
+        let error_source_info = SourceInfo::new(Pos::new(1, 5), Pos::new(1, 40));
+        let entry_source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 40));
+        let error = RunnerError::new(error_source_info, kind, false);
+
+        assert_eq!(
+            error.to_string(
+                filename,
+                content,
+                Some(entry_source_info),
+                OutputFormat::Terminal(false)
+            ),
+            r#"Too many redirects
+  --> test.hurl:1:5
+   |
+ 1 | GET http://localhost:8000/redirect-loop
+   |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ maximum of 2 redirects exceeded, last URL reached <http://localhost:8000/redirect-loop>
+   |"#
+        );
+    }
+
+    #[test]
+    fn test_assert_error_status_range() {
+        let content = r#"GET http://unknown
+HTTP *
+[Asserts]
+status isInRange 200 299
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let kind = RunnerErrorKind::AssertStatusRange {
+            actual: "404".to_string(),
+            min: 200,
+            max: 299,
+        };
+        let error_source_info = SourceInfo::new(Pos::new(4, 8), Pos::new(4, 21));
+        let error = RunnerError::new(error_source_info, kind, true);
+
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            "\n 4 | status isInRange 200 299\n   |        ^^^^^^^^^^^^^ actual value is <404>, expected in range [200, 299]\n   |"
+        );
+    }
+
     #[test]
     fn test_assert_error_status() {
         hurl_core::text::init_crate_colored();
@@ -501,7 +737,9 @@ xpath "strong(//head/title)" == "Hello"
         let entry_source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 22));
         let error = RunnerError::new(
             error_source_info,
-            RunnerErrorKind::QueryInvalidXpathEval,
+            RunnerErrorKind::QueryInvalidXpathEval {
+                unbound_prefix: None,
+            },
             true,
         );
         assert_eq!(
@@ -526,6 +764,78 @@ xpath "strong(//head/title)" == "Hello"
         );
     }
 
+    #[test]
+    fn test_eval_op_limit() {
+        let content = r#"GET http://example.com
+HTTP 200
+[Asserts]
+xpath "count(//*[count(//*) > 0])" == 1
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let error = RunnerError::new(
+            SourceInfo::new(Pos::new(4, 7), Pos::new(4, 35)),
+            RunnerErrorKind::EvalOpLimit {
+                expr: "count(//*[count(//*) > 0])".to_string(),
+                op_limit: 100000000,
+            },
+            false,
+        );
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            "\n 4 | xpath \"count(//*[count(//*) > 0])\" == 1\n   |       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^ evaluation of <count(//*[count(//*) > 0])> has exceeded the limit of 100000000 operations\n   |"
+        );
+    }
+
+    #[test]
+    fn test_eval_timeout() {
+        let content = r#"GET http://example.com
+HTTP 200
+[Asserts]
+jsonpath "$..*..*" count == 1
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let error = RunnerError::new(
+            SourceInfo::new(Pos::new(4, 10), Pos::new(4, 19)),
+            RunnerErrorKind::EvalTimeout {
+                expr: "$..*..*".to_string(),
+                timeout: Duration::from_secs(60),
+            },
+            false,
+        );
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            "\n 4 | jsonpath \"$..*..*\" count == 1\n   |          ^^^^^^^^^ evaluation of <$..*..*> has exceeded the timeout of 60000 ms\n   |"
+        );
+    }
+
+    #[test]
+    fn test_assert_error_float_tolerance() {
+        let content = r#"GET http://api
+HTTP/1.0 200
+[Asserts]
+jsonpath "$.total" isCloseTo 3.14 0.001
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let error = RunnerError::new(
+            SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0)),
+            RunnerErrorKind::AssertFloatTolerance {
+                actual: "float <3.2>".to_string(),
+                expected: "float <3.14>".to_string(),
+                epsilon: "0.001".to_string(),
+            },
+            true,
+        );
+
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            r#"
+ 4 | jsonpath "$.total" isCloseTo 3.14 0.001
+   |   actual:   float <3.2>
+   |   expected: float <3.14> (within tolerance 0.001)
+   |"#
+        );
+    }
+
     #[test]
     fn test_assert_error_jsonpath() {
         let content = r#"GET http://api
@@ -543,6 +853,7 @@ jsonpath "$.count" >= 5
                 actual: "int <2>".to_string(),
                 expected: "greater than int <5>".to_string(),
                 type_mismatch: false,
+                actual_value: None,
             },
             assert: true,
         };
@@ -575,6 +886,59 @@ jsonpath "$.count" >= 5
         );
     }
 
+    #[test]
+    fn test_assert_error_pretty() {
+        let content = r#"GET http://api
+HTTP/1.0 200
+[Asserts]
+jsonpath "$.user" == "Bob"
+"#;
+        let lines = content.lines().collect::<Vec<_>>();
+        let error_source_info = SourceInfo::new(Pos::new(4, 0), Pos::new(4, 0));
+        let actual_value = Value::Object(vec![
+            ("name".to_string(), Value::String("Bob".to_string())),
+            (
+                "tags".to_string(),
+                Value::List(vec![Value::String("admin".to_string())]),
+            ),
+        ]);
+        let error = RunnerError {
+            source_info: error_source_info,
+            kind: RunnerErrorKind::AssertFailure {
+                actual: "object".to_string(),
+                expected: "string <Bob>".to_string(),
+                type_mismatch: true,
+                actual_value: Some(Box::new(actual_value)),
+            },
+            assert: true,
+        };
+
+        // The actual value is compact, except in its pretty representation.
+        assert_eq!(
+            error.message(&lines).to_string(Format::Plain),
+            r#"
+ 4 | jsonpath "$.user" == "Bob"
+   |   actual:   object
+   |   expected: string <Bob>
+   |   >>> types between actual and expected are not consistent
+   |"#
+        );
+        assert_eq!(
+            error.to_pretty().message(&lines).to_string(Format::Plain),
+            r#"
+ 4 | jsonpath "$.user" == "Bob"
+   |   actual:   {
+   |               "name": "Bob",
+   |               "tags": [
+   |                 "admin"
+   |               ]
+   |             }
+   |   expected: string <Bob>
+   |   >>> types between actual and expected are not consistent
+   |"#
+        );
+    }
+
     #[test]
     fn test_assert_error_newline() {
         let content = r#"GET http://localhost
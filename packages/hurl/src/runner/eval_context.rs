@@ -0,0 +1,156 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::collections::HashMap;
+use std::time::Duration;
+
+use regex::Regex;
+
+use crate::runner::regex::{new_regex, DEFAULT_REGEX_SIZE_LIMIT};
+use crate::runner::{RunnerErrorKind, RunnerOptions};
+
+/// Default maximum duration of the evaluation of a JSONPath expression.
+pub const DEFAULT_EVAL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Default maximum number of libxml2 operations of the evaluation of a XPath expression.
+pub const DEFAULT_XPATH_OP_LIMIT: u64 = 100_000_000;
+
+/// Holds the state used to evaluate queries, filters and predicates at runtime.
+///
+/// An evaluation context is created from the [`RunnerOptions`] at the start of a run, and lives
+/// for the whole run: it is passed to each entry. Regexes compiled at runtime are cached, so the
+/// same pattern evaluated by different asserts or entries (in a loop with `--repeat` or with
+/// retries for instance) is compiled only once.
+#[derive(Clone, Debug)]
+pub struct EvalContext {
+    /// Maximum duration of the evaluation of a JSONPath expression.
+    eval_timeout: Duration,
+    /// Maximum number of libxml2 operations of the evaluation of a XPath expression.
+    xpath_op_limit: u64,
+    /// Maximum size in bytes of a regex compiled at runtime.
+    regex_size_limit: usize,
+    /// Compiled regexes, keyed by their pattern once templates are expanded.
+    regex_cache: HashMap<String, Regex>,
+}
+
+/// Maximum number of compiled regexes kept in the cache of an [`EvalContext`].
+const REGEX_CACHE_CAPACITY: usize = 256;
+
+impl Default for EvalContext {
+    fn default() -> Self {
+        EvalContext {
+            eval_timeout: DEFAULT_EVAL_TIMEOUT,
+            xpath_op_limit: DEFAULT_XPATH_OP_LIMIT,
+            regex_size_limit: DEFAULT_REGEX_SIZE_LIMIT,
+            regex_cache: HashMap::new(),
+        }
+    }
+}
+
+impl EvalContext {
+    /// Creates a new evaluation context, using limits of `runner_options`.
+    pub fn new(runner_options: &RunnerOptions) -> Self {
+        EvalContext {
+            eval_timeout: runner_options.eval_timeout,
+            xpath_op_limit: runner_options.xpath_op_limit,
+            regex_size_limit: runner_options.regex_size_limit,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the maximum duration of the evaluation of a JSONPath expression.
+    ///
+    /// A pathological expression or document is aborted after this timeout instead of hanging
+    /// the run.
+    pub fn eval_timeout(&self) -> Duration {
+        self.eval_timeout
+    }
+
+    /// Returns the maximum number of libxml2 operations of the evaluation of a XPath expression.
+    ///
+    /// libxml2 can't abort an evaluation after a timeout: a pathological expression or document
+    /// is aborted after this number of operations instead of hanging the run.
+    pub fn xpath_op_limit(&self) -> u64 {
+        self.xpath_op_limit
+    }
+
+    /// Returns the compiled regex of a `pattern` evaluated at runtime (see [`new_regex`]),
+    /// compiling it only if it's not already cached.
+    ///
+    /// Invalid patterns are not cached. When the cache is full, it is cleared so its memory stays
+    /// bounded with templated patterns that expand to a lot of different values.
+    pub fn new_regex(&mut self, pattern: &str) -> Result<Regex, RunnerErrorKind> {
+        if let Some(regex) = self.regex_cache.get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = new_regex(pattern, self.regex_size_limit)?;
+        if self.regex_cache.len() >= REGEX_CACHE_CAPACITY {
+            self.regex_cache.clear();
+        }
+        self.regex_cache.insert(pattern.to_string(), regex.clone());
+        Ok(regex)
+    }
+
+    /// Returns the number of regexes cached by this context.
+    #[cfg(test)]
+    pub fn regex_cache_len(&self) -> usize {
+        self.regex_cache.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::RunnerOptionsBuilder;
+
+    #[test]
+    fn test_regex_size_limit() {
+        let pattern = r"\w{10}";
+        let mut eval_context = EvalContext::default();
+        assert!(eval_context.new_regex(pattern).is_ok());
+
+        let runner_options = RunnerOptionsBuilder::new().regex_size_limit(1024).build();
+        let mut eval_context = EvalContext::new(&runner_options);
+        assert_eq!(
+            eval_context.new_regex(pattern).unwrap_err(),
+            RunnerErrorKind::UnsupportedRegex(
+                "compiled regex exceeds the size limit of 1024 bytes".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_regex_cache() {
+        let mut eval_context = EvalContext::default();
+        for _ in 0..100 {
+            assert!(eval_context.new_regex(r"id-\d+").is_ok());
+        }
+        assert_eq!(eval_context.regex_cache_len(), 1);
+
+        assert!(eval_context.new_regex(r"user-\d+").is_ok());
+        assert_eq!(eval_context.regex_cache_len(), 2);
+
+        // Invalid patterns are not cached.
+        for _ in 0..2 {
+            assert_eq!(
+                eval_context.new_regex("[").unwrap_err(),
+                RunnerErrorKind::InvalidRegex
+            );
+        }
+        assert_eq!(eval_context.regex_cache_len(), 2);
+    }
+}
@@ -36,7 +36,7 @@ pub fn eval(expr: &Expr, variables: &VariableSet) -> Result<Value, RunnerError>
                 Err(RunnerError::new(variable.source_info, kind, false))
             }
         }
-        ExprKind::Function(fct) => function::eval(fct),
+        ExprKind::Function(fct) => function::eval(fct, expr.source_info),
     }
 }
 
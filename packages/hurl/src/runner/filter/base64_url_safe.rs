@@ -0,0 +1,180 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig, URL_SAFE_NO_PAD};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// URL-safe Base64 engine accepting encoded strings with or without trailing padding.
+const URL_SAFE_INDIFFERENT_PAD: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Decodes a URL-safe Base64 string `value` into bytes. Padding is optional.
+pub fn eval_base64_url_safe_decode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => match URL_SAFE_INDIFFERENT_PAD.decode(value) {
+            Ok(bytes) => Ok(Some(Value::Bytes(bytes))),
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterInvalidInput(
+                    "invalid base64 URL-safe string".to_string(),
+                );
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Encodes bytes or a string `value` into a URL-safe Base64 string, without padding.
+pub fn eval_base64_url_safe_encode(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(bytes) => Ok(Some(Value::String(URL_SAFE_NO_PAD.encode(bytes)))),
+        Value::String(s) => Ok(Some(Value::String(URL_SAFE_NO_PAD.encode(s)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_base64_url_safe_decode() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::Base64UrlSafeDecode,
+        };
+        // Without padding
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("PDw_Pz8-Pg".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(b"<<???>>".to_vec())
+        );
+        // With padding
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("PDw_Pz8-Pg==".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(b"<<???>>".to_vec())
+        );
+
+        // Standard alphabet is not URL-safe
+        let error = eval_filter(
+            &filter,
+            &Value::String("PDw/Pz8+Pg==".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("invalid base64 URL-safe string".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_base64_url_safe_encode() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::Base64UrlSafeEncode,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("<<???>>".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("PDw_Pz8-Pg".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Bytes(vec![0xfb, 0xff]),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("-_8".to_string())
+        );
+    }
+}
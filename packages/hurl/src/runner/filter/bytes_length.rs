@@ -0,0 +1,109 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Returns the length in bytes of `value`.
+///
+/// For strings, the length is the number of bytes of the UTF-8 encoded string, which can be
+/// greater than its number of characters.
+pub fn eval_bytes_length(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::Bytes(bytes) => Ok(Some(Value::Number(Number::Integer(bytes.len() as i64)))),
+        Value::String(s) => Ok(Some(Value::Number(Number::Integer(s.len() as i64)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.display());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_bytes_length() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::BytesLength,
+        };
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Bytes(vec![1, 2, 3]),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(3))
+        );
+
+        // "café" has 4 characters but 5 bytes in UTF-8.
+        let value = Value::String("café".to_string());
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(5))
+        );
+        assert_eq!("café".chars().count(), 4);
+    }
+
+    #[test]
+    pub fn eval_filter_bytes_length_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::BytesLength,
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("bool <true>".to_string())
+        );
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts a string `value` to camel case (`userName`).
+pub fn eval_camel_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_case(value, camel_case, source_info, assert)
+}
+
+/// Converts a string `value` to kebab case (`user-name`).
+pub fn eval_kebab_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_case(value, |s| split_words(s).join("-"), source_info, assert)
+}
+
+/// Converts a string `value` to snake case (`user_name`).
+pub fn eval_snake_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_case(value, |s| split_words(s).join("_"), source_info, assert)
+}
+
+fn eval_case(
+    value: &Value,
+    convert: impl Fn(&str) -> String,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => Ok(Some(Value::String(convert(value)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the camel case version of `s`: the first word is lowercased, the following words are
+/// capitalized, with no separator.
+fn camel_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for (index, word) in split_words(s).iter().enumerate() {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) if index > 0 => {
+                result.extend(first.to_uppercase());
+                result.push_str(chars.as_str());
+            }
+            _ => result.push_str(word),
+        }
+    }
+    result
+}
+
+/// Splits `s` into lowercased words.
+///
+/// Words are separated by any non alphanumeric character (`_`, `-`, spaces etc...) and by case
+/// boundaries: a lowercase letter or a digit followed by an uppercase letter (`userName`). A run
+/// of uppercase letters is an acronym and is kept as one word; its last letter starts a new word
+/// if it's followed by a lowercase letter (`HTTPServer` is split into `http` and `server`).
+/// Digits stay attached to the preceding word (`utf8Value` is split into `utf8` and `value`).
+fn split_words(s: &str) -> Vec<String> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut words = vec![];
+    let mut word = String::new();
+    for (index, c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if !word.is_empty() && c.is_uppercase() {
+            let prev = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|c| c.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_numeric()
+                || (prev.is_uppercase() && next_is_lowercase)
+            {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::{camel_case, split_words};
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_case() {
+        let variables = VariableSet::new();
+        let tests = [
+            (FilterValue::SnakeCase, "userName", "user_name"),
+            (FilterValue::KebabCase, "userName", "user-name"),
+            (FilterValue::CamelCase, "user_name", "userName"),
+            (FilterValue::SnakeCase, "user-name", "user_name"),
+        ];
+        for (value, input, output) in tests {
+            let filter = Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+                value,
+            };
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::SnakeCase,
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn test_split_words() {
+        assert!(split_words("").is_empty());
+        assert_eq!(split_words("userName"), vec!["user", "name"]);
+        assert_eq!(split_words("UserName"), vec!["user", "name"]);
+        assert_eq!(split_words("user_name"), vec!["user", "name"]);
+        assert_eq!(split_words("user-name"), vec!["user", "name"]);
+        assert_eq!(split_words("  user  name "), vec!["user", "name"]);
+        assert_eq!(split_words("USER_NAME"), vec!["user", "name"]);
+        assert_eq!(split_words("HTTPServer"), vec!["http", "server"]);
+        assert_eq!(split_words("userID"), vec!["user", "id"]);
+        assert_eq!(
+            split_words("parseHTTPResponse"),
+            vec!["parse", "http", "response"]
+        );
+        assert_eq!(split_words("utf8Value"), vec!["utf8", "value"]);
+        assert_eq!(split_words("élodieÉté"), vec!["élodie", "été"]);
+    }
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case(""), "");
+        assert_eq!(camel_case("user_name"), "userName");
+        assert_eq!(camel_case("UserName"), "userName");
+        assert_eq!(camel_case("user_id"), "userId");
+        assert_eq!(camel_case("HTTP_SERVER"), "httpServer");
+        assert_eq!(camel_case("parseHTTPResponse"), "parseHttpResponse");
+    }
+}
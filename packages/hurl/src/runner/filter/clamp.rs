@@ -0,0 +1,211 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::cmp::Ordering;
+
+use hurl_core::ast::{Float, Number as AstNumber, SourceInfo};
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Bounds a number `value` to the range [`min`, `max`].
+///
+/// A number within the range is returned unchanged (keeping its integer or float type), otherwise
+/// the nearest bound is returned. `min` must be lower or equal to `max`.
+pub fn eval_clamp(
+    value: &Value,
+    min: &AstNumber,
+    max: &AstNumber,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::Number(number) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.display());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let min = eval_number(min);
+    let max = eval_number(max);
+    if min.cmp_value(&max) == Ordering::Greater {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!(
+            "clamp min {min} is greater than max {max}"
+        ));
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    let clamped = if number.cmp_value(&min) == Ordering::Less {
+        min
+    } else if number.cmp_value(&max) == Ordering::Greater {
+        max
+    } else {
+        number.clone()
+    };
+    Ok(Some(Value::Number(clamped)))
+}
+
+fn eval_number(number: &AstNumber) -> Number {
+    match number {
+        AstNumber::Float(Float { value, .. }) => Number::Float(*value),
+        AstNumber::Integer(value) => Number::Integer(*value),
+        AstNumber::BigInteger(value) => Number::BigInteger(value.clone()),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, Float, Number as AstNumber, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_clamp() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::Clamp {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                min: AstNumber::Integer(0),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                max: AstNumber::Integer(100),
+            },
+        };
+
+        let tests = [
+            (Number::Integer(150), Number::Integer(100)),
+            (Number::Integer(-5), Number::Integer(0)),
+            (Number::Integer(42), Number::Integer(42)),
+            (Number::Float(12.5), Number::Float(12.5)),
+            (Number::Float(100.5), Number::Integer(100)),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::Number(input.clone()),
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::Number(output.clone())
+            );
+        }
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::Clamp {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                min: AstNumber::Float(Float {
+                    value: 0.0,
+                    encoded: "0.0".to_string(),
+                }),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                max: AstNumber::Float(Float {
+                    value: 1.0,
+                    encoded: "1.0".to_string(),
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(2)),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(1.0))
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_clamp_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::Clamp {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                min: AstNumber::Integer(10),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                max: AstNumber::Integer(1),
+            },
+        };
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(5)),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("clamp min 10 is greater than max 1".to_string())
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+            value: FilterValue::Clamp {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                min: AstNumber::Integer(0),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                max: AstNumber::Integer(100),
+            },
+        };
+        let err = eval_filter(
+            &filter,
+            &Value::String("150".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("string <150>".to_string())
+        );
+    }
+}
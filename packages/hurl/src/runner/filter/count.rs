@@ -42,6 +42,7 @@ pub mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -62,6 +63,7 @@ pub mod tests {
                     Value::Number(Number::Integer(2)),
                 ]),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
@@ -69,9 +71,15 @@ pub mod tests {
             Value::Number(Number::Integer(3))
         );
 
-        let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info,
             SourceInfo::new(Pos::new(1, 1), Pos::new(1, 6))
@@ -0,0 +1,141 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{RegexValue, SourceInfo};
+
+use crate::runner::eval_context::EvalContext;
+use crate::runner::regex::eval_regex_value;
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Counts the number of non-overlapping matches of `pattern` in a string `value`.
+pub fn eval_count_matches(
+    value: &Value,
+    pattern: &RegexValue,
+    variables: &VariableSet,
+    eval_context: &mut EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let re = eval_regex_value(pattern, variables, eval_context)?;
+    match value {
+        Value::String(s) => {
+            let count = re.find_iter(s).count();
+            Ok(Some(Value::Number(Number::Integer(count as i64))))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{
+        Filter, FilterValue, RegexValue, SourceInfo, Template, TemplateElement, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_count_matches() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::CountMatches {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                pattern: RegexValue::Template(Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "err".to_string(),
+                        encoded: "err".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 20)),
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("err: disk full\nok\nerr: timeout\nerr: refused".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(3))
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("all good".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(0))
+        );
+    }
+
+    #[test]
+    fn eval_filter_count_matches_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::CountMatches {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                pattern: RegexValue::Template(Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "err".to_string(),
+                        encoded: "err".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 20)),
+                }),
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
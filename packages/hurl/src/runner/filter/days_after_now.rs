@@ -46,6 +46,7 @@ pub mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::VariableSet;
 
@@ -62,6 +63,7 @@ pub mod tests {
                 },
                 &Value::Date(now),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
@@ -78,6 +80,7 @@ pub mod tests {
                 },
                 &Value::Date(now_plus_30hours),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
@@ -92,6 +95,7 @@ pub mod tests {
                 },
                 &Value::Date(now_plus_30hours),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
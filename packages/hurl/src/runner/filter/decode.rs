@@ -58,6 +58,7 @@ pub mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
 
     #[test]
@@ -65,13 +66,14 @@ pub mod tests {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-            value: FilterValue::UrlDecode,
+            value: FilterValue::UrlDecode { mode: None },
         };
         assert_eq!(
             eval_filter(
                 &filter,
                 &Value::String("https://mozilla.org/?x=%D1%88%D0%B5%D0%BB%D0%BB%D1%8B".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
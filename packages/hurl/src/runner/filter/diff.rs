@@ -0,0 +1,261 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{JsonValue, SourceInfo};
+use serde_json::json;
+
+use crate::runner::json::eval_json_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Compares an object or a list `value` to the JSON value `other`, and returns an object
+/// describing the differences.
+///
+/// The returned object has three lists: `added` and `removed` with the `path` and `value` of the
+/// nodes only present in `other`, respectively in `value`, and `changed` with the `path` and the
+/// `from`/`to` values of the nodes that differ. Paths are JSON pointers (see
+/// [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901)).
+pub fn eval_diff(
+    value: &Value,
+    other: &JsonValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    if !matches!(value, Value::Object(_) | Value::List(_)) {
+        let kind = RunnerErrorKind::FilterInvalidInput(value._type());
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    let other = eval_json_value(other, variables, false)?;
+    let other = match serde_json::from_str::<serde_json::Value>(&other) {
+        Ok(other) => other,
+        Err(_) => {
+            let kind = RunnerErrorKind::InvalidJson { value: other };
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let mut diff = Diff::default();
+    diff.compare("", &value.to_json(), &other);
+    let diff = json!({
+        "added": diff.added,
+        "removed": diff.removed,
+        "changed": diff.changed,
+    });
+    Ok(Some(Value::from_json(&diff)))
+}
+
+/// Differences between two JSON values.
+#[derive(Default)]
+struct Diff {
+    added: Vec<serde_json::Value>,
+    removed: Vec<serde_json::Value>,
+    changed: Vec<serde_json::Value>,
+}
+
+impl Diff {
+    /// Compares the node `left` to the node `right`, both located at `pointer`.
+    fn compare(&mut self, pointer: &str, left: &serde_json::Value, right: &serde_json::Value) {
+        match (left, right) {
+            (serde_json::Value::Object(left), serde_json::Value::Object(right)) => {
+                for (name, left_value) in left {
+                    let pointer = child_pointer(pointer, name);
+                    match right.get(name) {
+                        Some(right_value) => self.compare(&pointer, left_value, right_value),
+                        None => self
+                            .removed
+                            .push(json!({"path": pointer, "value": left_value})),
+                    }
+                }
+                for (name, right_value) in right {
+                    if !left.contains_key(name) {
+                        let pointer = child_pointer(pointer, name);
+                        self.added
+                            .push(json!({"path": pointer, "value": right_value}));
+                    }
+                }
+            }
+            (serde_json::Value::Array(left), serde_json::Value::Array(right)) => {
+                for (i, left_value) in left.iter().enumerate() {
+                    let pointer = child_pointer(pointer, &i.to_string());
+                    match right.get(i) {
+                        Some(right_value) => self.compare(&pointer, left_value, right_value),
+                        None => self
+                            .removed
+                            .push(json!({"path": pointer, "value": left_value})),
+                    }
+                }
+                for (i, right_value) in right.iter().enumerate().skip(left.len()) {
+                    let pointer = child_pointer(pointer, &i.to_string());
+                    self.added
+                        .push(json!({"path": pointer, "value": right_value}));
+                }
+            }
+            _ => {
+                if left != right {
+                    self.changed
+                        .push(json!({"path": pointer, "from": left, "to": right}));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the JSON pointer of the child `token` of the node located at `pointer`.
+fn child_pointer(pointer: &str, token: &str) -> String {
+    let token = token.replace('~', "~0").replace('/', "~1");
+    format!("{pointer}/{token}")
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::{Pos, Reader};
+    use serde_json::json;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_diff() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Diff {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                other: hurl_core::parser::parse_json(&mut Reader::new(r#"{"a": 2, "b": 3}"#))
+                    .unwrap(),
+            },
+        };
+        let value = Value::Object(vec![("a".to_string(), Value::Number(Number::Integer(1)))]);
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap()
+            .to_json(),
+            json!({
+                "added": [{"path": "/b", "value": 3}],
+                "removed": [],
+                "changed": [{"path": "/a", "from": 1, "to": 2}],
+            })
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_diff_nested() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Diff {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                other: hurl_core::parser::parse_json(&mut Reader::new(
+                    r#"{"users": [{"name": "Bob"}], "a/b": true}"#,
+                ))
+                .unwrap(),
+            },
+        };
+        let value = Value::from_json(&json!({
+            "users": [{"name": "Bill"}, {"name": "Alice"}],
+            "a/b": true,
+        }));
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap()
+            .to_json(),
+            json!({
+                "added": [],
+                "removed": [{"path": "/users/1", "value": {"name": "Alice"}}],
+                "changed": [{"path": "/users/0/name", "from": "Bill", "to": "Bob"}],
+            })
+        );
+
+        // No differences
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Diff {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                other: hurl_core::parser::parse_json(&mut Reader::new(r#"[1, [2, 3]]"#)).unwrap(),
+            },
+        };
+        let value = Value::from_json(&json!([1, [2, 3]]));
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap()
+            .to_json(),
+            json!({"added": [], "removed": [], "changed": []})
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_diff_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Diff {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                other: hurl_core::parser::parse_json(&mut Reader::new(r#"{"b": 2}"#)).unwrap(),
+            },
+        };
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
@@ -18,23 +18,52 @@
 use hurl_core::ast::{Filter, FilterValue};
 
 use super::count::eval_count;
+use crate::runner::eval_context::EvalContext;
+use crate::runner::filter::base64_url_safe::{
+    eval_base64_url_safe_decode, eval_base64_url_safe_encode,
+};
+use crate::runner::filter::bytes_length::eval_bytes_length;
+use crate::runner::filter::case::{eval_camel_case, eval_kebab_case, eval_snake_case};
+use crate::runner::filter::clamp::eval_clamp;
+use crate::runner::filter::count_matches::eval_count_matches;
 use crate::runner::filter::days_after_now::eval_days_after_now;
 use crate::runner::filter::days_before_now::eval_days_before_now;
 use crate::runner::filter::decode::eval_decode;
+use crate::runner::filter::diff::eval_diff;
 use crate::runner::filter::format::eval_format;
 use crate::runner::filter::html_escape::eval_html_escape;
 use crate::runner::filter::html_unescape::eval_html_unescape;
-use crate::runner::filter::jsonpath::eval_jsonpath;
+use crate::runner::filter::join::eval_join;
+use crate::runner::filter::json_escape::{eval_json_escape, eval_json_quote, eval_json_unescape};
+use crate::runner::filter::json_merge::eval_json_merge;
+use crate::runner::filter::jsonpath::{eval_jsonpath, eval_jsonpath_pointers};
+use crate::runner::filter::ndjson::eval_ndjson;
+use crate::runner::filter::normalize_whitespace::eval_normalize_whitespace;
 use crate::runner::filter::nth::eval_nth;
-use crate::runner::filter::regex::eval_regex;
-use crate::runner::filter::replace::eval_replace;
+use crate::runner::filter::parse_csv::eval_parse_csv;
+use crate::runner::filter::percent_of::eval_percent_of;
+use crate::runner::filter::regex::{eval_regex, eval_regex_all};
+use crate::runner::filter::repeat::eval_repeat;
+use crate::runner::filter::replace::{eval_replace, eval_replace_literal};
+use crate::runner::filter::retry_after_seconds::eval_retry_after_seconds;
 use crate::runner::filter::split::eval_split;
+use crate::runner::filter::strip_prefix::eval_strip_prefix;
+use crate::runner::filter::strip_suffix::eval_strip_suffix;
+use crate::runner::filter::title_case::eval_title_case;
+use crate::runner::filter::to_bool::eval_to_bool;
 use crate::runner::filter::to_date::eval_to_date;
 use crate::runner::filter::to_float::eval_to_float;
 use crate::runner::filter::to_int::eval_to_int;
 use crate::runner::filter::url_decode::eval_url_decode;
 use crate::runner::filter::url_encode::eval_url_encode;
-use crate::runner::filter::xpath::eval_xpath;
+use crate::runner::filter::url_part::{
+    eval_url_host, eval_url_path, eval_url_query, eval_url_scheme,
+};
+use crate::runner::filter::value_type::eval_type;
+use crate::runner::filter::xml_to_json::eval_xml_to_json;
+use crate::runner::filter::xpath::{
+    eval_xpath, eval_xpath_attr, eval_xpath_outer_html, eval_xpath_xml,
+};
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
 /// Apply successive `filter` to an input `value`.
@@ -43,12 +72,16 @@ pub fn eval_filters(
     filters: &[Filter],
     value: &Value,
     variables: &VariableSet,
+    eval_context: &mut EvalContext,
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
+    let optional = is_optional(filters);
     let mut value = Some(value.clone());
     for filter in filters {
         value = if let Some(value) = value {
-            eval_filter(filter, &value, variables, in_assert)?
+            eval_filter(filter, &value, variables, eval_context, in_assert)?
+        } else if optional {
+            return Ok(None);
         } else {
             return Err(RunnerError::new(
                 filter.source_info,
@@ -60,32 +93,117 @@ pub fn eval_filters(
     Ok(value)
 }
 
+/// Returns `true` if the chain of `filters` is optional, i.e. it contains the `?` filter.
+///
+/// In an optional chain, a missing value short-circuits the chain to `None`, instead of raising
+/// a [`RunnerErrorKind::FilterMissingInput`] error.
+pub fn is_optional(filters: &[Filter]) -> bool {
+    filters.iter().any(|f| f.value == FilterValue::Optional)
+}
+
 /// Evaluates a `filter` with an input `value`, given a set of `variables`.
 pub fn eval_filter(
     filter: &Filter,
     value: &Value,
     variables: &VariableSet,
+    eval_context: &mut EvalContext,
     in_assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match &filter.value {
+        FilterValue::BytesLength => eval_bytes_length(value, filter.source_info, in_assert),
+        FilterValue::Clamp { min, max, .. } => {
+            eval_clamp(value, min, max, filter.source_info, in_assert)
+        }
         FilterValue::Count => eval_count(value, filter.source_info, in_assert),
+        FilterValue::CountMatches { pattern, .. } => eval_count_matches(
+            value,
+            pattern,
+            variables,
+            eval_context,
+            filter.source_info,
+            in_assert,
+        ),
         FilterValue::DaysAfterNow => eval_days_after_now(value, filter.source_info, in_assert),
         FilterValue::DaysBeforeNow => eval_days_before_now(value, filter.source_info, in_assert),
         FilterValue::Decode { encoding, .. } => {
             eval_decode(value, encoding, variables, filter.source_info, in_assert)
         }
+        FilterValue::Diff { other, .. } => {
+            eval_diff(value, other, variables, filter.source_info, in_assert)
+        }
         FilterValue::Format { fmt, .. } => {
             eval_format(value, fmt, variables, filter.source_info, in_assert)
         }
         FilterValue::HtmlEscape => eval_html_escape(value, filter.source_info, in_assert),
         FilterValue::HtmlUnescape => eval_html_unescape(value, filter.source_info, in_assert),
-        FilterValue::JsonPath { expr, .. } => {
-            eval_jsonpath(value, expr, variables, filter.source_info, in_assert)
+        FilterValue::JsonEscape => eval_json_escape(value, filter.source_info, in_assert),
+        FilterValue::JsonQuote => eval_json_quote(value, filter.source_info, in_assert),
+        FilterValue::JsonUnescape => eval_json_unescape(value, filter.source_info, in_assert),
+        FilterValue::JsonPath {
+            expr,
+            expected_type,
+            ..
+        } => {
+            let expected_type = expected_type.as_ref().map(|(_, _, name)| name.as_str());
+            eval_jsonpath(
+                value,
+                expr,
+                expected_type,
+                variables,
+                eval_context,
+                filter.source_info,
+                in_assert,
+            )
+        }
+        FilterValue::JsonPathPointers { expr, .. } => eval_jsonpath_pointers(
+            value,
+            expr,
+            variables,
+            eval_context,
+            filter.source_info,
+            in_assert,
+        ),
+        FilterValue::JsonMerge { other, .. } => {
+            eval_json_merge(value, other, variables, filter.source_info, in_assert)
+        }
+        FilterValue::Ndjson => eval_ndjson(value, filter.source_info, in_assert),
+        FilterValue::RetryAfterSeconds => {
+            eval_retry_after_seconds(value, filter.source_info, in_assert)
+        }
+        FilterValue::NormalizeWhitespace => {
+            eval_normalize_whitespace(value, filter.source_info, in_assert)
         }
         FilterValue::Regex {
             value: regex_value, ..
-        } => eval_regex(value, regex_value, variables, filter.source_info, in_assert),
+        } => eval_regex(
+            value,
+            regex_value,
+            variables,
+            eval_context,
+            filter.source_info,
+            in_assert,
+        ),
+        FilterValue::RegexAll {
+            value: regex_value, ..
+        } => eval_regex_all(
+            value,
+            regex_value,
+            variables,
+            eval_context,
+            filter.source_info,
+            in_assert,
+        ),
+        FilterValue::Repeat { count, .. } => {
+            eval_repeat(value, *count, filter.source_info, in_assert)
+        }
         FilterValue::Nth { n, .. } => eval_nth(value, filter.source_info, in_assert, *n),
+        FilterValue::Optional => Ok(Some(value.clone())),
+        FilterValue::ParseCsv { has_header, .. } => {
+            eval_parse_csv(value, *has_header, filter.source_info, in_assert)
+        }
+        FilterValue::PercentOf { total, .. } => {
+            eval_percent_of(value, total, variables, filter.source_info, in_assert)
+        }
         FilterValue::Replace {
             old_value,
             new_value,
@@ -93,34 +211,116 @@ pub fn eval_filter(
         } => eval_replace(
             value,
             variables,
+            eval_context,
             filter.source_info,
             in_assert,
             old_value,
             new_value,
         ),
+        FilterValue::ReplaceLiteral {
+            old_value,
+            new_value,
+            ..
+        } => eval_replace_literal(
+            value,
+            variables,
+            filter.source_info,
+            in_assert,
+            old_value,
+            new_value,
+        ),
+        FilterValue::Join { sep, .. } => {
+            eval_join(value, variables, filter.source_info, in_assert, sep)
+        }
         FilterValue::Split { sep, .. } => {
             eval_split(value, variables, filter.source_info, in_assert, sep)
         }
+        FilterValue::StripPrefix { prefix, .. } => {
+            eval_strip_prefix(value, prefix, variables, filter.source_info, in_assert)
+        }
+        FilterValue::StripSuffix { suffix, .. } => {
+            eval_strip_suffix(value, suffix, variables, filter.source_info, in_assert)
+        }
+        FilterValue::TitleCase => eval_title_case(value, filter.source_info, in_assert),
+        FilterValue::Base64UrlSafeDecode => {
+            eval_base64_url_safe_decode(value, filter.source_info, in_assert)
+        }
+        FilterValue::Base64UrlSafeEncode => {
+            eval_base64_url_safe_encode(value, filter.source_info, in_assert)
+        }
+        FilterValue::CamelCase => eval_camel_case(value, filter.source_info, in_assert),
+        FilterValue::KebabCase => eval_kebab_case(value, filter.source_info, in_assert),
+        FilterValue::SnakeCase => eval_snake_case(value, filter.source_info, in_assert),
+        FilterValue::ToBool => eval_to_bool(value, filter.source_info, in_assert),
         FilterValue::ToDate { fmt, .. } => {
             eval_to_date(value, fmt, variables, filter.source_info, in_assert)
         }
         FilterValue::ToFloat => eval_to_float(value, filter.source_info, in_assert),
         FilterValue::ToInt => eval_to_int(value, filter.source_info, in_assert),
-        FilterValue::UrlDecode => eval_url_decode(value, filter.source_info, in_assert),
-        FilterValue::UrlEncode => eval_url_encode(value, filter.source_info, in_assert),
-        FilterValue::XPath { expr, .. } => {
-            eval_xpath(value, expr, variables, filter.source_info, in_assert)
+        FilterValue::Type => eval_type(value, filter.source_info, in_assert),
+        FilterValue::UrlDecode { mode } => {
+            let mode = mode.as_ref().map(|(_, mode)| mode.as_str());
+            eval_url_decode(value, mode, filter.source_info, in_assert)
+        }
+        FilterValue::UrlEncode { mode } => {
+            let mode = mode.as_ref().map(|(_, mode)| mode.as_str());
+            eval_url_encode(value, mode, filter.source_info, in_assert)
+        }
+        FilterValue::UrlHost => eval_url_host(value, filter.source_info, in_assert),
+        FilterValue::UrlPath => eval_url_path(value, filter.source_info, in_assert),
+        FilterValue::UrlQuery => eval_url_query(value, filter.source_info, in_assert),
+        FilterValue::UrlScheme => eval_url_scheme(value, filter.source_info, in_assert),
+        FilterValue::XmlToJson => eval_xml_to_json(value, filter.source_info, in_assert),
+        FilterValue::XPath {
+            expr, namespaces, ..
+        } => {
+            let namespaces = namespaces.as_ref().map(|(_, _, n)| n);
+            eval_xpath(
+                value,
+                expr,
+                namespaces,
+                variables,
+                eval_context,
+                filter.source_info,
+                in_assert,
+            )
         }
+        FilterValue::XPathAttr { expr, attr, .. } => eval_xpath_attr(
+            value,
+            expr,
+            attr,
+            variables,
+            eval_context,
+            filter.source_info,
+            in_assert,
+        ),
+        FilterValue::XPathOuterHtml { expr, .. } => eval_xpath_outer_html(
+            value,
+            expr,
+            variables,
+            eval_context,
+            filter.source_info,
+            in_assert,
+        ),
+        FilterValue::XPathXml { expr, .. } => eval_xpath_xml(
+            value,
+            expr,
+            variables,
+            eval_context,
+            filter.source_info,
+            in_assert,
+        ),
     }
 }
 
 #[cfg(test)]
 pub mod tests {
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filters;
-    use crate::runner::{Number, Value, VariableSet};
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
     #[test]
     pub fn test_filters() {
@@ -138,6 +338,7 @@ pub mod tests {
                     Value::Number(Number::Integer(2)),
                 ]),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
@@ -145,4 +346,76 @@ pub mod tests {
             Value::Number(Number::Integer(3))
         );
     }
+
+    /// Returns the filters `jsonpath "$.missing" nth 0`, with an optional `?` filter first if
+    /// `optional` is `true`.
+    fn missing_path_filters(optional: bool) -> Vec<Filter> {
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        };
+        let mut filters = vec![];
+        if optional {
+            filters.push(Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 2)),
+                value: FilterValue::Optional,
+            });
+        }
+        filters.push(Filter {
+            source_info: SourceInfo::new(Pos::new(1, 3), Pos::new(1, 23)),
+            value: FilterValue::JsonPath {
+                space0: whitespace.clone(),
+                expected_type: None,
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.missing".to_string(),
+                        encoded: "$.missing".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 23)),
+                },
+            },
+        });
+        filters.push(Filter {
+            source_info: SourceInfo::new(Pos::new(1, 24), Pos::new(1, 29)),
+            value: FilterValue::Nth {
+                space0: whitespace,
+                n: 0,
+            },
+        });
+        filters
+    }
+
+    #[test]
+    pub fn test_filters_optional() {
+        let variables = VariableSet::new();
+        let value = Value::String(r#"{"id": 1}"#.to_string());
+
+        assert_eq!(
+            eval_filters(
+                &missing_path_filters(true),
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap(),
+            None
+        );
+
+        let error = eval_filters(
+            &missing_path_filters(false),
+            &value,
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::FilterMissingInput);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 24), Pos::new(1, 29))
+        );
+    }
 }
@@ -0,0 +1,224 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{SourceInfo, Template};
+
+use crate::runner::template::eval_template;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Joins the elements of a list `value` into a string, separated by `sep`.
+///
+/// Strings are joined as is, numbers and booleans are converted to their string form. Any other
+/// element is an error.
+pub fn eval_join(
+    value: &Value,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+    sep: &Template,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::List(values) => {
+            let sep = eval_template(sep, variables)?;
+            let mut elements = Vec::with_capacity(values.len());
+            for v in values {
+                match v {
+                    Value::String(s) => elements.push(s.clone()),
+                    Value::Number(_) | Value::Bool(_) => elements.push(v.to_string()),
+                    v => {
+                        let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                            "list element of type {}",
+                            v._type()
+                        ));
+                        return Err(RunnerError::new(source_info, kind, assert));
+                    }
+                }
+            }
+            Ok(Some(Value::String(elements.join(&sep))))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_join() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Join {
+                sep: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: ",".to_string(),
+                        encoded: ",".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 9)),
+                },
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::List(vec![
+                    Value::String("a".to_string()),
+                    Value::Number(Number::Integer(2)),
+                    Value::Number(Number::Float(3.5)),
+                    Value::Bool(true),
+                ]),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("a,2,3.5,true".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::List(vec![]),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(String::new())
+        );
+
+        // Separator with several characters
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Join {
+                sep: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: ", ".to_string(),
+                        encoded: ", ".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 9)),
+                },
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::List(vec![
+                    Value::String("192.168.2.1".to_string()),
+                    Value::String("10.0.0.20".to_string()),
+                ]),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("192.168.2.1, 10.0.0.20".to_string())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_join_invalid_input() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Join {
+                sep: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: ",".to_string(),
+                        encoded: ",".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 9)),
+                },
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 6)),
+                },
+            },
+        };
+
+        let error = eval_filter(
+            &filter,
+            &Value::List(vec![
+                Value::String("a".to_string()),
+                Value::List(vec![Value::String("b".to_string())]),
+            ]),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("list element of type list".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::List(vec![Value::Nodeset(2)]),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("list element of type nodeset".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("a,b".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
@@ -0,0 +1,218 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Escapes a string for embedding in a JSON string, without the surrounding double quotes.
+pub fn eval_json_escape(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let quoted = json_quote(value, source_info, assert)?;
+    let escaped = &quoted[1..quoted.len() - 1];
+    Ok(Some(Value::String(escaped.to_string())))
+}
+
+/// Converts a string to a JSON string literal, enclosed in double quotes.
+pub fn eval_json_quote(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let quoted = json_quote(value, source_info, assert)?;
+    Ok(Some(Value::String(quoted)))
+}
+
+/// Unescapes a JSON escaped string, without the surrounding double quotes.
+pub fn eval_json_unescape(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => {
+            // The string is parsed as a JSON string literal, so an unescaped double quote or
+            // backslash is detected as invalid.
+            let quoted = format!("\"{value}\"");
+            match serde_json::from_str::<String>(&quoted) {
+                Ok(unescaped) => Ok(Some(Value::String(unescaped))),
+                Err(_) => {
+                    let kind = RunnerErrorKind::FilterInvalidInput(
+                        "invalid JSON escaped string".to_string(),
+                    );
+                    Err(RunnerError::new(source_info, kind, assert))
+                }
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+fn json_quote(value: &Value, source_info: SourceInfo, assert: bool) -> Result<String, RunnerError> {
+    match value {
+        // Serializing a string can't fail.
+        Value::String(value) => Ok(serde_json::to_string(value).unwrap()),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_json_escape() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 11)),
+            value: FilterValue::JsonEscape,
+        };
+
+        let tests = [
+            ("foo", "foo"),
+            ("say \"hi\"\nbye", "say \\\"hi\\\"\\nbye"),
+            ("a\\b\tc", "a\\\\b\\tc"),
+            ("\u{1}", "\\u0001"),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
+
+    #[test]
+    pub fn eval_filter_json_unescape() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+            value: FilterValue::JsonUnescape,
+        };
+
+        let tests = [
+            ("foo", "foo"),
+            ("say \\\"hi\\\"\\nbye", "say \"hi\"\nbye"),
+            ("a\\\\b\\tc", "a\\b\tc"),
+            ("\\u0001", "\u{1}"),
+            ("caf\\u00e9 \\ud83d\\ude00", "café 😀"),
+            ("café", "café"),
+            ("\\/", "/"),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(input.to_string()),
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+
+        for input in ["say \"hi\"", "a\\", "\\x41", "a\nb"] {
+            let error = eval_filter(
+                &filter,
+                &Value::String(input.to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false,
+            )
+            .err()
+            .unwrap();
+            assert_eq!(
+                error.kind,
+                RunnerErrorKind::FilterInvalidInput("invalid JSON escaped string".to_string())
+            );
+        }
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_json_quote() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::JsonQuote,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("say \"hi\"\nbye".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("\"say \\\"hi\\\"\\nbye\"".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("integer".to_string())
+        );
+    }
+}
@@ -0,0 +1,200 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{JsonValue, SourceInfo};
+
+use crate::runner::json::eval_json_value;
+use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Merges the JSON object `other` into an object `value`, using JSON Merge Patch semantics
+/// (see [RFC 7386](https://datatracker.ietf.org/doc/html/rfc7386)).
+///
+/// Properties of `other` override properties of `value`, objects are merged recursively and
+/// properties with a `null` value are removed.
+pub fn eval_json_merge(
+    value: &Value,
+    other: &JsonValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::Object(_) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value._type());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let patch = eval_json_value(other, variables, false)?;
+    let patch = match serde_json::from_str::<serde_json::Value>(&patch) {
+        Ok(patch) => patch,
+        Err(_) => {
+            let kind = RunnerErrorKind::InvalidJson { value: patch };
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+    let mut target = value.to_json();
+    merge_patch(&mut target, &patch);
+    Ok(Some(Value::from_json(&target)))
+}
+
+/// Applies a JSON Merge `patch` to a `target` JSON value, as specified by RFC 7386.
+fn merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let serde_json::Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    let serde_json::Value::Object(map) = target else {
+        unreachable!()
+    };
+    for (name, value) in patch {
+        if value.is_null() {
+            map.remove(name);
+        } else {
+            merge_patch(
+                map.entry(name.clone()).or_insert(serde_json::Value::Null),
+                value,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::{Pos, Reader};
+    use serde_json::json;
+
+    use super::merge_patch;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_json_merge() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonMerge {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                other: hurl_core::parser::parse_json(&mut Reader::new(r#"{"a": null, "b": 2}"#))
+                    .unwrap(),
+            },
+        };
+        let value = Value::Object(vec![("a".to_string(), Value::Number(Number::Integer(1)))]);
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Object(vec![("b".to_string(), Value::Number(Number::Integer(2)))])
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_json_merge_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonMerge {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                },
+                other: hurl_core::parser::parse_json(&mut Reader::new(r#"{"b": 2}"#)).unwrap(),
+            },
+        };
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn test_merge_patch() {
+        // Test cases from RFC 7386, Appendix A.
+        let tests = [
+            (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+            (
+                json!({"a": "b"}),
+                json!({"b": "c"}),
+                json!({"a": "b", "b": "c"}),
+            ),
+            (json!({"a": "b"}), json!({"a": null}), json!({})),
+            (
+                json!({"a": "b", "b": "c"}),
+                json!({"a": null}),
+                json!({"b": "c"}),
+            ),
+            (json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"})),
+            (json!({"a": "c"}), json!({"a": ["b"]}), json!({"a": ["b"]})),
+            (
+                json!({"a": {"b": "c"}}),
+                json!({"a": {"b": "d", "c": null}}),
+                json!({"a": {"b": "d"}}),
+            ),
+            (
+                json!({"a": [{"b": "c"}]}),
+                json!({"a": [1]}),
+                json!({"a": [1]}),
+            ),
+            (json!(["a", "b"]), json!(["c", "d"]), json!(["c", "d"])),
+            (json!({"a": "b"}), json!(["c"]), json!(["c"])),
+            (json!({"a": "foo"}), json!(null), json!(null)),
+            (json!({"a": "foo"}), json!("bar"), json!("bar")),
+            (
+                json!({"e": null}),
+                json!({"a": 1}),
+                json!({"e": null, "a": 1}),
+            ),
+            (
+                json!([1, 2]),
+                json!({"a": "b", "c": null}),
+                json!({"a": "b"}),
+            ),
+            (
+                json!({}),
+                json!({"a": {"bb": {"ccc": null}}}),
+                json!({"a": {"bb": {}}}),
+            ),
+        ];
+        for (target, patch, expected) in tests {
+            let mut result = target.clone();
+            merge_patch(&mut result, &patch);
+            assert_eq!(result, expected, "merging {patch} into {target}");
+        }
+    }
+}
@@ -15,16 +15,53 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{SourceInfo, Template};
+use hurl_core::ast::{SourceInfo, Template, TemplateElement};
+use hurl_core::reader::Pos;
 
 use crate::jsonpath;
+use crate::runner::eval_context::EvalContext;
 use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
+/// Evaluates the JSONPath expression `expr` against `value`.
+///
+/// If `expected_type` is set, the matched value must be of this type (`number` matching both
+/// integers and floats), otherwise a [`RunnerErrorKind::FilterTypeMismatch`] error is raised.
 pub fn eval_jsonpath(
     value: &Value,
     expr: &Template,
+    expected_type: Option<&str>,
     variables: &VariableSet,
+    eval_context: &EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let result = eval_jsonpath_value(value, expr, variables, eval_context, source_info, assert)?;
+    match (result, expected_type) {
+        (Some(value), Some(expected)) if !has_type(&value, expected) => {
+            let kind = RunnerErrorKind::FilterTypeMismatch {
+                expected: expected.to_string(),
+                actual: value._type(),
+            };
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+        (result, _) => Ok(result),
+    }
+}
+
+/// Returns `true` if `value` is of type `expected`.
+fn has_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "number" => matches!(value, Value::Number(_)),
+        _ => value._type() == expected,
+    }
+}
+
+fn eval_jsonpath_value(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    eval_context: &EvalContext,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
@@ -40,7 +77,11 @@ pub fn eval_jsonpath(
                 }
                 Ok(v) => v,
             };
-            eval_jsonpath_json(&json, expr, variables)
+            eval_jsonpath_json(&json, expr, variables, eval_context)
+        }
+        // Objects and lists (produced for instance by `xmlToJson` filter) are evaluated as JSON.
+        Value::Object(_) | Value::List(_) => {
+            eval_jsonpath_json(&value.to_json(), expr, variables, eval_context)
         }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v._type());
@@ -53,18 +94,22 @@ pub fn eval_jsonpath_json(
     json: &serde_json::Value,
     expr: &Template,
     variables: &VariableSet,
+    eval_context: &EvalContext,
 ) -> Result<Option<Value>, RunnerError> {
     let expr_str = eval_template(expr, variables)?;
-    let expr_source_info = expr.source_info;
     let jsonpath_query = match jsonpath::parse(&expr_str) {
         Ok(q) => q,
-        Err(_) => {
-            let kind = RunnerErrorKind::QueryInvalidJsonpathExpression { value: expr_str };
-            return Err(RunnerError::new(expr_source_info, kind, false));
+        Err(e) => {
+            let source_info = parse_error_source_info(expr, &e);
+            let kind = RunnerErrorKind::QueryInvalidJsonPath { value: expr_str };
+            return Err(RunnerError::new(source_info, kind, false));
         }
     };
 
-    let results = jsonpath_query.eval(json);
+    let results = match jsonpath_query.try_eval(json, eval_context.eval_timeout()) {
+        Ok(results) => results,
+        Err(error) => return Err(jsonpath_error(error, expr_str, expr.source_info)),
+    };
     match results {
         None => Ok(None),
         Some(jsonpath::JsonpathResult::SingleEntry(value)) => Ok(Some(Value::from_json(&value))),
@@ -74,13 +119,101 @@ pub fn eval_jsonpath_json(
     }
 }
 
+/// Converts an `error` of the evaluation of the JSONPath expression `expr_str` to a runner error.
+fn jsonpath_error(
+    error: jsonpath::EvalError,
+    expr_str: String,
+    source_info: SourceInfo,
+) -> RunnerError {
+    let kind = match error {
+        jsonpath::EvalError::Function(error) => RunnerErrorKind::QueryInvalidJsonPathFunction {
+            function: error.function.to_string(),
+            value_type: error.value_type,
+        },
+        // A function returns a computed value, not a node of the document.
+        jsonpath::EvalError::UnexpectedFunction(_) => {
+            RunnerErrorKind::QueryInvalidJsonPath { value: expr_str }
+        }
+        jsonpath::EvalError::Timeout { timeout } => RunnerErrorKind::EvalTimeout {
+            expr: expr_str,
+            timeout,
+        },
+    };
+    RunnerError::new(source_info, kind, false)
+}
+
+/// Returns the source info of the JSONPath parse `error` in the expression template `expr`.
+///
+/// The position of the error can only be mapped to the Hurl file for single line literal
+/// expressions (without placeholders and escaped chars), otherwise the source info of the whole
+/// expression is returned.
+fn parse_error_source_info(expr: &Template, error: &jsonpath::ParseError) -> SourceInfo {
+    let literal = match expr.elements.as_slice() {
+        [TemplateElement::String { value, encoded }] if value == encoded => value,
+        _ => return expr.source_info,
+    };
+    if expr.source_info.start.line != expr.source_info.end.line
+        || error.pos.line != 1
+        || error.pos.column > literal.chars().count() + 1
+    {
+        return expr.source_info;
+    }
+    let offset = if expr.delimiter.is_some() { 1 } else { 0 };
+    let line = expr.source_info.start.line;
+    let column = expr.source_info.start.column + offset + error.pos.column - 1;
+    SourceInfo::new(Pos::new(line, column), Pos::new(line, column + 1))
+}
+
+/// Evaluates the JSONPath expression `expr` against a JSON string `value`, and returns the
+/// JSON pointers of the matching nodes.
+pub fn eval_jsonpath_pointers(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    eval_context: &EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::String(text) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value._type());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let Ok(json) = serde_json::from_str(text) else {
+        return Err(RunnerError::new(
+            source_info,
+            RunnerErrorKind::QueryInvalidJson,
+            false,
+        ));
+    };
+    let expr_str = eval_template(expr, variables)?;
+    let jsonpath_query = match jsonpath::parse(&expr_str) {
+        Ok(q) => q,
+        Err(e) => {
+            let source_info = parse_error_source_info(expr, &e);
+            let kind = RunnerErrorKind::QueryInvalidJsonPath { value: expr_str };
+            return Err(RunnerError::new(source_info, kind, false));
+        }
+    };
+    let pointers = match jsonpath_query.eval_pointers(&json, eval_context.eval_timeout()) {
+        Ok(pointers) => pointers,
+        Err(error) => return Err(jsonpath_error(error, expr_str, expr.source_info)),
+    };
+    let pointers = pointers.into_iter().map(Value::String).collect();
+    Ok(Some(Value::List(pointers)))
+}
+
 #[cfg(test)]
 pub mod tests {
+    use std::time::Duration;
+
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
-    use crate::runner::filter::eval::eval_filter;
-    use crate::runner::{Value, VariableSet};
+    use super::eval_jsonpath_json;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::{eval_filter, eval_filters};
+    use crate::runner::RunnerOptionsBuilder;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
     #[test]
     pub fn eval_filter_jsonpath() {
@@ -89,6 +222,7 @@ pub mod tests {
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
             value: FilterValue::JsonPath {
+                expected_type: None,
                 expr: Template {
                     delimiter: Some('"'),
                     elements: vec![TemplateElement::String {
@@ -108,6 +242,7 @@ pub mod tests {
                 &filter,
                 &Value::String(r#"{"message":"Hello"}"#.to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -115,4 +250,463 @@ pub mod tests {
             Value::String("Hello".to_string())
         );
     }
+
+    #[test]
+    pub fn eval_filter_jsonpath_timeout() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30)),
+            value: FilterValue::JsonPath {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$..*..*".to_string(),
+                        encoded: "$..*..*".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expected_type: None,
+            },
+        };
+        let json = Value::String(r#"{"a":[1,2,{"b":[3,4,{"c":[5,6]}]}]}"#.to_string());
+
+        assert!(eval_filter(
+            &filter,
+            &json,
+            &variables,
+            &mut EvalContext::default(),
+            false
+        )
+        .is_ok());
+
+        let runner_options = RunnerOptionsBuilder::new()
+            .eval_timeout(Duration::ZERO)
+            .build();
+        let error = eval_filter(
+            &filter,
+            &json,
+            &variables,
+            &mut EvalContext::new(&runner_options),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::EvalTimeout {
+                expr: "$..*..*".to_string(),
+                timeout: Duration::ZERO,
+            }
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_jsonpath_expected_type() {
+        let variables = VariableSet::new();
+        let json = Value::String(r#"{"id":1,"price":9.5,"name":"Bob","tags":[]}"#.to_string());
+
+        for (expr, expected_type, expected) in [
+            ("$.id", "number", Value::Number(Number::Integer(1))),
+            ("$.id", "integer", Value::Number(Number::Integer(1))),
+            ("$.price", "number", Value::Number(Number::Float(9.5))),
+            ("$.price", "float", Value::Number(Number::Float(9.5))),
+            ("$.name", "string", Value::String("Bob".to_string())),
+            ("$.tags", "list", Value::List(vec![])),
+        ] {
+            let filter = Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30)),
+                value: FilterValue::JsonPath {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    expr: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: expr.to_string(),
+                            encoded: expr.to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    expected_type: Some((
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                        },
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                        },
+                        expected_type.to_string(),
+                    )),
+                },
+            };
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &json,
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                expected
+            );
+        }
+
+        // No match is not a type error.
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30)),
+            value: FilterValue::JsonPath {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.unknown".to_string(),
+                        encoded: "$.unknown".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expected_type: Some((
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    "number".to_string(),
+                )),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &json,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_jsonpath_expected_type_mismatch() {
+        let variables = VariableSet::new();
+        let json = Value::String(r#"{"id":"1","price":9.5}"#.to_string());
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30)),
+            value: FilterValue::JsonPath {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.id".to_string(),
+                        encoded: "$.id".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expected_type: Some((
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    "number".to_string(),
+                )),
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &json,
+            &variables,
+            &mut EvalContext::default(),
+            true,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterTypeMismatch {
+                expected: "number".to_string(),
+                actual: "string".to_string(),
+            }
+        );
+        assert!(error.assert);
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30)),
+            value: FilterValue::JsonPath {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.price".to_string(),
+                        encoded: "$.price".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expected_type: Some((
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    "integer".to_string(),
+                )),
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &json,
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterTypeMismatch {
+                expected: "integer".to_string(),
+                actual: "float".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_jsonpath_invalid_json() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30)),
+            value: FilterValue::JsonPath {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.sub".to_string(),
+                        encoded: "$.sub".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expected_type: None,
+            },
+        };
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("{\"sub\":".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            true,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30))
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_decode_then_jsonpath() {
+        // decode "utf-8" jsonpath "$.sub", on a decoded JWT payload for instance.
+        let variables = VariableSet::new();
+        let decode = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 15)),
+            value: FilterValue::Decode {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                encoding: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "utf-8".to_string(),
+                        encoded: "utf-8".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 15)),
+                },
+            },
+        };
+        let jsonpath = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 30)),
+            value: FilterValue::JsonPath {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.sub".to_string(),
+                        encoded: "$.sub".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expected_type: None,
+            },
+        };
+        let payload = Value::Bytes(br#"{"sub":"1234567890","name":"John Doe"}"#.to_vec());
+
+        assert_eq!(
+            eval_filters(
+                &[decode, jsonpath],
+                &payload,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("1234567890".to_string())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_jsonpath_pointers() {
+        let variables = VariableSet::new();
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::JsonPathPointers {
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$..price".to_string(),
+                        encoded: "$..price".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let json = r#"{"books":[{"price":8.95},{"price":12.99}],"bicycle":{"price":19.95}}"#;
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String(json.to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("/bicycle/price".to_string()),
+                Value::String("/books/0/price".to_string()),
+                Value::String("/books/1/price".to_string()),
+            ])
+        );
+    }
+
+    fn jsonpath_template(expr: &str, start: Pos) -> Template {
+        let end = Pos::new(start.line, start.column + expr.chars().count() + 2);
+        Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: expr.to_string(),
+                encoded: expr.to_string(),
+            }],
+            source_info: SourceInfo::new(start, end),
+        }
+    }
+
+    #[test]
+    fn eval_jsonpath_invalid_expression() {
+        let variables = VariableSet::new();
+        let json = serde_json::json!({"users": ["Bob", "Bill"]});
+
+        // jsonpath "$.users xxx"
+        let expr = jsonpath_template("$.users xxx", Pos::new(1, 10));
+        let error =
+            eval_jsonpath_json(&json, &expr, &variables, &EvalContext::default()).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidJsonPath {
+                value: "$.users xxx".to_string()
+            }
+        );
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 18), Pos::new(1, 19))
+        );
+    }
+
+    #[test]
+    fn eval_jsonpath_no_match() {
+        let variables = VariableSet::new();
+        let json = serde_json::json!({"users": ["Bob", "Bill"]});
+
+        // jsonpath "$.books"
+        let expr = jsonpath_template("$.books", Pos::new(1, 10));
+        assert_eq!(
+            eval_jsonpath_json(&json, &expr, &variables, &EvalContext::default()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_jsonpath_length() {
+        let variables = VariableSet::new();
+        let json = serde_json::json!({"users": ["Bob", "Bill"], "count": 2});
+
+        // jsonpath "$.users.length()"
+        let expr = jsonpath_template("$.users.length()", Pos::new(1, 10));
+        assert_eq!(
+            eval_jsonpath_json(&json, &expr, &variables, &EvalContext::default()).unwrap(),
+            Some(Value::Number(Number::Integer(2)))
+        );
+
+        // jsonpath "$.count.length()"
+        let expr = jsonpath_template("$.count.length()", Pos::new(1, 10));
+        let error =
+            eval_jsonpath_json(&json, &expr, &variables, &EvalContext::default()).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidJsonPathFunction {
+                function: "length()".to_string(),
+                value_type: "number".to_string()
+            }
+        );
+        assert_eq!(error.source_info, expr.source_info);
+    }
 }
@@ -16,26 +16,48 @@
  *
  */
 
-pub use eval::eval_filters;
+pub use eval::{eval_filters, is_optional};
 pub use jsonpath::eval_jsonpath_json;
 pub use xpath::eval_xpath_doc;
 
+mod base64_url_safe;
+mod bytes_length;
+mod case;
+mod clamp;
 mod count;
+mod count_matches;
 mod days_after_now;
 mod days_before_now;
 mod decode;
+mod diff;
 mod eval;
 mod format;
 mod html_escape;
 mod html_unescape;
+mod join;
+mod json_escape;
+mod json_merge;
 mod jsonpath;
+mod ndjson;
+mod normalize_whitespace;
 mod nth;
+mod parse_csv;
+mod percent_of;
 mod regex;
+mod repeat;
 mod replace;
+mod retry_after_seconds;
 mod split;
+mod strip_prefix;
+mod strip_suffix;
+mod title_case;
+mod to_bool;
 mod to_date;
 mod to_float;
 mod to_int;
 mod url_decode;
 mod url_encode;
+mod url_part;
+mod value_type;
+mod xml_to_json;
 mod xpath;
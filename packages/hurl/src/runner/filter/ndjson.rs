@@ -0,0 +1,157 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Parses a newline-delimited JSON (NDJSON) `value` and returns the list of its JSON documents.
+///
+/// Blank lines are skipped. Each line must be a valid JSON document, otherwise an error is
+/// returned with the (1-based) number of the invalid line.
+pub fn eval_ndjson(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let text = match value {
+        Value::String(s) => s.as_str(),
+        Value::Bytes(bytes) => match std::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => {
+                let kind = RunnerErrorKind::FilterDecode("utf-8".to_string());
+                return Err(RunnerError::new(source_info, kind, assert));
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.display());
+            return Err(RunnerError::new(source_info, kind, assert));
+        }
+    };
+
+    let mut values = vec![];
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(json) => values.push(Value::from_json(&json)),
+            Err(_) => {
+                let message = format!("invalid JSON at line {}", index + 1);
+                let kind = RunnerErrorKind::FilterInvalidInput(message);
+                return Err(RunnerError::new(source_info, kind, assert));
+            }
+        }
+    }
+    Ok(Some(Value::List(values)))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_ndjson() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: FilterValue::Ndjson,
+        };
+        let body = "{\"id\":1,\"name\":\"Bob\"}\n\n{\"id\":2,\"name\":\"Bill\"}\r\n{\"id\":3,\"name\":\"Alice\"}\n";
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String(body.to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::Object(vec![
+                    ("id".to_string(), Value::Number(Number::Integer(1))),
+                    ("name".to_string(), Value::String("Bob".to_string())),
+                ]),
+                Value::Object(vec![
+                    ("id".to_string(), Value::Number(Number::Integer(2))),
+                    ("name".to_string(), Value::String("Bill".to_string())),
+                ]),
+                Value::Object(vec![
+                    ("id".to_string(), Value::Number(Number::Integer(3))),
+                    ("name".to_string(), Value::String("Alice".to_string())),
+                ]),
+            ])
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String(String::new()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![])
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_ndjson_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: FilterValue::Ndjson,
+        };
+        let body = "{\"id\":1}\n{\"id\":2\n{\"id\":3}\n";
+
+        let error = eval_filter(
+            &filter,
+            &Value::String(body.to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("invalid JSON at line 2".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("bool <true>".to_string())
+        );
+    }
+}
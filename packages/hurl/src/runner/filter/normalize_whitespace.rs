@@ -0,0 +1,126 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Normalizes the whitespaces of a string `value`: each line is trimmed, runs of whitespaces
+/// inside a line are collapsed to a single space, and leading and trailing empty lines are removed.
+pub fn eval_normalize_whitespace(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => Ok(Some(Value::String(normalize_whitespace(value)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+fn normalize_whitespace(value: &str) -> String {
+    let lines = value
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>();
+    let start = lines.iter().position(|l| !l.is_empty());
+    let end = lines.iter().rposition(|l| !l.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].join("\n"),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_normalize_whitespace() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::NormalizeWhitespace,
+        };
+
+        let actual = "\n    <p>\n        Hello   world\n    </p>\n";
+        let expected = "<p>\n\tHello world\r\n</p>";
+        let actual = eval_filter(
+            &filter,
+            &Value::String(actual.to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .unwrap()
+        .unwrap();
+        let expected = eval_filter(
+            &filter,
+            &Value::String(expected.to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(actual, Value::String("<p>\nHello world\n</p>".to_string()));
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String(" \n \n".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(String::new())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_normalize_whitespace_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::NormalizeWhitespace,
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(1)),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("integer".to_string())
+        );
+    }
+}
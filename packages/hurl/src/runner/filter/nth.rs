@@ -49,6 +49,7 @@ pub mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
 
@@ -76,6 +77,7 @@ pub mod tests {
                     Value::Number(Number::Integer(3))
                 ]),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -90,6 +92,7 @@ pub mod tests {
                     Value::Number(Number::Integer(1))
                 ]),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .err()
@@ -0,0 +1,230 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Parses a CSV `value`.
+///
+/// If `has_header` is true, the first record is used as header and a list of objects is returned,
+/// each object mapping a header name to its field. Otherwise, a list of records is returned, each
+/// record being a list of fields.
+pub fn eval_parse_csv(
+    value: &Value,
+    has_header: bool,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::String(text) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.display());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let invalid_csv = |e: csv::Error| {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!("invalid CSV: {e}"));
+        RunnerError::new(source_info, kind, assert)
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(has_header)
+        .from_reader(text.as_bytes());
+    let headers = if has_header {
+        let headers = reader.headers().map_err(invalid_csv)?;
+        Some(headers.iter().map(|h| h.to_string()).collect::<Vec<_>>())
+    } else {
+        None
+    };
+
+    let mut values = vec![];
+    for record in reader.records() {
+        let record = record.map_err(invalid_csv)?;
+        let value = match &headers {
+            Some(headers) => Value::Object(
+                headers
+                    .iter()
+                    .zip(record.iter())
+                    .map(|(name, field)| (name.clone(), Value::String(field.to_string())))
+                    .collect(),
+            ),
+            None => Value::List(
+                record
+                    .iter()
+                    .map(|field| Value::String(field.to_string()))
+                    .collect(),
+            ),
+        };
+        values.push(value);
+    }
+    Ok(Some(Value::List(values)))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    pub fn eval_filter_parse_csv_with_header() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::ParseCsv {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                },
+                has_header: true,
+            },
+        };
+        let csv = "name,address\nBob,\"1 rue de la Paix, Paris\"\nBill,London\n";
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &string(csv),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::Object(vec![
+                    ("name".to_string(), string("Bob")),
+                    ("address".to_string(), string("1 rue de la Paix, Paris")),
+                ]),
+                Value::Object(vec![
+                    ("name".to_string(), string("Bill")),
+                    ("address".to_string(), string("London")),
+                ]),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_parse_csv_without_header() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::ParseCsv {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                },
+                has_header: false,
+            },
+        };
+        let csv = "Bob,\"1 rue de la Paix, Paris\"\nBill,London\n";
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &string(csv),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::List(vec![string("Bob"), string("1 rue de la Paix, Paris")]),
+                Value::List(vec![string("Bill"), string("London")]),
+            ])
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_parse_csv_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::ParseCsv {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                },
+                has_header: true,
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("bool <true>".to_string())
+        );
+
+        // Records with a different number of fields are rejected.
+        let csv = "name,address\nBob\n";
+        let error = eval_filter(
+            &filter,
+            &string(csv),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert!(matches!(error.kind, RunnerErrorKind::FilterInvalidInput(_)));
+    }
+
+    #[test]
+    pub fn eval_filter_parse_csv_quoted_fields() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::ParseCsv {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                },
+                has_header: false,
+            },
+        };
+        let csv = "\"say \"\"hello\"\"\",\"a\r\nb\",\r\n\r\nc,,\"\"";
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &string(csv),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::List(vec![string("say \"hello\""), string("a\r\nb"), string("")]),
+                Value::List(vec![string("c"), string(""), string("")]),
+            ])
+        );
+    }
+}
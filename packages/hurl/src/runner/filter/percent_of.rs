@@ -0,0 +1,258 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::{Float, Number as AstNumber, NumberValue, Placeholder, SourceInfo};
+
+use crate::runner::{expr, Number, RunnerError, RunnerErrorKind, Value, VariableSet};
+
+/// Returns a number `value` as a percentage of `total` (`value / total * 100`), as a float.
+///
+/// `total` can be a placeholder, evaluated with `variables`. A total of zero is an error.
+pub fn eval_percent_of(
+    value: &Value,
+    total: &NumberValue,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::Number(number) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value._type());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let total = eval_number_value(total, variables)?;
+    if total == 0.0 {
+        let kind = RunnerErrorKind::FilterInvalidInput("percentOf a total of 0".to_string());
+        return Err(RunnerError::new(source_info, kind, assert));
+    }
+    let percent = to_f64(number) / total * 100.0;
+    Ok(Some(Value::Number(Number::Float(percent))))
+}
+
+/// Evaluates a number literal or placeholder `number_value` to a float, given a set of `variables`.
+fn eval_number_value(
+    number_value: &NumberValue,
+    variables: &VariableSet,
+) -> Result<f64, RunnerError> {
+    match number_value {
+        NumberValue::Literal(AstNumber::Float(Float { value, .. })) => Ok(*value),
+        NumberValue::Literal(AstNumber::Integer(value)) => Ok(*value as f64),
+        NumberValue::Literal(AstNumber::BigInteger(value)) => {
+            Ok(to_f64(&Number::BigInteger(value.clone())))
+        }
+        NumberValue::Placeholder(Placeholder { expr, .. }) => match expr::eval(expr, variables)? {
+            Value::Number(number) => Ok(to_f64(&number)),
+            v => {
+                let kind = RunnerErrorKind::ExpressionInvalidType {
+                    value: v.format(),
+                    expecting: "number".to_string(),
+                };
+                Err(RunnerError::new(expr.source_info, kind, false))
+            }
+        },
+    }
+}
+
+fn to_f64(number: &Number) -> f64 {
+    match number {
+        Number::Float(value) => *value,
+        Number::Integer(value) => *value as f64,
+        // A big integer is a valid number literal, so it can always be parsed (possibly losing
+        // precision).
+        Number::BigInteger(value) => value.parse().unwrap_or(f64::NAN),
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{
+        Expr, ExprKind, Filter, FilterValue, Number as AstNumber, NumberValue, Placeholder,
+        SourceInfo, Variable, Whitespace,
+    };
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_percent_of() {
+        let variables = VariableSet::new();
+
+        // 25 percentOf 200 == 12.5
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::PercentOf {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                total: NumberValue::Literal(AstNumber::Integer(200)),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(25)),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(12.5))
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Float(300.0)),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(150.0))
+        );
+    }
+
+    #[test]
+    fn eval_filter_percent_of_placeholder() {
+        let mut variables = VariableSet::new();
+        variables
+            .insert("total".to_string(), Value::Number(Number::Integer(200)))
+            .unwrap();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::PercentOf {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                total: NumberValue::Placeholder(Placeholder {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                    expr: Expr {
+                        source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 18)),
+                        kind: ExprKind::Variable(Variable {
+                            name: "total".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 13), Pos::new(1, 18)),
+                        }),
+                    },
+                    space1: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    },
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(25)),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(12.5))
+        );
+
+        let mut variables = VariableSet::new();
+        variables
+            .insert("total".to_string(), Value::String("200".to_string()))
+            .unwrap();
+        let error = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(25)),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 13), Pos::new(1, 18))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::ExpressionInvalidType {
+                value: "string <200>".to_string(),
+                expecting: "number".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn eval_filter_percent_of_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::PercentOf {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                total: NumberValue::Literal(AstNumber::Integer(0)),
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(25)),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("percentOf a total of 0".to_string())
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 14)),
+            value: FilterValue::PercentOf {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                total: NumberValue::Literal(AstNumber::Integer(200)),
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::String("25".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("string".to_string())
+        );
+    }
+}
@@ -17,6 +17,7 @@
  */
 use hurl_core::ast::{RegexValue, SourceInfo};
 
+use crate::runner::eval_context::EvalContext;
 use crate::runner::regex::eval_regex_value;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
@@ -24,10 +25,11 @@ pub fn eval_regex(
     value: &Value,
     regex_value: &RegexValue,
     variables: &VariableSet,
+    eval_context: &mut EvalContext,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
-    let re = eval_regex_value(regex_value, variables)?;
+    let re = eval_regex_value(regex_value, variables, eval_context)?;
     match value {
         Value::String(s) => match re.captures(s.as_str()) {
             Some(captures) => match captures.get(1) {
@@ -43,6 +45,41 @@ pub fn eval_regex(
     }
 }
 
+/// Returns the list of all non-overlapping matches of `regex_value` in a string `value`.
+///
+/// If the regex has a capture group, the first capture group of each match is returned instead of
+/// the whole match. No match returns an empty list.
+pub fn eval_regex_all(
+    value: &Value,
+    regex_value: &RegexValue,
+    variables: &VariableSet,
+    eval_context: &mut EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let re = eval_regex_value(regex_value, variables, eval_context)?;
+    match value {
+        Value::String(s) => {
+            let values = re
+                .captures_iter(s.as_str())
+                .filter_map(|captures| {
+                    if re.captures_len() > 1 {
+                        captures.get(1)
+                    } else {
+                        captures.get(0)
+                    }
+                })
+                .map(|m| Value::String(m.as_str().to_string()))
+                .collect();
+            Ok(Some(Value::List(values)))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use hurl_core::ast::{
@@ -50,6 +87,7 @@ pub mod tests {
     };
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{RunnerErrorKind, Value, VariableSet};
 
@@ -80,6 +118,7 @@ pub mod tests {
                 &filter,
                 &Value::String("Hello Bob!".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
@@ -87,9 +126,15 @@ pub mod tests {
             Value::String("Bob".to_string())
         );
 
-        let error = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.source_info,
             SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20))
@@ -100,6 +145,168 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn eval_filter_regex_all() {
+        let variables = VariableSet::new();
+        let value = Value::String("id=12 retry=3 id=450".to_string());
+
+        // regexAll "id=(\\d+)"
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::RegexAll {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                value: RegexValue::Template(Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "id=(\\d+)".to_string(),
+                        encoded: "id=(\\d+)".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 20)),
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("12".to_string()),
+                Value::String("450".to_string()),
+            ])
+        );
+
+        // regexAll "\\d+"
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::RegexAll {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                value: RegexValue::Template(Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "\\d+".to_string(),
+                        encoded: "\\d+".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 20)),
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("12".to_string()),
+                Value::String("3".to_string()),
+                Value::String("450".to_string()),
+            ])
+        );
+
+        // No match
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::RegexAll {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                value: RegexValue::Template(Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "user=(\\w+)".to_string(),
+                        encoded: "user=(\\w+)".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 20)),
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![])
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn eval_filter_regex_all_numbers() {
+        let variables = VariableSet::new();
+        let value = Value::String("took 12ms, then 3.5ms and -40ms (total 55.5)".to_string());
+
+        // regexAll /-?\d+(?:\.\d+)?/
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 20)),
+            value: FilterValue::RegexAll {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                value: RegexValue::Template(Template {
+                    delimiter: None,
+                    elements: vec![TemplateElement::String {
+                        value: "-?\\d+(?:\\.\\d+)?".to_string(),
+                        encoded: "-?\\d+(?:\\.\\d+)?".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 20)),
+                }),
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::List(vec![
+                Value::String("12".to_string()),
+                Value::String("3.5".to_string()),
+                Value::String("-40".to_string()),
+                Value::String("55.5".to_string()),
+            ])
+        );
+    }
+
     #[test]
     fn eval_filter_invalid_regex() {
         let variables = VariableSet::new();
@@ -125,6 +332,7 @@ pub mod tests {
             &filter,
             &Value::String("Hello Bob!".to_string()),
             &variables,
+            &mut EvalContext::default(),
             false,
         )
         .err()
@@ -0,0 +1,198 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Maximum length in bytes of a string produced by the `repeat` filter.
+pub const MAX_REPEAT_LENGTH: usize = 10 * 1024 * 1024;
+
+/// Repeats a string `value` `count` times.
+///
+/// `count` must be positive, and the length of the resulting string must not exceed
+/// [`MAX_REPEAT_LENGTH`].
+pub fn eval_repeat(
+    value: &Value,
+    count: i64,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let Value::String(s) = value else {
+        let kind = RunnerErrorKind::FilterInvalidInput(value.display());
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    let Ok(count) = usize::try_from(count) else {
+        let kind = RunnerErrorKind::FilterInvalidInput(format!(
+            "repeat count must be positive, actual is {count}"
+        ));
+        return Err(RunnerError::new(source_info, kind, assert));
+    };
+    match s.len().checked_mul(count) {
+        Some(len) if len <= MAX_REPEAT_LENGTH => Ok(Some(Value::String(s.repeat(count)))),
+        _ => {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!(
+                "repeated string exceeds the maximum length of {MAX_REPEAT_LENGTH} bytes"
+            ));
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_repeat() {
+        let variables = VariableSet::new();
+        let value = Value::String("ab".to_string());
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Repeat {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                count: 3,
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("ababab".to_string())
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Repeat {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                count: 0,
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &value,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String(String::new())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_repeat_error() {
+        let variables = VariableSet::new();
+        let value = Value::String("ab".to_string());
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Repeat {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                count: -1,
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &value,
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "repeat count must be positive, actual is -1".to_string()
+            )
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Repeat {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                count: i64::MAX,
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &value,
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "repeated string exceeds the maximum length of 10485760 bytes".to_string()
+            )
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 9)),
+            value: FilterValue::Repeat {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                count: 2,
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("bool <true>".to_string())
+        );
+    }
+}
@@ -17,13 +17,18 @@
  */
 use hurl_core::ast::{RegexValue, SourceInfo, Template};
 
-use crate::runner::regex::eval_regex_value;
+use crate::runner::eval_context::EvalContext;
 use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
 
+/// Replaces all matches of the regex `old_value` with `new_value` in a string `value`.
+///
+/// A quoted string `old_value` is compiled as a regex, like a regex literal (`/.../`), and
+/// `new_value` can reference capture groups (`$1`, `${name}`).
 pub fn eval_replace(
     value: &Value,
     variables: &VariableSet,
+    eval_context: &mut EvalContext,
     source_info: SourceInfo,
     assert: bool,
     old_value: &RegexValue,
@@ -31,7 +36,15 @@ pub fn eval_replace(
 ) -> Result<Option<Value>, RunnerError> {
     match value {
         Value::String(v) => {
-            let re = eval_regex_value(old_value, variables)?;
+            let re = match old_value {
+                RegexValue::Template(t) => {
+                    let old_value = eval_template(t, variables)?;
+                    eval_context
+                        .new_regex(&old_value)
+                        .map_err(|kind| RunnerError::new(source_info, kind, assert))?
+                }
+                RegexValue::Regex(re) => re.inner.clone(),
+            };
             let new_value = eval_template(new_value, variables)?;
             let s = re.replace_all(v, new_value).to_string();
             Ok(Some(Value::String(s)))
@@ -43,16 +56,42 @@ pub fn eval_replace(
     }
 }
 
+/// Replaces all occurrences of `old_value` with `new_value` in a string `value`.
+///
+/// Contrary to [`eval_replace`], `old_value` is matched literally and `new_value` is inserted as
+/// is.
+pub fn eval_replace_literal(
+    value: &Value,
+    variables: &VariableSet,
+    source_info: SourceInfo,
+    assert: bool,
+    old_value: &Template,
+    new_value: &Template,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(v) => {
+            let old_value = eval_template(old_value, variables)?;
+            let new_value = eval_template(new_value, variables)?;
+            Ok(Some(Value::String(v.replace(&old_value, &new_value))))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v.display());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
 
     use hurl_core::ast::{
-        Filter, FilterValue, RegexValue, SourceInfo, Template, TemplateElement, Whitespace,
+        Filter, FilterValue, Regex, RegexValue, SourceInfo, Template, TemplateElement, Whitespace,
     };
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
-    use crate::runner::{Value, VariableSet};
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
 
     #[test]
     pub fn eval_filter_replace() {
@@ -92,6 +131,7 @@ pub mod tests {
                 &filter,
                 &Value::String("1 2\t3  4".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -99,4 +139,265 @@ pub mod tests {
             Value::String("1,2,3,4".to_string())
         );
     }
+
+    #[test]
+    pub fn eval_filter_replace_capture_groups() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Replace {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                old_value: RegexValue::Template(Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "^v(\\d+)".to_string(),
+                        encoded: "^v(\\d+)".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                }),
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                new_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "version $1".to_string(),
+                        encoded: "version $1".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("v42".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("version 42".to_string())
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Replace {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                old_value: RegexValue::Regex(Regex {
+                    inner: regex::Regex::new(r"(\w+)@(\w+)").unwrap(),
+                }),
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                new_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$2 at $1".to_string(),
+                        encoded: "$2 at $1".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("bob@example".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("example at bob".to_string())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_replace_literal() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ReplaceLiteral {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                old_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "http://".to_string(),
+                        encoded: "http://".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                new_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "https://".to_string(),
+                        encoded: "https://".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("http://foo.com http://bar.com".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("https://foo.com https://bar.com".to_string())
+        );
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ReplaceLiteral {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                old_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "a.b".to_string(),
+                        encoded: "a.b".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                new_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$1".to_string(),
+                        encoded: "$1".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("a.b axb a.b".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("$1 axb $1".to_string())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_replace_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::Replace {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                old_value: RegexValue::Template(Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "[".to_string(),
+                        encoded: "[".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                }),
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                new_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: String::new(),
+                        encoded: String::new(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::String("foo".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1))
+        );
+        assert_eq!(error.kind, RunnerErrorKind::InvalidRegex);
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::ReplaceLiteral {
+                space0: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                old_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: ",".to_string(),
+                        encoded: ",".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                space1: Whitespace {
+                    value: String::new(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                new_value: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "|".to_string(),
+                        encoded: "|".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("bool <true>".to_string())
+        );
+    }
 }
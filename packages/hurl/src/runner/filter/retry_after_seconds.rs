@@ -0,0 +1,153 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use chrono::{DateTime, Utc};
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Converts the value of a `Retry-After` header to a number of seconds to wait.
+///
+/// The header value can be a number of seconds (`120`) or an HTTP date
+/// (`Wed, 21 Oct 2015 07:28:00 GMT`), in which case the delay is computed from now. A date in the
+/// past gives a delay of 0 seconds.
+pub fn eval_retry_after_seconds(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(s) => match retry_after_seconds(s.trim(), Utc::now()) {
+            Some(seconds) => Ok(Some(Value::Number(Number::Integer(seconds)))),
+            None => {
+                let kind = RunnerErrorKind::FilterInvalidInput(value.display());
+                Err(RunnerError::new(source_info, kind, assert))
+            }
+        },
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the number of seconds to wait for a `Retry-After` header `value`, relatively to `now`.
+fn retry_after_seconds(value: &str, now: DateTime<Utc>) -> Option<i64> {
+    if !value.is_empty() && value.chars().all(|c| c.is_ascii_digit()) {
+        return value.parse::<i64>().ok();
+    }
+    // The preferred HTTP date format (IMF-fixdate) is compatible with RFC 2822.
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    let seconds = date.signed_duration_since(now).num_seconds();
+    Some(seconds.max(0))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use chrono::{Duration, TimeZone};
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::VariableSet;
+
+    #[test]
+    pub fn eval_filter_retry_after_seconds() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::RetryAfterSeconds,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("120".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(120))
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(0))
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("soon".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("string <soon>".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn test_retry_after_seconds() {
+        let now = Utc.with_ymd_and_hms(2015, 10, 21, 7, 28, 0).unwrap();
+        assert_eq!(retry_after_seconds("0", now), Some(0));
+        assert_eq!(retry_after_seconds("3600", now), Some(3600));
+        assert_eq!(
+            retry_after_seconds("Wed, 21 Oct 2015 07:30:30 GMT", now),
+            Some(150)
+        );
+        assert_eq!(
+            retry_after_seconds("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(0)
+        );
+
+        let date = now + Duration::try_days(1).unwrap();
+        assert_eq!(retry_after_seconds(&date.to_rfc2822(), now), Some(86400));
+
+        assert_eq!(retry_after_seconds("", now), None);
+        assert_eq!(retry_after_seconds("-5", now), None);
+        assert_eq!(retry_after_seconds("2015-10-21", now), None);
+    }
+}
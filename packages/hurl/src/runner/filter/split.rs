@@ -49,6 +49,7 @@ pub mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -78,6 +79,7 @@ pub mod tests {
                 &filter,
                 &Value::String("1,2,3".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -0,0 +1,119 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts a string `value` to title case.
+///
+/// The first letter of each word is uppercased, and the rest of the word is lowercased.
+pub fn eval_title_case(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(value) => Ok(Some(Value::String(title_case(value)))),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the title case version of `s`.
+///
+/// A word is a sequence of alphanumeric characters. Apostrophes don't break words, so that
+/// `"don't"` is converted to `"Don't"`.
+fn title_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut in_word = false;
+    for c in s.chars() {
+        if c.is_alphanumeric() {
+            if in_word {
+                result.extend(c.to_lowercase());
+            } else {
+                result.extend(c.to_uppercase());
+            }
+            in_word = true;
+        } else {
+            if c != '\'' && c != '’' {
+                in_word = false;
+            }
+            result.push(c);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use super::title_case;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_title_case() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::TitleCase,
+        };
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("hello world".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Hello World".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+
+    #[test]
+    fn test_title_case() {
+        assert_eq!(title_case(""), "");
+        assert_eq!(title_case("hello world"), "Hello World");
+        assert_eq!(title_case("hELLO wORLD"), "Hello World");
+        assert_eq!(title_case("jean-luc  picard"), "Jean-Luc  Picard");
+        assert_eq!(title_case("don't stop"), "Don't Stop");
+        assert_eq!(title_case("élodie ÉTÉ"), "Élodie Été");
+        assert_eq!(title_case("route 66th"), "Route 66th");
+    }
+}
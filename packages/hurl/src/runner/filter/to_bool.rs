@@ -0,0 +1,145 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value};
+
+/// Strings converted to `true` (case-insensitive).
+const TRUTHY: [&str; 4] = ["true", "1", "yes", "on"];
+
+/// Strings converted to `false` (case-insensitive).
+const FALSY: [&str; 4] = ["false", "0", "no", "off"];
+
+/// Converts `value` to a boolean.
+///
+/// Strings in [`TRUTHY`] and [`FALSY`] are converted (ignoring case and surrounding whitespaces),
+/// as well as numbers `1` and `0`. Any other value is an error.
+pub fn eval_to_bool(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let b = match value {
+        Value::Bool(b) => Some(*b),
+        Value::String(s) => {
+            let s = s.trim().to_lowercase();
+            if TRUTHY.contains(&s.as_str()) {
+                Some(true)
+            } else if FALSY.contains(&s.as_str()) {
+                Some(false)
+            } else {
+                None
+            }
+        }
+        Value::Number(Number::Integer(1)) => Some(true),
+        Value::Number(Number::Integer(0)) => Some(false),
+        Value::Number(Number::Float(f)) if *f == 1.0 => Some(true),
+        Value::Number(Number::Float(f)) if *f == 0.0 => Some(false),
+        _ => None,
+    };
+    match b {
+        Some(b) => Ok(Some(Value::Bool(b))),
+        None => {
+            let kind = RunnerErrorKind::FilterInvalidInput(value.display());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_to_bool() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: FilterValue::ToBool,
+        };
+
+        let tests = [
+            (Value::String("true".to_string()), true),
+            (Value::String("1".to_string()), true),
+            (Value::String("Yes".to_string()), true),
+            (Value::String(" ON ".to_string()), true),
+            (Value::String("false".to_string()), false),
+            (Value::String("0".to_string()), false),
+            (Value::String("no".to_string()), false),
+            (Value::String("Off".to_string()), false),
+            (Value::Number(Number::Integer(1)), true),
+            (Value::Number(Number::Integer(0)), false),
+            (Value::Number(Number::Float(0.0)), false),
+            (Value::Bool(true), true),
+        ];
+        for (input, output) in tests.iter() {
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    input,
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::Bool(*output)
+            );
+        }
+    }
+
+    #[test]
+    pub fn eval_filter_to_bool_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 7)),
+            value: FilterValue::ToBool,
+        };
+        let err = eval_filter(
+            &filter,
+            &Value::String("maybe".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("string <maybe>".to_string())
+        );
+        let err = eval_filter(
+            &filter,
+            &Value::Number(Number::Integer(2)),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.kind,
+            RunnerErrorKind::FilterInvalidInput("int <2>".to_string())
+        );
+    }
+}
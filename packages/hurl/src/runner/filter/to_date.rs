@@ -54,6 +54,7 @@ pub mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -89,6 +90,7 @@ pub mod tests {
                 &filter,
                 &Value::String("1983 Apr 13 12:09:14.274 +0000".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -124,6 +126,7 @@ pub mod tests {
                 &filter,
                 &Value::String("Wed, 21 Oct 2015 07:28:00 GMT".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -47,6 +47,7 @@ pub mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
@@ -62,6 +63,7 @@ pub mod tests {
                 &filter,
                 &Value::String("3.1415".to_string()),
                 &variable,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -73,6 +75,7 @@ pub mod tests {
                 &filter,
                 &Value::Number(Number::Float(3.1415)),
                 &variable,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -84,6 +87,7 @@ pub mod tests {
                 &filter,
                 &Value::Number(Number::Float(3.0)),
                 &variable,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -95,6 +99,7 @@ pub mod tests {
                 &filter,
                 &Value::Number(Number::Integer(3)),
                 &variable,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -114,6 +119,7 @@ pub mod tests {
             &filter,
             &Value::String("3x.1415".to_string()),
             &variables,
+            &mut EvalContext::default(),
             false,
         )
         .err()
@@ -122,9 +128,15 @@ pub mod tests {
             err.kind,
             RunnerErrorKind::FilterInvalidInput("string <3x.1415>".to_string())
         );
-        let err = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let err = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             err.kind,
             RunnerErrorKind::FilterInvalidInput("bool <true>".to_string())
@@ -48,6 +48,7 @@ pub mod tests {
     use hurl_core::ast::{Filter, FilterValue, SourceInfo};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Number, RunnerErrorKind, Value, VariableSet};
 
@@ -63,6 +64,7 @@ pub mod tests {
                 &filter,
                 &Value::String("123".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -74,6 +76,7 @@ pub mod tests {
                 &filter,
                 &Value::Number(Number::Integer(123)),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -85,6 +88,7 @@ pub mod tests {
                 &filter,
                 &Value::Number(Number::Float(1.6)),
                 &variables,
+                &mut EvalContext::default(),
                 false
             )
             .unwrap()
@@ -104,6 +108,7 @@ pub mod tests {
             &filter,
             &Value::String("123x".to_string()),
             &variables,
+            &mut EvalContext::default(),
             false,
         )
         .err()
@@ -112,9 +117,15 @@ pub mod tests {
             err.kind,
             RunnerErrorKind::FilterInvalidInput("string <123x>".to_string())
         );
-        let err = eval_filter(&filter, &Value::Bool(true), &variables, false)
-            .err()
-            .unwrap();
+        let err = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             err.kind,
             RunnerErrorKind::FilterInvalidInput("bool <true>".to_string())
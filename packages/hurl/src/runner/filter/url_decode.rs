@@ -15,19 +15,32 @@
  * limitations under the License.
  *
  */
-
 use hurl_core::ast::SourceInfo;
 
 use crate::runner::{RunnerError, RunnerErrorKind, Value};
 
+/// Decodes a percent-encoded string `value`.
+///
+/// In `form` mode, "+" is decoded as a space, as in `application/x-www-form-urlencoded` data.
+/// A "%" not followed by two hexadecimal digits is an error.
 pub fn eval_url_decode(
     value: &Value,
+    mode: Option<&str>,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match value {
         Value::String(value) => {
-            match percent_encoding::percent_decode(value.as_bytes()).decode_utf8() {
+            if let Some(offset) = invalid_percent_encoding(value) {
+                let kind = RunnerErrorKind::FilterUrlDecode { offset };
+                return Err(RunnerError::new(source_info, kind, assert));
+            }
+            let bytes = if mode == Some("form") {
+                value.replace('+', " ").into_bytes()
+            } else {
+                value.clone().into_bytes()
+            };
+            match percent_encoding::percent_decode(&bytes).decode_utf8() {
                 Ok(decoded) => Ok(Some(Value::String(decoded.to_string()))),
                 Err(_) => {
                     let kind =
@@ -43,5 +56,120 @@ pub fn eval_url_decode(
     }
 }
 
+/// Returns the byte offset of the first "%" of `value` that is not followed by two hexadecimal
+/// digits, if any.
+fn invalid_percent_encoding(value: &str) -> Option<usize> {
+    let bytes = value.as_bytes();
+    bytes.iter().enumerate().find_map(|(offset, b)| {
+        let valid = *b != b'%'
+            || bytes
+                .get(offset + 1..offset + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+        (!valid).then_some(offset)
+    })
+}
+
 #[cfg(test)]
-pub mod tests {}
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use super::invalid_percent_encoding;
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_url_decode_mode() {
+        let variables = VariableSet::new();
+        let value = Value::String("a+b%20c%2Bd".to_string());
+        let tests = [
+            (None, "a+b c+d"),
+            (Some("component"), "a+b c+d"),
+            (Some("form"), "a b c+d"),
+        ];
+        for (mode, output) in tests {
+            let filter = Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+                value: FilterValue::UrlDecode {
+                    mode: mode.map(|mode| {
+                        (
+                            Whitespace {
+                                value: " ".to_string(),
+                                source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                            },
+                            mode.to_string(),
+                        )
+                    }),
+                },
+            };
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &value,
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
+
+    #[test]
+    pub fn eval_filter_url_decode_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::UrlDecode {
+                mode: Some((
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                    },
+                    "form".to_string(),
+                )),
+            },
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::String("a=100%+b=%zz".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10))
+        );
+        assert_eq!(error.kind, RunnerErrorKind::FilterUrlDecode { offset: 5 });
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("%FF".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("Invalid UTF-8 stream".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_percent_encoding() {
+        assert_eq!(invalid_percent_encoding(""), None);
+        assert_eq!(invalid_percent_encoding("%2F%2f"), None);
+        assert_eq!(invalid_percent_encoding("%"), Some(0));
+        assert_eq!(invalid_percent_encoding("ab%2"), Some(2));
+        assert_eq!(invalid_percent_encoding("%20%G0"), Some(3));
+        assert_eq!(invalid_percent_encoding("é%"), Some(2));
+    }
+}
@@ -15,28 +15,40 @@
  * limitations under the License.
  *
  */
-
 use hurl_core::ast::SourceInfo;
 use percent_encoding::AsciiSet;
 
 use crate::runner::{RunnerError, RunnerErrorKind, Value};
 
-// does not encode "/"
-// like Jinja template (https://jinja.palletsprojects.com/en/3.1.x/templates/#jinja-filters.urlencode)
+/// Characters encoded by default: all characters except unreserved chars and "/",
+/// like Jinja template (https://jinja.palletsprojects.com/en/3.1.x/templates/#jinja-filters.urlencode)
+const FRAGMENT: &AsciiSet = &COMPONENT.remove(b'/');
+
+/// Characters encoded in `component` mode: all characters except unreserved chars (see RFC3986).
+const COMPONENT: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent-encodes a string `value`.
+///
+/// Without `mode`, unreserved chars and "/" are not encoded. In `component` mode, only
+/// unreserved chars are not encoded. In `form` mode, the string is encoded as
+/// `application/x-www-form-urlencoded` data, spaces being encoded as "+".
 pub fn eval_url_encode(
     value: &Value,
+    mode: Option<&str>,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match value {
         Value::String(value) => {
-            const FRAGMENT: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
-                .remove(b'-')
-                .remove(b'.')
-                .remove(b'_')
-                .remove(b'~')
-                .remove(b'/');
-            let encoded = percent_encoding::percent_encode(value.as_bytes(), FRAGMENT).to_string();
+            let encoded = match mode {
+                Some("form") => url::form_urlencoded::byte_serialize(value.as_bytes()).collect(),
+                Some(_) => percent_encoding::utf8_percent_encode(value, COMPONENT).to_string(),
+                None => percent_encoding::utf8_percent_encode(value, FRAGMENT).to_string(),
+            };
             Ok(Some(Value::String(encoded)))
         }
         v => {
@@ -48,9 +60,10 @@ pub fn eval_url_encode(
 
 #[cfg(test)]
 pub mod tests {
-    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Whitespace};
     use hurl_core::reader::Pos;
 
+    use crate::runner::eval_context::EvalContext;
     use crate::runner::filter::eval::eval_filter;
     use crate::runner::{Value, VariableSet};
 
@@ -59,13 +72,14 @@ pub mod tests {
         let variables = VariableSet::new();
         let filter = Filter {
             source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-            value: FilterValue::UrlEncode,
+            value: FilterValue::UrlEncode { mode: None },
         };
         assert_eq!(
             eval_filter(
                 &filter,
                 &Value::String("https://mozilla.org/?x=шеллы".to_string()),
                 &variables,
+                &mut EvalContext::default(),
                 false,
             )
             .unwrap()
@@ -75,4 +89,37 @@ pub mod tests {
             )
         );
     }
+
+    #[test]
+    pub fn eval_filter_url_encode_mode() {
+        let variables = VariableSet::new();
+        let whitespace = Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let tests = [
+            ("component", "a%20b%2Bc%2Fd~e%26f%3Dg%C3%A9"),
+            ("form", "a+b%2Bc%2Fd%7Ee%26f%3Dg%C3%A9"),
+        ];
+        for (mode, output) in tests {
+            let filter = Filter {
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                value: FilterValue::UrlEncode {
+                    mode: Some((whitespace.clone(), mode.to_string())),
+                },
+            };
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String("a b+c/d~e&f=gé".to_string()),
+                    &variables,
+                    &mut EvalContext::default(),
+                    false,
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
 }
@@ -0,0 +1,195 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+use url::Url;
+
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Returns the host of an URL string `value`.
+pub fn eval_url_host(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let url = parse_url(value, source_info, assert)?;
+    match url.host_str() {
+        Some(host) => Ok(Some(Value::String(host.to_string()))),
+        None => {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!("URL <{url}> has no host"));
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Returns the path of an URL string `value`.
+pub fn eval_url_path(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let url = parse_url(value, source_info, assert)?;
+    Ok(Some(Value::String(url.path().to_string())))
+}
+
+/// Returns the query of an URL string `value`, without the leading `?`.
+///
+/// An URL without query returns an empty string.
+pub fn eval_url_query(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let url = parse_url(value, source_info, assert)?;
+    let query = url.query().unwrap_or_default();
+    Ok(Some(Value::String(query.to_string())))
+}
+
+/// Returns the scheme of an URL string `value`.
+pub fn eval_url_scheme(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let url = parse_url(value, source_info, assert)?;
+    Ok(Some(Value::String(url.scheme().to_string())))
+}
+
+/// Parses a string `value` as an absolute URL.
+fn parse_url(value: &Value, source_info: SourceInfo, assert: bool) -> Result<Url, RunnerError> {
+    match value {
+        Value::String(s) => Url::parse(s).map_err(|_| {
+            let kind = RunnerErrorKind::FilterInvalidInput(format!("invalid URL <{s}>"));
+            RunnerError::new(source_info, kind, assert)
+        }),
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    fn eval_filter_url_part() {
+        let variables = VariableSet::new();
+        let tests = [
+            (
+                FilterValue::UrlHost,
+                "https://example.com/a?b=1",
+                "example.com",
+            ),
+            (FilterValue::UrlPath, "https://example.com/a?b=1", "/a"),
+            (FilterValue::UrlQuery, "https://example.com/a?b=1", "b=1"),
+            (FilterValue::UrlScheme, "https://example.com/a?b=1", "https"),
+            (
+                FilterValue::UrlHost,
+                "HTTP://Example.COM:8080",
+                "example.com",
+            ),
+            (FilterValue::UrlPath, "HTTP://Example.COM:8080", "/"),
+            (FilterValue::UrlQuery, "HTTP://Example.COM:8080", ""),
+            (FilterValue::UrlScheme, "HTTP://Example.COM:8080", "http"),
+        ];
+        for (value, url, output) in tests {
+            let filter = Filter {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+                value,
+            };
+            assert_eq!(
+                eval_filter(
+                    &filter,
+                    &Value::String(url.to_string()),
+                    &variables,
+                    &mut EvalContext::default(),
+                    false
+                )
+                .unwrap()
+                .unwrap(),
+                Value::String(output.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn eval_filter_url_part_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+            value: FilterValue::UrlHost,
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::String("/a?b=1".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("invalid URL </a?b=1>".to_string())
+        );
+
+        let error = eval_filter(
+            &filter,
+            &Value::String("mailto:bob@example.com".to_string()),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput(
+                "URL <mailto:bob@example.com> has no host".to_string()
+            )
+        );
+
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 8)),
+            value: FilterValue::UrlPath,
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
@@ -0,0 +1,117 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::{RunnerError, Value};
+
+/// Returns the type name of `value` as a string.
+///
+/// All numbers (integer, float and big integer) are reported as `number`, other values use
+/// their runtime type name (`string`, `boolean`, `list`, `object`, `null` etc...).
+pub fn eval_type(
+    value: &Value,
+    _source_info: SourceInfo,
+    _assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    let name = match value {
+        Value::Number(_) => "number".to_string(),
+        v => v._type(),
+    };
+    Ok(Some(Value::String(name)))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::{Number, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_type() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 5)),
+            value: FilterValue::Type,
+        };
+
+        // jsonpath "$.id" type == "number"
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Integer(42)),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("number".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Number(Number::Float(1.5)),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("number".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::String("Dune".to_string()),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("string".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::List(vec![]),
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("list".to_string())
+        );
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &Value::Null,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("null".to_string())
+        );
+    }
+}
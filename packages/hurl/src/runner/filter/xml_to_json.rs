@@ -0,0 +1,148 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::runner::xpath::{Document, Format};
+use crate::runner::{RunnerError, RunnerErrorKind, Value};
+
+/// Converts a XML string `value` to an object, see [`Document::to_value`] for the conversion
+/// convention.
+pub fn eval_xml_to_json(
+    value: &Value,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(xml) => {
+            let Ok(doc) = Document::parse(xml, Format::Xml) else {
+                return Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::QueryInvalidXml,
+                    false,
+                ));
+            };
+            Ok(Some(doc.to_value()))
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use hurl_core::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use crate::runner::eval_context::EvalContext;
+    use crate::runner::filter::eval::{eval_filter, eval_filters};
+    use crate::runner::{RunnerErrorKind, Value, VariableSet};
+
+    #[test]
+    pub fn eval_filter_xml_to_json() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::XmlToJson,
+        };
+        let xml = Value::String(r#"<a x="1">hi</a>"#.to_string());
+
+        assert_eq!(
+            eval_filter(
+                &filter,
+                &xml,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Object(vec![(
+                "a".to_string(),
+                Value::Object(vec![
+                    ("@x".to_string(), Value::String("1".to_string())),
+                    ("#text".to_string(), Value::String("hi".to_string())),
+                ])
+            )])
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_xml_to_json_jsonpath() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::XmlToJson,
+        };
+        let xml = Value::String(r#"<a x="1">hi</a>"#.to_string());
+        let jsonpath = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 29)),
+            value: FilterValue::JsonPath {
+                expected_type: None,
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 19), Pos::new(1, 20)),
+                },
+                expr: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "$.a['@x']".to_string(),
+                        encoded: "$.a['@x']".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 20), Pos::new(1, 29)),
+                },
+            },
+        };
+
+        assert_eq!(
+            eval_filters(
+                &[filter, jsonpath],
+                &xml,
+                &variables,
+                &mut EvalContext::default(),
+                false
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("1".to_string())
+        );
+    }
+
+    #[test]
+    pub fn eval_filter_xml_to_json_error() {
+        let variables = VariableSet::new();
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 10)),
+            value: FilterValue::XmlToJson,
+        };
+        let error = eval_filter(
+            &filter,
+            &Value::Bool(true),
+            &variables,
+            &mut EvalContext::default(),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+    }
+}
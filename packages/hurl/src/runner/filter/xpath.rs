@@ -15,30 +15,123 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{SourceInfo, Template};
+use hurl_core::ast::{JsonValue, SourceInfo, Template};
 
+use crate::runner::eval_context::EvalContext;
 use crate::runner::template::eval_template;
 use crate::runner::xpath::{Document, Format, XPathError};
-use crate::runner::{RunnerError, RunnerErrorKind, Value, VariableSet};
+use crate::runner::{Number, RunnerError, RunnerErrorKind, Value, VariableSet};
 
 pub fn eval_xpath(
     value: &Value,
     expr: &Template,
+    namespaces: Option<&JsonValue>,
     variables: &VariableSet,
+    eval_context: &EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match namespaces {
+        // Namespace bindings are only meaningful for XML documents.
+        Some(namespaces) => {
+            let namespaces = eval_namespaces(namespaces, variables)?;
+            eval_xpath_format(
+                value,
+                expr,
+                Format::Xml,
+                &namespaces,
+                variables,
+                eval_context,
+                source_info,
+                assert,
+            )
+        }
+        // The filter will use the HTML parser that should also work with XML input
+        None => eval_xpath_format(
+            value,
+            expr,
+            Format::Html,
+            &[],
+            variables,
+            eval_context,
+            source_info,
+            assert,
+        ),
+    }
+}
+
+/// Evaluates the namespace bindings `namespaces` of a `xpath` filter to a list of
+/// `(prefix, href)`.
+fn eval_namespaces(
+    namespaces: &JsonValue,
+    variables: &VariableSet,
+) -> Result<Vec<(String, String)>, RunnerError> {
+    let JsonValue::Object { elements, .. } = namespaces else {
+        return Ok(vec![]);
+    };
+    let mut bindings = vec![];
+    for element in elements {
+        // The parser only accepts strings as namespace URIs.
+        if let JsonValue::String(href) = &element.value {
+            let prefix = eval_template(&element.name, variables)?;
+            let href = eval_template(href, variables)?;
+            bindings.push((prefix, href));
+        }
+    }
+    Ok(bindings)
+}
+
+/// Evaluates the XPath expression `expr` against `value`, parsed as a strict XML document.
+///
+/// Contrary to [`eval_xpath`], tag names are case-sensitive and the namespaces declared in the
+/// document can be used in the expression.
+pub fn eval_xpath_xml(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    eval_context: &EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    eval_xpath_format(
+        value,
+        expr,
+        Format::Xml,
+        &[],
+        variables,
+        eval_context,
+        source_info,
+        assert,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn eval_xpath_format(
+    value: &Value,
+    expr: &Template,
+    format: Format,
+    namespaces: &[(String, String)],
+    variables: &VariableSet,
+    eval_context: &EvalContext,
     source_info: SourceInfo,
     assert: bool,
 ) -> Result<Option<Value>, RunnerError> {
     match value {
         Value::String(xml) => {
-            // The filter will use the HTML parser that should also work with XML input
-            let Ok(doc) = Document::parse(xml, Format::Html) else {
+            let Ok(doc) = Document::parse(xml, format) else {
                 return Err(RunnerError::new(
                     source_info,
                     RunnerErrorKind::QueryInvalidXml,
                     false,
                 ));
             };
-            eval_xpath_doc(&doc, expr, variables)
+            let expr_str = eval_template(expr, variables)?;
+            let result = doc.eval_xpath_with_namespaces(
+                &expr_str,
+                namespaces,
+                eval_context.xpath_op_limit(),
+            );
+            xpath_result_to_value(result, &expr_str, expr.source_info)
         }
         v => {
             let kind = RunnerErrorKind::FilterInvalidInput(v._type());
@@ -47,22 +140,538 @@ pub fn eval_xpath(
     }
 }
 
+/// Evaluates the XPath expression `expr` against a parsed document `doc`.
 pub fn eval_xpath_doc(
     doc: &Document,
     expr: &Template,
     variables: &VariableSet,
+    eval_context: &EvalContext,
 ) -> Result<Option<Value>, RunnerError> {
     let expr_str = eval_template(expr, variables)?;
-    let result = doc.eval_xpath(&expr_str);
+    let result = doc.eval_xpath(&expr_str, eval_context.xpath_op_limit());
+    xpath_result_to_value(result, &expr_str, expr.source_info)
+}
+
+/// Converts the `result` of the evaluation of the XPath expression `expr_str` to a value.
+fn xpath_result_to_value(
+    result: Result<Value, XPathError>,
+    expr_str: &str,
+    source_info: SourceInfo,
+) -> Result<Option<Value>, RunnerError> {
     match result {
+        Ok(value) if is_count_expr(expr_str) => Ok(Some(count_to_integer(value))),
         Ok(value) => Ok(Some(value)),
-        Err(XPathError::Eval) => Err(RunnerError::new(
-            expr.source_info,
-            RunnerErrorKind::QueryInvalidXpathEval,
-            false,
-        )),
-        Err(XPathError::Unsupported) => {
-            panic!("Unsupported xpath {expr}"); // good usecase for panic - I could not reproduce this usecase myself
+        Err(error) => Err(xpath_error(error, expr_str, source_info)),
+    }
+}
+
+/// Converts an `error` of the evaluation of the XPath expression `expr_str` to a runner error.
+///
+/// An XPath result type that is not supported (other than number, boolean, string and node-set,
+/// or other than node-set when nodes are expected) is reported as a
+/// [`RunnerErrorKind::QueryUnsupportedXpathResult`] error.
+fn xpath_error(error: XPathError, expr_str: &str, source_info: SourceInfo) -> RunnerError {
+    let kind = match error {
+        XPathError::Eval => RunnerErrorKind::QueryInvalidXpathEval {
+            unbound_prefix: None,
+        },
+        XPathError::UnboundPrefix(prefix) => RunnerErrorKind::QueryInvalidXpathEval {
+            unbound_prefix: Some(prefix),
+        },
+        XPathError::Unsupported => RunnerErrorKind::QueryUnsupportedXpathResult {
+            expr: expr_str.to_string(),
+        },
+        XPathError::OpLimit { op_limit } => RunnerErrorKind::EvalOpLimit {
+            expr: expr_str.to_string(),
+            op_limit,
+        },
+    };
+    RunnerError::new(source_info, kind, false)
+}
+
+/// Returns `true` if the XPath expression `expr` is a call to the `count()` function.
+///
+/// The parenthesis opened by `count(` must be closed at the end of `expr`: a compound expression
+/// like `count(//a) div count(//b)` is not a count.
+fn is_count_expr(expr: &str) -> bool {
+    let Some(args) = expr.trim().strip_prefix("count(") else {
+        return false;
+    };
+    let mut depth = 1;
+    let mut quote = None;
+    for (i, c) in args.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+                if depth == 0 {
+                    return i == args.len() - 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// XPath numbers are always floats: converts the result of a `count()` to an integer, so that it
+/// can be compared and displayed as such.
+fn count_to_integer(value: Value) -> Value {
+    match value {
+        Value::Number(Number::Float(f)) if f.fract() == 0.0 && f.abs() < i64::MAX as f64 => {
+            Value::Number(Number::Integer(f as i64))
+        }
+        v => v,
+    }
+}
+
+/// Evaluates the XPath expression `expr` against `value`, and returns the attribute `attr` of the
+/// first selected element. Returns `None` if there is no such element or attribute.
+pub fn eval_xpath_attr(
+    value: &Value,
+    expr: &Template,
+    attr: &Template,
+    variables: &VariableSet,
+    eval_context: &EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(xml) => {
+            // The filter will use the HTML parser that should also work with XML input
+            let Ok(doc) = Document::parse(xml, Format::Html) else {
+                return Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::QueryInvalidXml,
+                    false,
+                ));
+            };
+            let expr_str = eval_template(expr, variables)?;
+            let attr_str = eval_template(attr, variables)?;
+            let result = doc.eval_xpath_attr(&expr_str, &attr_str, eval_context.xpath_op_limit());
+            match result {
+                Ok(value) => Ok(value.map(Value::String)),
+                Err(error) => Err(xpath_error(error, &expr_str, expr.source_info)),
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+/// Evaluates the XPath expression `expr` against `value`, and returns the markup of the first
+/// selected node, including the node itself. Returns `None` if there is no such node.
+pub fn eval_xpath_outer_html(
+    value: &Value,
+    expr: &Template,
+    variables: &VariableSet,
+    eval_context: &EvalContext,
+    source_info: SourceInfo,
+    assert: bool,
+) -> Result<Option<Value>, RunnerError> {
+    match value {
+        Value::String(xml) => {
+            // The filter will use the HTML parser that should also work with XML input
+            let Ok(doc) = Document::parse(xml, Format::Html) else {
+                return Err(RunnerError::new(
+                    source_info,
+                    RunnerErrorKind::QueryInvalidXml,
+                    false,
+                ));
+            };
+            let expr_str = eval_template(expr, variables)?;
+            let result = doc.eval_xpath_outer_html(&expr_str, eval_context.xpath_op_limit());
+            match result {
+                Ok(value) => Ok(value.map(Value::String)),
+                Err(error) => Err(xpath_error(error, &expr_str, expr.source_info)),
+            }
+        }
+        v => {
+            let kind = RunnerErrorKind::FilterInvalidInput(v._type());
+            Err(RunnerError::new(source_info, kind, assert))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use hurl_core::ast::{Filter, FilterValue, JsonObjectElement, TemplateElement, Whitespace};
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::runner::filter::eval::eval_filter;
+    use crate::runner::RunnerOptionsBuilder;
+
+    fn template(value: &str) -> Template {
+        Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: value.to_string(),
+                encoded: value.to_string(),
+            }],
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
         }
     }
+
+    #[test]
+    fn eval_filter_xpath_count() {
+        let variables = VariableSet::new();
+        let xml = "<users><user>Bob</user><user>Bill</user><user>Alice</user></users>";
+        let value = Value::String(xml.to_string());
+
+        assert_eq!(
+            eval_xpath(
+                &value,
+                &template("count(//user)"),
+                None,
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(3))
+        );
+        assert_eq!(
+            eval_xpath(
+                &value,
+                &template("count(//admin)"),
+                None,
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(0))
+        );
+        // Other numeric XPath expressions are kept as floats.
+        assert_eq!(
+            eval_xpath(
+                &value,
+                &template("count(//user) div 2"),
+                None,
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(1.5))
+        );
+        assert_eq!(
+            eval_xpath(
+                &value,
+                &template("count(//user) div count(//user)"),
+                None,
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Float(1.0))
+        );
+        assert_eq!(
+            eval_xpath(
+                &value,
+                &template("count(//user[text() = ')'])"),
+                None,
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(0))
+        );
+    }
+
+    #[test]
+    fn eval_filter_xpath_unsupported() {
+        let source_info = SourceInfo::new(Pos::new(1, 7), Pos::new(1, 20));
+        let error =
+            xpath_result_to_value(Err(XPathError::Unsupported), "//user", source_info).unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryUnsupportedXpathResult {
+                expr: "//user".to_string()
+            }
+        );
+        assert_eq!(error.source_info, source_info);
+    }
+
+    #[test]
+    fn eval_filter_xpath_xml() {
+        let variables = VariableSet::new();
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<soap:Envelope xmlns:soap="http://www.w3.org/2003/05/soap-envelope">
+  <soap:Body>
+    <GetPrice><Item>Apples</Item></GetPrice>
+  </soap:Body>
+</soap:Envelope>"#;
+        let value = Value::String(xml.to_string());
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+
+        assert_eq!(
+            eval_xpath_xml(
+                &value,
+                &template("string(//soap:Body/GetPrice/Item)"),
+                &variables,
+                &EvalContext::default(),
+                source_info,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Apples".to_string())
+        );
+        // Tag names are case-sensitive.
+        assert_eq!(
+            eval_xpath_xml(
+                &value,
+                &template("count(//getprice)"),
+                &variables,
+                &EvalContext::default(),
+                source_info,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(0))
+        );
+
+        let error = eval_xpath_xml(
+            &Value::String("??".to_string()),
+            &template("//a"),
+            &variables,
+            &EvalContext::default(),
+            source_info,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidXml);
+    }
+
+    #[test]
+    fn eval_filter_xpath_namespaces() {
+        let variables = VariableSet::new();
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry><title>Hurl 6.0.0</title></entry>
+  <entry><title>Hurl 5.0.1</title></entry>
+</feed>"#;
+        let value = Value::String(xml.to_string());
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1));
+        let namespaces = JsonValue::Object {
+            space0: String::new(),
+            elements: vec![JsonObjectElement {
+                space0: String::new(),
+                name: template("atom"),
+                space1: String::new(),
+                space2: " ".to_string(),
+                value: JsonValue::String(template("http://www.w3.org/2005/Atom")),
+                space3: String::new(),
+            }],
+        };
+
+        assert_eq!(
+            eval_xpath(
+                &value,
+                &template("count(//atom:entry)"),
+                Some(&namespaces),
+                &variables,
+                &EvalContext::default(),
+                source_info,
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Number(Number::Integer(2))
+        );
+
+        let error = eval_xpath(
+            &value,
+            &template("//atom:entry/dc:title"),
+            Some(&namespaces),
+            &variables,
+            &EvalContext::default(),
+            source_info,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidXpathEval {
+                unbound_prefix: Some("dc".to_string())
+            }
+        );
+        assert_eq!(error.source_info, source_info);
+    }
+
+    #[test]
+    fn eval_filter_xpath_attr() {
+        let variables = VariableSet::new();
+        let html = r#"<html><body><a href="https://hurl.dev">Hurl</a></body></html>"#;
+        let value = Value::String(html.to_string());
+
+        assert_eq!(
+            eval_xpath_attr(
+                &value,
+                &template("//a"),
+                &template("href"),
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("https://hurl.dev".to_string())
+        );
+        assert_eq!(
+            eval_xpath_attr(
+                &value,
+                &template("//a"),
+                &template("title"),
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_filter_xpath_attr_error() {
+        let variables = VariableSet::new();
+        let error = eval_xpath_attr(
+            &Value::Bool(true),
+            &template("//a"),
+            &template("href"),
+            &variables,
+            &EvalContext::default(),
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            false,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::FilterInvalidInput("boolean".to_string())
+        );
+
+        // A XPath expression that doesn't select nodes is reported with the expression.
+        let html = r#"<html><body><a href="https://hurl.dev">Hurl</a></body></html>"#;
+        let source_info = SourceInfo::new(Pos::new(1, 11), Pos::new(1, 23));
+        let mut expr = template("count(//a)");
+        expr.source_info = source_info;
+        let error = eval_xpath_attr(
+            &Value::String(html.to_string()),
+            &expr,
+            &template("href"),
+            &variables,
+            &EvalContext::default(),
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryUnsupportedXpathResult {
+                expr: "count(//a)".to_string()
+            }
+        );
+        assert_eq!(error.source_info, source_info);
+    }
+
+    #[test]
+    fn eval_filter_xpath_outer_html() {
+        let variables = VariableSet::new();
+        let html = r#"<p>Hello <b>world</b>!</p>"#;
+        let value = Value::String(html.to_string());
+
+        assert_eq!(
+            eval_xpath_outer_html(
+                &value,
+                &template("//b"),
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("<b>world</b>".to_string())
+        );
+        assert_eq!(
+            eval_xpath_outer_html(
+                &value,
+                &template("//i"),
+                &variables,
+                &EvalContext::default(),
+                SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                false,
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn eval_filter_xpath_op_limit() {
+        let variables = VariableSet::new();
+        let expr = "count(//*[count(//*[count(//*) > 0]) > 0])";
+        let filter = Filter {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+            value: FilterValue::XPath {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                expr: template(expr),
+                namespaces: None,
+            },
+        };
+        let value = Value::String(format!("<a>{}</a>", "<b/>".repeat(100)));
+
+        assert!(eval_filter(
+            &filter,
+            &value,
+            &variables,
+            &mut EvalContext::default(),
+            false
+        )
+        .is_ok());
+
+        let runner_options = RunnerOptionsBuilder::new().xpath_op_limit(10_000).build();
+        let error = eval_filter(
+            &filter,
+            &value,
+            &variables,
+            &mut EvalContext::new(&runner_options),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::EvalOpLimit {
+                expr: expr.to_string(),
+                op_limit: 10_000,
+            }
+        );
+    }
 }
@@ -16,15 +16,28 @@ use chrono::Utc;
  * limitations under the License.
  *
  */
-use hurl_core::ast::Function;
+use hurl_core::ast::{Function, SourceInfo};
 use uuid::Uuid;
 
-use crate::runner::error::RunnerError;
+use crate::runner::error::{RunnerError, RunnerErrorKind};
 use crate::runner::value::Value;
+use crate::runner::Number;
 
 /// Evaluates the function `function`, returns a [`Value`] on success or an [`RunnerError`] .
-pub fn eval(function: &Function) -> Result<Value, RunnerError> {
+///
+/// `source_info` is the source position of the function, used if an error is returned.
+pub fn eval(function: &Function, source_info: SourceInfo) -> Result<Value, RunnerError> {
     match &function {
+        Function::GetEnv { name, default, .. } => match std::env::var(name) {
+            Ok(value) => Ok(Value::String(value)),
+            Err(_) => match default {
+                Some((_, default)) => Ok(Value::String(default.clone())),
+                None => {
+                    let kind = RunnerErrorKind::EnvVariableNotDefined { name: name.clone() };
+                    Err(RunnerError::new(source_info, kind, false))
+                }
+            },
+        },
         Function::NewDate => {
             let now = Utc::now();
             Ok(Value::Date(now))
@@ -33,5 +46,112 @@ pub fn eval(function: &Function) -> Result<Value, RunnerError> {
             let uuid = Uuid::new_v4();
             Ok(Value::String(uuid.to_string()))
         }
+        Function::RandomInt { min, max, .. } => {
+            // UUID v4 are generated from a random number generator, we use them as a source of
+            // random bits to avoid another dependency.
+            let random = random_bits(Uuid::new_v4());
+            let width = (*max as i128 - *min as i128 + 1) as u128;
+            let value = *min as i128 + (random % width) as i128;
+            Ok(Value::Number(Number::Integer(value as i64)))
+        }
+    }
+}
+
+/// Returns the 122 random bits of a UUID v4 `uuid`, without its fixed version and variant bits.
+fn random_bits(uuid: Uuid) -> u128 {
+    let uuid = uuid.as_u128();
+    // The version is stored in bits 76 to 79 and the variant in bits 62 and 63.
+    let high = uuid >> 80;
+    let middle = (uuid >> 64) & 0xfff;
+    let low = uuid & ((1 << 62) - 1);
+    (high << 74) | (middle << 62) | low
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::ast::Whitespace;
+    use hurl_core::reader::Pos;
+
+    use super::*;
+
+    fn whitespace() -> Whitespace {
+        Whitespace {
+            value: " ".to_string(),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        }
+    }
+
+    fn source_info() -> SourceInfo {
+        SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1))
+    }
+
+    #[test]
+    fn test_new_uuid() {
+        let Value::String(uuid) = eval(&Function::NewUuid, source_info()).unwrap() else {
+            panic!("expecting a string");
+        };
+        let uuid = Uuid::parse_str(&uuid).unwrap();
+        assert_eq!(uuid.get_version_num(), 4);
+    }
+
+    #[test]
+    fn test_new_date() {
+        let Value::Date(date) = eval(&Function::NewDate, source_info()).unwrap() else {
+            panic!("expecting a date");
+        };
+        let rendered = Value::Date(date).render().unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(&rendered).is_ok());
+    }
+
+    #[test]
+    fn test_random_bits() {
+        // All the bits of a UUID v4 except version (0100) and variant (10) bits are random.
+        let uuid = Uuid::from_u128(0xffff_ffff_ffff_4fff_bfff_ffff_ffff_ffff);
+        assert_eq!(random_bits(uuid), (1 << 122) - 1);
+        let uuid = Uuid::from_u128(0x0000_0000_0000_4000_8000_0000_0000_0000);
+        assert_eq!(random_bits(uuid), 0);
+    }
+
+    #[test]
+    fn test_random_int() {
+        let function = Function::RandomInt {
+            space0: whitespace(),
+            min: -3,
+            space1: whitespace(),
+            max: 3,
+        };
+        for _ in 0..100 {
+            let Value::Number(Number::Integer(value)) = eval(&function, source_info()).unwrap()
+            else {
+                panic!("expecting an integer");
+            };
+            assert!((-3..=3).contains(&value));
+        }
+
+        let function = Function::RandomInt {
+            space0: whitespace(),
+            min: i64::MIN,
+            space1: whitespace(),
+            max: i64::MAX,
+        };
+        let values = (0..100)
+            .map(|_| match eval(&function, source_info()).unwrap() {
+                Value::Number(Number::Integer(value)) => value,
+                _ => panic!("expecting an integer"),
+            })
+            .collect::<Vec<_>>();
+        assert!(values.iter().any(|value| *value < 0));
+        assert!(values.iter().any(|value| *value > i64::MAX / 2));
+
+        let function = Function::RandomInt {
+            space0: whitespace(),
+            min: 7,
+            space1: whitespace(),
+            max: 7,
+        };
+        assert_eq!(
+            eval(&function, source_info()).unwrap(),
+            Value::Number(Number::Integer(7))
+        );
     }
 }
@@ -27,6 +27,7 @@ use hurl_core::parser;
 use hurl_core::typing::Count;
 
 use crate::http::{Call, Client};
+use crate::runner::eval_context::EvalContext;
 use crate::runner::event::EventListener;
 use crate::runner::runner_options::RunnerOptions;
 use crate::runner::{entry, options, EntryResult, HurlResult, VariableSet};
@@ -145,6 +146,7 @@ pub fn run_entries(
     logger: &mut Logger,
 ) -> HurlResult {
     let mut http_client = Client::new();
+    let mut eval_context = EvalContext::new(runner_options);
     let mut entries_result = vec![];
     let mut variables = variables.clone();
     let mut entry_index = runner_options.from_entry.unwrap_or(1);
@@ -250,6 +252,7 @@ pub fn run_entries(
             content,
             filename,
             &mut http_client,
+            &mut eval_context,
             &options,
             &mut variables,
             stdout,
@@ -314,6 +317,7 @@ fn run_request(
     content: &str,
     filename: Option<&Input>,
     http_client: &mut Client,
+    eval_context: &mut EvalContext,
     options: &RunnerOptions,
     variables: &mut VariableSet,
     stdout: &mut Stdout,
@@ -323,7 +327,15 @@ fn run_request(
     let mut retry_count = 1;
 
     loop {
-        let mut result = entry::run(entry, entry_index, http_client, variables, options, logger);
+        let mut result = entry::run(
+            entry,
+            entry_index,
+            http_client,
+            eval_context,
+            variables,
+            options,
+            logger,
+        );
 
         // Check if we need to retry.
         let mut has_error = !result.errors.is_empty();
@@ -26,18 +26,24 @@ pub use self::hurl_file::run;
 pub use self::hurl_file::run_entries;
 pub use self::number::Number;
 pub use self::output::Output;
-pub use self::result::{AssertResult, CaptureResult, EntryResult, HurlResult};
+pub use self::result::{
+    AssertResult, CaptureResult, DurationBucket, EntryResult, HurlResult, DEFAULT_DURATION_BUCKETS,
+};
 pub use self::runner_options::{RunnerOptions, RunnerOptionsBuilder};
 pub use self::value::Value;
 pub use self::variable::VariableSet;
+pub use self::variables_file::{parse_variables, VariablesFileError, VariablesFormat};
+pub use self::warning::{Warning, WarningKind};
 
 mod assert;
 mod body;
+mod body_scan;
 mod cache;
 mod capture;
 mod diff;
 mod entry;
 mod error;
+mod eval_context;
 mod event;
 mod expr;
 mod filter;
@@ -60,4 +66,6 @@ mod runner_options;
 mod template;
 mod value;
 mod variable;
+mod variables_file;
+mod warning;
 mod xpath;
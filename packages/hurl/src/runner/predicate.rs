@@ -15,11 +15,17 @@
  * limitations under the License.
  *
  */
-use hurl_core::ast::{Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo};
+use hurl_core::ast::{
+    JsonValue, Placeholder, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue,
+    SourceInfo, Whitespace,
+};
 use hurl_core::reader::Pos;
 use std::cmp::Ordering;
 
+use crate::http::ResponseCookie;
 use crate::runner::error::RunnerError;
+use crate::runner::eval_context::EvalContext;
+use crate::runner::json::eval_json_value;
 use crate::runner::predicate_value::{eval_predicate_value, eval_predicate_value_template};
 use crate::runner::result::PredicateResult;
 use crate::runner::template::eval_template;
@@ -50,20 +56,23 @@ pub fn eval_predicate(
     variables: &VariableSet,
     value: &Option<Value>,
     context_dir: &ContextDir,
+    eval_context: &mut EvalContext,
 ) -> PredicateResult {
+    // Column error is set to 0 to disable the error display of "^^^"
+    let source_info = SourceInfo::new(
+        Pos::new(predicate.space0.source_info.start.line, 0),
+        Pos::new(predicate.space0.source_info.start.line, 0),
+    );
+
     let assert_result = eval_predicate_func(
         &predicate.predicate_func,
         variables,
         value.as_ref(),
+        predicate.not,
         context_dir,
+        eval_context,
     )?;
 
-    // Column error is set to 0 to disable the error display of "^^^"
-    let source_info = SourceInfo::new(
-        Pos::new(predicate.space0.source_info.start.line, 0),
-        Pos::new(predicate.space0.source_info.start.line, 0),
-    );
-
     if assert_result.type_mismatch {
         let not = if predicate.not { "not " } else { "" };
         let expected = format!("{}{}", not, assert_result.expected);
@@ -71,6 +80,7 @@ pub fn eval_predicate(
             actual: assert_result.actual,
             expected,
             type_mismatch: true,
+            actual_value: structured_value(value),
         };
         Err(RunnerError::new(source_info, kind, true))
     } else if predicate.not && assert_result.success {
@@ -78,6 +88,7 @@ pub fn eval_predicate(
             actual: assert_result.actual,
             expected: format!("not {}", assert_result.expected),
             type_mismatch: false,
+            actual_value: structured_value(value),
         };
         Err(RunnerError::new(source_info, kind, true))
     } else if !predicate.not && !assert_result.success {
@@ -85,6 +96,7 @@ pub fn eval_predicate(
             actual: assert_result.actual,
             expected: assert_result.expected,
             type_mismatch: false,
+            actual_value: structured_value(value),
         };
         Err(RunnerError::new(source_info, kind, true))
     } else {
@@ -92,6 +104,15 @@ pub fn eval_predicate(
     }
 }
 
+/// Returns a copy of `value` if it's a list or an object, to be displayed on multiple lines in an
+/// assert failure.
+fn structured_value(value: &Option<Value>) -> Option<Box<Value>> {
+    value
+        .as_ref()
+        .filter(|v| v.is_structured())
+        .map(|v| Box::new(v.clone()))
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 struct AssertResult {
     pub success: bool,
@@ -217,6 +238,10 @@ fn expected_no_value(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("contains string <{expected}>"))
         }
+        PredicateFuncValue::ContainsSubset { object, .. } => {
+            let object = eval_json_value(object, variables, true)?;
+            Ok(format!("contains subset <{object}>"))
+        }
         PredicateFuncValue::Include { value, .. } => {
             let value = eval_predicate_value(value, variables, context_dir)?;
             Ok(format!("include {}", value.format()))
@@ -227,6 +252,38 @@ fn expected_no_value(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{expected}>"))
         }
+        PredicateFuncValue::MatchGlob {
+            value: expected, ..
+        } => {
+            let expected = eval_predicate_value_template(expected, variables)?;
+            Ok(format!("matches glob <{expected}>"))
+        }
+        PredicateFuncValue::AnyLineMatch {
+            value: expected, ..
+        } => {
+            let expected = eval_predicate_value_template(expected, variables)?;
+            Ok(format!("any line matches regex <{expected}>"))
+        }
+        PredicateFuncValue::MatchesAll { patterns } => {
+            let expected = patterns
+                .iter()
+                .map(|(_, value)| {
+                    eval_predicate_value_template(value, variables).map(|v| format!("<{v}>"))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(format!("matches all regexes {}", expected.join(", ")))
+        }
+        PredicateFuncValue::HasLength { n, .. } => Ok(format!("length equals to {n}")),
+        PredicateFuncValue::IsInRange { min, max, .. } => {
+            let min = eval_predicate_value(min, variables, context_dir)?;
+            let max = eval_predicate_value(max, variables, context_dir)?;
+            Ok(format!("in range [{}, {}]", min.format(), max.format()))
+        }
+        PredicateFuncValue::IsCloseTo { value, epsilon, .. } => {
+            let value = eval_predicate_value(value, variables, context_dir)?;
+            let epsilon = eval_epsilon(epsilon, variables, context_dir)?;
+            Ok(expected_close_to(&value, &epsilon))
+        }
         PredicateFuncValue::IsInteger => Ok("integer".to_string()),
         PredicateFuncValue::IsFloat => Ok("float".to_string()),
         PredicateFuncValue::IsBoolean => Ok("boolean".to_string()),
@@ -237,17 +294,24 @@ fn expected_no_value(
         PredicateFuncValue::Exist => Ok("something".to_string()),
         PredicateFuncValue::IsEmpty => Ok("empty".to_string()),
         PredicateFuncValue::IsNumber => Ok("number".to_string()),
+        PredicateFuncValue::IsNull => Ok("null".to_string()),
+        PredicateFuncValue::IsNotNull => Ok("not null".to_string()),
+        PredicateFuncValue::IsTrue => Ok("bool <true>".to_string()),
+        PredicateFuncValue::IsFalse => Ok("bool <false>".to_string()),
+        PredicateFuncValue::IsCookieSecureCompliant => Ok(COOKIE_SECURE_COMPLIANT.to_string()),
     }
 }
 
 /// Evaluates a `predicate_func` against an actual `value`.
 /// The `predicate_func` is a test with an expected value. The expected value can
-/// use a set of `variables`.
+/// use a set of `variables`, `not` is true if the predicate is negated.
 fn eval_predicate_func(
     predicate_func: &PredicateFunc,
     variables: &VariableSet,
     value: Option<&Value>,
+    not: bool,
     context_dir: &ContextDir,
+    eval_context: &mut EvalContext,
 ) -> Result<AssertResult, RunnerError> {
     let value = match value {
         Some(value) => value,
@@ -290,12 +354,46 @@ fn eval_predicate_func(
         PredicateFuncValue::Contain {
             value: expected, ..
         } => eval_contain(expected, variables, value, context_dir),
+        PredicateFuncValue::ContainsSubset { object, .. } => {
+            eval_contains_subset(object, predicate_func.source_info, variables, value)
+        }
         PredicateFuncValue::Include {
             value: expected, ..
         } => eval_include(expected, variables, value, context_dir),
         PredicateFuncValue::Match {
             value: expected, ..
-        } => eval_match(expected, predicate_func.source_info, variables, value),
+        } => eval_match(expected, variables, value, not, eval_context),
+        PredicateFuncValue::MatchGlob {
+            value: expected, ..
+        } => eval_match_glob(expected, predicate_func.source_info, variables, value),
+        PredicateFuncValue::AnyLineMatch {
+            value: expected, ..
+        } => eval_any_line_match(expected, variables, value, eval_context),
+        PredicateFuncValue::MatchesAll { patterns } => {
+            eval_matches_all(patterns, variables, value, eval_context)
+        }
+        PredicateFuncValue::HasLength { n, .. } => eval_has_length(*n, value),
+        PredicateFuncValue::IsInRange { min, max, .. } => {
+            eval_is_in_range(min, max, variables, value, context_dir)
+        }
+        PredicateFuncValue::IsCloseTo {
+            value: expected,
+            epsilon,
+            ..
+        } => {
+            // Column error is set to 0 to disable the error display of "^^^"
+            let line = predicate_func.source_info.start.line;
+            let source_info = SourceInfo::new(Pos::new(line, 0), Pos::new(line, 0));
+            eval_is_close_to(
+                expected,
+                epsilon,
+                variables,
+                value,
+                not,
+                source_info,
+                context_dir,
+            )
+        }
         PredicateFuncValue::IsInteger => eval_is_integer(value),
         PredicateFuncValue::IsFloat => eval_is_float(value),
         PredicateFuncValue::IsBoolean => eval_is_boolean(value),
@@ -306,6 +404,11 @@ fn eval_predicate_func(
         PredicateFuncValue::Exist => eval_exist(value),
         PredicateFuncValue::IsEmpty => eval_is_empty(value),
         PredicateFuncValue::IsNumber => eval_is_number(value),
+        PredicateFuncValue::IsNull => eval_is_null(value),
+        PredicateFuncValue::IsNotNull => eval_is_not_null(value),
+        PredicateFuncValue::IsTrue => eval_is_true(value),
+        PredicateFuncValue::IsFalse => eval_is_false(value),
+        PredicateFuncValue::IsCookieSecureCompliant => eval_is_cookie_secure_compliant(value),
     }
 }
 
@@ -474,6 +577,81 @@ fn eval_contain(
     }
 }
 
+/// Evaluates if an `actual` object contains the key/value pairs of an `expected` JSON object
+/// (using a `variables` set).
+///
+/// Objects are compared deeply, extra keys of the `actual` object being ignored. If the assert
+/// fails, the path of the first mismatch is reported (for instance `$.address.city`).
+fn eval_contains_subset(
+    expected: &JsonValue,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+) -> Result<AssertResult, RunnerError> {
+    let expected = eval_json_value(expected, variables, true)?;
+    let expected = match serde_json::from_str::<serde_json::Value>(&expected) {
+        Ok(json) => Value::from_json(&json),
+        Err(_) => {
+            let kind = RunnerErrorKind::InvalidJson { value: expected };
+            return Err(RunnerError::new(source_info, kind, false));
+        }
+    };
+    let expected_display = format!("contains subset {}", expected.display());
+    match actual {
+        Value::Object(_) => {
+            let (success, actual_display) = match subset_mismatch(&expected, actual, "$") {
+                None => (true, actual.display()),
+                Some((path, None)) => (false, format!("no value at path {path}")),
+                Some((path, Some(value))) => (false, format!("{} at path {path}", value.display())),
+            };
+            Ok(AssertResult {
+                success,
+                actual: actual_display,
+                expected: expected_display,
+                type_mismatch: false,
+            })
+        }
+        _ => Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected: expected_display,
+            type_mismatch: true,
+        }),
+    }
+}
+
+/// Returns the path of the first value of `expected` not found in `actual`, with the actual value
+/// at this path (`None` if there is no such key), or `None` if `expected` is a subset of `actual`.
+///
+/// Objects are subsets if all their keys are found in the actual object, lists must have the same
+/// length and their elements are compared one by one.
+fn subset_mismatch<'a>(
+    expected: &Value,
+    actual: &'a Value,
+    path: &str,
+) -> Option<(String, Option<&'a Value>)> {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            expected.iter().find_map(|(key, expected)| {
+                let path = format!("{path}.{key}");
+                match actual.iter().find(|(k, _)| k == key) {
+                    None => Some((path, None)),
+                    Some((_, actual)) => subset_mismatch(expected, actual, &path),
+                }
+            })
+        }
+        (Value::List(expected), Value::List(actual)) if expected.len() == actual.len() => expected
+            .iter()
+            .zip(actual.iter())
+            .enumerate()
+            .find_map(|(i, (expected, actual))| {
+                subset_mismatch(expected, actual, &format!("{path}[{i}]"))
+            }),
+        (expected, actual) if expected == actual => None,
+        _ => Some((path.to_string(), Some(actual))),
+    }
+}
+
 /// Evaluates if an `expected` value (using a `variables` set) includes an `actual` value.
 /// This predicate works with list (maybe we should merge it with `eval_contains`?)
 fn eval_include(
@@ -489,32 +667,212 @@ fn eval_include(
 /// Evaluates if an `expected` regex (using a `variables` set) matches an `actual` value.
 fn eval_match(
     expected: &PredicateValue,
-    source_info: SourceInfo,
     variables: &VariableSet,
     actual: &Value,
+    not: bool,
+    eval_context: &mut EvalContext,
 ) -> Result<AssertResult, RunnerError> {
-    let regex = match expected {
+    let regex = eval_regex_predicate_value(expected, variables, eval_context)?;
+    let expected_display = format!("matches regex <{regex}>");
+    let Value::String(value) = actual else {
+        return Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    let found = regex.find(value);
+    let success = found.is_some();
+    // Actual string can be a whole response body, we only display its beginning, with the
+    // position of the first match to help diagnose the failure. This display is only built if the
+    // assert fails (no match, or a match with `not`).
+    let actual_display = if success == not {
+        format!(
+            "{} ({})",
+            display_string_prefix(value),
+            display_match_span(found, value)
+        )
+    } else {
+        String::new()
+    };
+    Ok(AssertResult {
+        success,
+        actual: actual_display,
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+/// Evaluates if at least one line of an `actual` string matches the regex `expected`.
+///
+/// The regex is applied to each line separately (lines being split on `\n` or `\r\n`), so `^` and
+/// `$` anchor to the start and end of a line.
+fn eval_any_line_match(
+    expected: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    eval_context: &mut EvalContext,
+) -> Result<AssertResult, RunnerError> {
+    let regex = eval_regex_predicate_value(expected, variables, eval_context)?;
+    let expected_display = format!("any line matches regex <{regex}>");
+    match actual {
+        Value::String(value) => {
+            let line = value.lines().position(|line| regex.is_match(line));
+            let line_display = match line {
+                Some(index) => format!("match at line {}", index + 1),
+                None => "no line match".to_string(),
+            };
+            Ok(AssertResult {
+                success: line.is_some(),
+                actual: format!("{} ({line_display})", display_string_prefix(value)),
+                expected: expected_display,
+                type_mismatch: false,
+            })
+        }
+        _ => Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected: expected_display,
+            type_mismatch: true,
+        }),
+    }
+}
+
+/// Evaluates if an `actual` value matches all the regexes `patterns` of a `matchesAll` predicate,
+/// given a set of `variables`.
+///
+/// On failure, the actual value lists the patterns that don't match.
+fn eval_matches_all(
+    patterns: &[(Whitespace, PredicateValue)],
+    variables: &VariableSet,
+    actual: &Value,
+    eval_context: &mut EvalContext,
+) -> Result<AssertResult, RunnerError> {
+    let regexes = patterns
+        .iter()
+        .map(|(_, value)| eval_regex_predicate_value(value, variables, eval_context))
+        .collect::<Result<Vec<_>, _>>()?;
+    let expected_display = format!(
+        "matches all regexes {}",
+        regexes
+            .iter()
+            .map(|regex| format!("<{regex}>"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    match actual {
+        Value::String(value) => {
+            let failures = regexes
+                .iter()
+                .filter(|regex| !regex.is_match(value))
+                .map(|regex| format!("<{regex}>"))
+                .collect::<Vec<_>>();
+            let match_display = if failures.is_empty() {
+                "all regexes match".to_string()
+            } else {
+                format!("no match for regex {}", failures.join(", "))
+            };
+            Ok(AssertResult {
+                success: failures.is_empty(),
+                actual: format!("{} ({match_display})", display_string_prefix(value)),
+                expected: expected_display,
+                type_mismatch: false,
+            })
+        }
+        _ => Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected: expected_display,
+            type_mismatch: true,
+        }),
+    }
+}
+
+/// Returns the regex of a `matches`, `anyLineMatches` or `matchesAll` predicate value `expected`,
+/// given a set of `variables`.
+///
+/// An invalid regex error points to the pattern itself, not to the whole predicate.
+fn eval_regex_predicate_value(
+    expected: &PredicateValue,
+    variables: &VariableSet,
+    eval_context: &mut EvalContext,
+) -> Result<regex::Regex, RunnerError> {
+    match expected {
         PredicateValue::String(template) => {
             let expected = eval_template(template, variables)?;
-            match regex::Regex::new(expected.as_str()) {
-                Ok(re) => re,
-                Err(_) => {
-                    return Err(RunnerError::new(
-                        source_info,
-                        RunnerErrorKind::InvalidRegex,
-                        false,
-                    ))
-                }
+            eval_context
+                .new_regex(expected.as_str())
+                .map_err(|kind| RunnerError::new(template.source_info, kind, false))
+        }
+        PredicateValue::Regex(regex) => Ok(regex.inner.clone()),
+        _ => panic!("expect a string predicate value"), // should have failed in parsing
+    }
+}
+
+/// Maximum number of characters of an actual string displayed in a `matches` assert.
+const MATCH_ACTUAL_MAX_CHARS: usize = 80;
+
+/// Displays a string `value`, truncated to its first [`MATCH_ACTUAL_MAX_CHARS`] characters.
+fn display_string_prefix(value: &str) -> String {
+    match value.char_indices().nth(MATCH_ACTUAL_MAX_CHARS) {
+        None => format!("string <{value}>"),
+        Some((index, _)) => format!(
+            "string <{}...> (truncated, {} characters)",
+            &value[..index],
+            value.chars().count()
+        ),
+    }
+}
+
+/// Displays the position of the first match `found` in `value`, as a range of character offsets
+/// (for instance `match at characters 3..6`), or `no match`. For a multiline `value`, the line of
+/// the match start is also displayed (for instance `match at characters 12..15, line 3`).
+fn display_match_span(found: Option<regex::Match>, value: &str) -> String {
+    match found {
+        None => "no match".to_string(),
+        Some(m) => {
+            let start = value[..m.start()].chars().count();
+            let end = start + m.as_str().chars().count();
+            if value.contains('\n') {
+                let line = value[..m.start()].matches('\n').count() + 1;
+                format!("match at characters {start}..{end}, line {line}")
+            } else {
+                format!("match at characters {start}..{end}")
             }
         }
-        PredicateValue::Regex(regex) => regex.inner.clone(),
+    }
+}
+
+/// Evaluates if an `actual` string matches the glob pattern `expected`.
+///
+/// Glob patterns support `?` (any single character), `*` (any sequence of characters) and `[...]`
+/// (any character in the brackets).
+fn eval_match_glob(
+    expected: &PredicateValue,
+    source_info: SourceInfo,
+    variables: &VariableSet,
+    actual: &Value,
+) -> Result<AssertResult, RunnerError> {
+    let pattern = match expected {
+        PredicateValue::String(template) => eval_template(template, variables)?,
         _ => panic!("expect a string predicate value"), // should have failed in parsing
     };
+    let glob = match glob::Pattern::new(&pattern) {
+        Ok(glob) => glob,
+        Err(_) => {
+            return Err(RunnerError::new(
+                source_info,
+                RunnerErrorKind::InvalidGlob,
+                false,
+            ))
+        }
+    };
     let actual_display = actual.display();
-    let expected_display = format!("matches regex <{regex}>");
+    let expected_display = format!("matches glob <{pattern}>");
     match actual {
         Value::String(value) => Ok(AssertResult {
-            success: regex.is_match(value.as_str()),
+            success: glob.matches(value),
             actual: actual_display,
             expected: expected_display,
             type_mismatch: false,
@@ -528,6 +886,101 @@ fn eval_match(
     }
 }
 
+/// Evaluates if an `actual` list, string or object has a length of `expected`.
+///
+/// The length of a string is its number of characters.
+fn eval_has_length(expected: u64, actual: &Value) -> Result<AssertResult, RunnerError> {
+    let len = match actual {
+        Value::List(values) => values.len(),
+        Value::String(data) => data.chars().count(),
+        Value::Object(props) => props.len(),
+        _ => {
+            return Ok(AssertResult {
+                success: false,
+                actual: actual.display(),
+                expected: format!("length equals to {expected}"),
+                type_mismatch: true,
+            })
+        }
+    };
+    Ok(AssertResult {
+        success: len as u64 == expected,
+        actual: format!("length equals to {len}"),
+        expected: format!("length equals to {expected}"),
+        type_mismatch: false,
+    })
+}
+
+/// Evaluates if an `actual` value is between a `min` and a `max` values (using a `variables` set),
+/// bounds included.
+fn eval_is_in_range(
+    min: &PredicateValue,
+    max: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    context_dir: &ContextDir,
+) -> Result<AssertResult, RunnerError> {
+    let min = eval_predicate_value(min, variables, context_dir)?;
+    let max = eval_predicate_value(max, variables, context_dir)?;
+    Ok(assert_values_in_range(actual, &min, &max))
+}
+
+/// Evaluates if an `actual` value is equal to an `expected` value, within a tolerance `epsilon`
+/// (using a `variables` set).
+///
+/// If the predicate is not negated (`not` is false), a number that is not close to the expected
+/// one is reported as an [`RunnerErrorKind::AssertFloatTolerance`] error at `source_info`.
+fn eval_is_close_to(
+    expected: &PredicateValue,
+    epsilon: &PredicateValue,
+    variables: &VariableSet,
+    actual: &Value,
+    not: bool,
+    source_info: SourceInfo,
+    context_dir: &ContextDir,
+) -> Result<AssertResult, RunnerError> {
+    let expected = eval_predicate_value(expected, variables, context_dir)?;
+    let epsilon = eval_epsilon(epsilon, variables, context_dir)?;
+    let assert_result = assert_values_close_to(actual, &expected, &epsilon);
+    if !not && !assert_result.success && !assert_result.type_mismatch {
+        let kind = RunnerErrorKind::AssertFloatTolerance {
+            actual: assert_result.actual,
+            expected: expected.display(),
+            epsilon: epsilon.to_string(),
+        };
+        return Err(RunnerError::new(source_info, kind, true));
+    }
+    Ok(assert_result)
+}
+
+/// Evaluates the tolerance `epsilon` of an `isCloseTo` predicate (using a `variables` set).
+///
+/// Negative and NaN numbers are rejected, a negative literal being already rejected by the parser.
+fn eval_epsilon(
+    epsilon: &PredicateValue,
+    variables: &VariableSet,
+    context_dir: &ContextDir,
+) -> Result<Value, RunnerError> {
+    let value = eval_predicate_value(epsilon, variables, context_dir)?;
+    let valid = match &value {
+        Value::Number(Number::Float(f)) => *f >= 0.0,
+        Value::Number(Number::Integer(i)) => *i >= 0,
+        Value::Number(Number::BigInteger(s)) => !s.starts_with('-'),
+        // Other types are reported as a type mismatch by the predicate.
+        _ => true,
+    };
+    match epsilon {
+        PredicateValue::Placeholder(Placeholder { expr, .. }) if !valid => {
+            let kind = RunnerErrorKind::ExpressionInvalidType {
+                value: value.format(),
+                expecting: "number >= 0".to_string(),
+            };
+            Err(RunnerError::new(expr.source_info, kind, false))
+        }
+        _ => Ok(value),
+    }
+}
+
 /// Evaluates if an `actual` value is an integer.
 fn eval_is_integer(actual: &Value) -> Result<AssertResult, RunnerError> {
     Ok(AssertResult {
@@ -686,6 +1139,121 @@ fn eval_is_number(actual: &Value) -> Result<AssertResult, RunnerError> {
     })
 }
 
+/// Evaluates if an `actual` value is null.
+fn eval_is_null(actual: &Value) -> Result<AssertResult, RunnerError> {
+    Ok(AssertResult {
+        success: matches!(actual, Value::Null),
+        actual: actual.display(),
+        expected: "null".to_string(),
+        type_mismatch: false,
+    })
+}
+
+/// Evaluates if an `actual` value is not null.
+fn eval_is_not_null(actual: &Value) -> Result<AssertResult, RunnerError> {
+    Ok(AssertResult {
+        success: !matches!(actual, Value::Null),
+        actual: actual.display(),
+        expected: "not null".to_string(),
+        type_mismatch: false,
+    })
+}
+
+/// Evaluates if an `actual` value is the boolean `true`.
+fn eval_is_true(actual: &Value) -> Result<AssertResult, RunnerError> {
+    let expected = "bool <true>".to_string();
+    match actual {
+        Value::Bool(value) => Ok(AssertResult {
+            success: *value,
+            actual: actual.display(),
+            expected,
+            type_mismatch: false,
+        }),
+        _ => Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected,
+            type_mismatch: true,
+        }),
+    }
+}
+
+/// Evaluates if an `actual` value is the boolean `false`.
+fn eval_is_false(actual: &Value) -> Result<AssertResult, RunnerError> {
+    let expected = "bool <false>".to_string();
+    match actual {
+        Value::Bool(value) => Ok(AssertResult {
+            success: !*value,
+            actual: actual.display(),
+            expected,
+            type_mismatch: false,
+        }),
+        _ => Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected,
+            type_mismatch: true,
+        }),
+    }
+}
+
+/// Expected value displayed for the `isCookieSecureCompliant` predicate.
+const COOKIE_SECURE_COMPLIANT: &str = "cookie with Secure attribute when SameSite=None";
+
+/// Evaluates if an `actual` cookie doesn't have a `SameSite=None` attribute without `Secure`.
+///
+/// The cookie is either a `Set-Cookie` header value, or an object with the `same_site` and `secure`
+/// fields of the cookies JSON export. A list of cookies is compliant if all its cookies are.
+fn eval_is_cookie_secure_compliant(actual: &Value) -> Result<AssertResult, RunnerError> {
+    let cookies = match actual {
+        Value::List(values) => values.iter().collect::<Vec<_>>(),
+        _ => vec![actual],
+    };
+    let mut success = true;
+    for cookie in cookies {
+        let Some((same_site, secure)) = cookie_same_site_secure(cookie) else {
+            return Ok(AssertResult {
+                success: false,
+                actual: actual.display(),
+                expected: "cookie".to_string(),
+                type_mismatch: true,
+            });
+        };
+        let same_site_none = same_site.is_some_and(|s| s.trim().eq_ignore_ascii_case("None"));
+        if same_site_none && !secure {
+            success = false;
+        }
+    }
+    Ok(AssertResult {
+        success,
+        actual: actual.display(),
+        expected: COOKIE_SECURE_COMPLIANT.to_string(),
+        type_mismatch: false,
+    })
+}
+
+/// Returns the SameSite attribute and the presence of the Secure attribute of a cookie `value`,
+/// or `None` if `value` is not a cookie.
+fn cookie_same_site_secure(value: &Value) -> Option<(Option<String>, bool)> {
+    match value {
+        Value::String(s) => {
+            let cookie = ResponseCookie::parse(s)?;
+            Some((cookie.samesite(), cookie.has_secure()))
+        }
+        Value::Object(fields) => {
+            let same_site = fields.iter().find_map(|(name, value)| match value {
+                Value::String(s) if name == "same_site" => Some(s.clone()),
+                _ => None,
+            });
+            let secure = fields
+                .iter()
+                .any(|(name, value)| name == "secure" && *value == Value::Bool(true));
+            Some((same_site, secure))
+        }
+        _ => None,
+    }
+}
+
 fn assert_values_equal(actual: &Value, expected: &Value) -> AssertResult {
     let actual_display = actual.display();
     let expected_display = expected.display();
@@ -887,23 +1455,90 @@ fn assert_values_less_or_equal(actual_value: &Value, expected_value: &Value) ->
     }
 }
 
-/// Compares `actual` and `expected`.
-///
-/// Returns None it the values are not cpmparable
-fn compare_values(actual: &Value, expected: &Value) -> Option<Ordering> {
-    match (actual, expected) {
-        (Value::Number(number1), Value::Number(number2)) => Some(number1.cmp_value(number2)),
-        (Value::String(s1), Value::String(s2)) => Some(s1.cmp(s2)),
-        _ => None,
-    }
-}
-
-fn assert_include(value: &Value, element: &Value) -> AssertResult {
-    let expected = format!("includes {}", element.display());
-    match value {
-        Value::List(values) => {
-            let mut success = false;
-            for v in values {
+/// Asserts that an `actual_value` is a number between `min` and `max`, bounds included.
+fn assert_values_in_range(actual_value: &Value, min: &Value, max: &Value) -> AssertResult {
+    let actual = actual_value.display();
+    let expected = format!("in range [{}, {}]", min.display(), max.display());
+    match (actual_value, min, max) {
+        (Value::Number(number), Value::Number(min), Value::Number(max)) => AssertResult {
+            success: number.cmp_value(min) != Ordering::Less
+                && number.cmp_value(max) != Ordering::Greater,
+            actual,
+            expected,
+            type_mismatch: false,
+        },
+        _ => AssertResult {
+            success: false,
+            actual,
+            expected,
+            type_mismatch: true,
+        },
+    }
+}
+
+fn assert_values_close_to(actual_value: &Value, expected: &Value, epsilon: &Value) -> AssertResult {
+    let actual = actual_value.display();
+    let expected_display = expected_close_to(expected, epsilon);
+    let close = match (actual_value, expected, epsilon) {
+        (Value::Number(actual), Value::Number(expected), Value::Number(epsilon)) => {
+            is_close_to(actual, expected, epsilon)
+        }
+        _ => None,
+    };
+    match close {
+        Some(success) => AssertResult {
+            success,
+            actual,
+            expected: expected_display,
+            type_mismatch: false,
+        },
+        None => AssertResult {
+            success: false,
+            actual,
+            expected: expected_display,
+            type_mismatch: true,
+        },
+    }
+}
+
+/// Returns the expected message of an `isCloseTo` predicate.
+fn expected_close_to(expected: &Value, epsilon: &Value) -> String {
+    format!("close to {} within {epsilon}", expected.format())
+}
+
+/// Returns `true` if `actual` is equal to `expected` within the tolerance `epsilon`
+/// (`|actual - expected| <= epsilon`), integers and floats being compared as floats.
+///
+/// Returns `None` for big integers, that can't be compared as floats.
+fn is_close_to(actual: &Number, expected: &Number, epsilon: &Number) -> Option<bool> {
+    let as_float = |n: &Number| match n {
+        Number::Float(f) => Some(*f),
+        Number::Integer(i) => Some(*i as f64),
+        Number::BigInteger(_) => None,
+    };
+    let actual = as_float(actual)?;
+    let expected = as_float(expected)?;
+    let epsilon = as_float(epsilon)?;
+    Some((actual - expected).abs() <= epsilon)
+}
+
+/// Compares `actual` and `expected`.
+///
+/// Returns None it the values are not cpmparable
+fn compare_values(actual: &Value, expected: &Value) -> Option<Ordering> {
+    match (actual, expected) {
+        (Value::Number(number1), Value::Number(number2)) => Some(number1.cmp_value(number2)),
+        (Value::String(s1), Value::String(s2)) => Some(s1.cmp(s2)),
+        _ => None,
+    }
+}
+
+fn assert_include(value: &Value, element: &Value) -> AssertResult {
+    let expected = format!("includes {}", element.display());
+    match value {
+        Value::List(values) => {
+            let mut success = false;
+            for v in values {
                 let result = assert_values_equal(v, element);
                 if result.success {
                     success = true;
@@ -987,7 +1622,8 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Bool(true)),
-            &context_dir
+            &context_dir,
+            &mut EvalContext::default()
         )
         .is_ok());
 
@@ -996,6 +1632,7 @@ mod tests {
             &variables,
             &Some(Value::Number(Number::Integer(10))),
             &context_dir,
+            &mut EvalContext::default(),
         )
         .unwrap_err();
         assert_eq!(
@@ -1004,6 +1641,7 @@ mod tests {
                 actual: "int <10>".to_string(),
                 expected: "not int <10>".to_string(),
                 type_mismatch: false,
+                actual_value: None,
             }
         );
         assert_eq!(
@@ -1015,11 +1653,61 @@ mod tests {
             &predicate,
             &variables,
             &Some(Value::Number(Number::Integer(1))),
-            &context_dir
+            &context_dir,
+            &mut EvalContext::default()
         )
         .is_ok());
     }
 
+    #[test]
+    fn test_predicate_structured_actual_value() {
+        let variables = VariableSet::new();
+        let whitespace = Whitespace {
+            value: String::from(" "),
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+        };
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+
+        // predicate: `== 10`
+        // value: [1, 2]
+        let predicate = Predicate {
+            not: false,
+            space0: whitespace.clone(),
+            predicate_func: PredicateFunc {
+                value: PredicateFuncValue::Equal {
+                    space0: whitespace,
+                    value: PredicateValue::Number(hurl_core::ast::Number::Integer(10)),
+                    operator: true,
+                },
+                source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 12)),
+            },
+        };
+        let value = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(2)),
+        ]);
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &Some(value.clone()),
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        // The actual value is kept to be displayed on multiple lines, its display stays compact.
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::AssertFailure {
+                actual: "[int <1>, int <2>]".to_string(),
+                expected: "int <10>".to_string(),
+                type_mismatch: false,
+                actual_value: Some(Box::new(value)),
+            }
+        );
+    }
+
     #[test]
     fn test_predicate_type_mismatch() {
         let variables = VariableSet::new();
@@ -1114,15 +1802,29 @@ mod tests {
         };
 
         let value = Some(&Value::Unit);
-        let assert_result =
-            eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+        let assert_result = eval_predicate_func(
+            &pred_func,
+            &variables,
+            value,
+            false,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
         assert!(assert_result.success);
         assert_eq!(assert_result.actual.as_str(), "unit");
         assert_eq!(assert_result.expected.as_str(), "something");
 
         let value = None;
-        let assert_result =
-            eval_predicate_func(&pred_func, &variables, value, &context_dir).unwrap();
+        let assert_result = eval_predicate_func(
+            &pred_func,
+            &variables,
+            value,
+            false,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
         assert_eq!(assert_result.actual, "none");
@@ -1185,6 +1887,41 @@ mod tests {
         assert_eq!(assert_result.expected, "bool <true>");
     }
 
+    #[test]
+    fn test_predicate_value_equals_null() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+
+        // predicate: `== null`
+        // value: null
+        let expected = PredicateValue::Null;
+        let value = Value::Null;
+        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "null");
+        assert_eq!(assert_result.expected, "null");
+
+        // predicate: `== null`
+        // value: "null"
+        let expected = PredicateValue::Null;
+        let value = Value::String("null".to_string());
+        let assert_result = eval_equal(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(assert_result.actual, "string <null>");
+        assert_eq!(assert_result.expected, "null");
+
+        // predicate: `!= null`
+        // value: null
+        let expected = PredicateValue::Null;
+        let value = Value::Null;
+        let assert_result = eval_not_equal(&expected, &variables, &value, &context_dir).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+    }
+
     #[test]
     fn test_predicate_value_equals_floats() {
         let variables = VariableSet::new();
@@ -1380,141 +2117,608 @@ mod tests {
     }
 
     #[test]
-    fn test_predicate_is_empty_are_true() {
-        // predicate: `isEmpty`
-        // value: [1]
-        let value = Value::List(vec![]);
-        let assert_result = eval_is_empty(&value).unwrap();
+    fn test_predicate_has_length() {
+        // predicate: `hasLength 2`
+        // value: [1, 2]
+        let value = Value::List(vec![
+            Value::Number(Number::Integer(1)),
+            Value::Number(Number::Integer(2)),
+        ]);
+        let assert_result = eval_has_length(2, &value).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
-        assert_eq!(assert_result.actual, "count equals to 0");
-        assert_eq!(assert_result.expected, "count equals to 0");
+        assert_eq!(assert_result.actual, "length equals to 2");
+        assert_eq!(assert_result.expected, "length equals to 2");
 
-        // predicate: `isEmpty`
-        // value: Nodeset(0)
-        let value = Value::Nodeset(0);
-        let assert_result = eval_is_empty(&value).unwrap();
+        // predicate: `hasLength 3`
+        // value: [1, 2]
+        let assert_result = eval_has_length(3, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "length equals to 2");
+        assert_eq!(assert_result.expected, "length equals to 3");
+
+        // predicate: `hasLength 4`
+        // value: "café"
+        let value = Value::String("café".to_string());
+        let assert_result = eval_has_length(4, &value).unwrap();
         assert!(assert_result.success);
         assert!(!assert_result.type_mismatch);
-        assert_eq!(assert_result.actual, "count equals to 0");
-        assert_eq!(assert_result.expected, "count equals to 0");
+        assert_eq!(assert_result.actual, "length equals to 4");
+
+        // predicate: `hasLength 2`
+        // value: {"a": 1}
+        let value = Value::Object(vec![("a".to_string(), Value::Number(Number::Integer(1)))]);
+        let assert_result = eval_has_length(2, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "length equals to 1");
+        assert_eq!(assert_result.expected, "length equals to 2");
+
+        // predicate: `hasLength 1`
+        // value: true
+        let value = Value::Bool(true);
+        let assert_result = eval_has_length(1, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "bool <true>");
+        assert_eq!(assert_result.expected, "length equals to 1");
     }
 
     #[test]
-    fn test_predicate_type() {
-        // predicate: `isInteger`
-        // value: 1
-        let value = Value::Number(Number::Integer(1));
-        let assert_result = eval_is_integer(&value).unwrap();
-        assert!(assert_result.success);
-        assert!(!assert_result.type_mismatch);
-        assert_eq!(assert_result.actual, "int <1>");
-        assert_eq!(assert_result.expected, "integer");
+    fn test_predicate_is_in_range() {
+        let min = Value::Number(Number::Integer(200));
+        let max = Value::Number(Number::Integer(299));
+
+        // Bounds are included.
+        for status in [200, 204, 299] {
+            let value = Value::Number(Number::Integer(status));
+            assert!(assert_values_in_range(&value, &min, &max).success);
+        }
 
-        // predicate: `isInteger`
-        // value: 1
-        let value = Value::Number(Number::Float(1.0));
-        let assert_result = eval_is_integer(&value).unwrap();
+        let value = Value::Number(Number::Integer(404));
+        let assert_result = assert_values_in_range(&value, &min, &max);
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
-        assert_eq!(assert_result.actual, "float <1.0>");
-        assert_eq!(assert_result.expected, "integer");
+        assert_eq!(assert_result.actual, "int <404>");
+        assert_eq!(assert_result.expected, "in range [int <200>, int <299>]");
+
+        // Integers and floats can be mixed.
+        let value = Value::Number(Number::Float(0.5));
+        let min = Value::Number(Number::Integer(0));
+        let max = Value::Number(Number::Integer(1));
+        assert!(assert_values_in_range(&value, &min, &max).success);
+
+        let value = Value::String("200".to_string());
+        let assert_result = assert_values_in_range(&value, &min, &max);
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
     }
 
     #[test]
-    fn test_predicate_not_with_different_types() {
-        let current_dir = std::env::current_dir().unwrap();
-        let file_root = Path::new("file_root");
-        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+    fn test_predicate_is_close_to() {
+        let expected = Value::Number(Number::Float(12.5));
+        let epsilon = Value::Number(Number::Float(0.001));
 
-        // equals predicate does not generate a type error with an integer value
-        // predicate: `not == null`
-        // value: 1
+        let value = Value::Number(Number::Float(12.5005));
+        assert!(assert_values_close_to(&value, &expected, &epsilon).success);
+
+        let value = Value::Number(Number::Float(12.6));
+        let assert_result = assert_values_close_to(&value, &expected, &epsilon);
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "float <12.6>");
+        assert_eq!(assert_result.expected, "close to float <12.5> within 0.001");
+
+        // Integers and floats interoperate.
+        let value = Value::Number(Number::Integer(3));
+        let expected = Value::Number(Number::Integer(3));
+        let epsilon = Value::Number(Number::Float(0.0));
+        assert!(assert_values_close_to(&value, &expected, &epsilon).success);
+        let value = Value::Number(Number::Float(0.1 + 0.2));
+        let expected = Value::Number(Number::Float(0.3));
+        let epsilon = Value::Number(Number::Float(1e-9));
+        assert!(assert_values_close_to(&value, &expected, &epsilon).success);
+
+        let value = Value::String("12.5".to_string());
+        let assert_result = assert_values_close_to(&value, &expected, &epsilon);
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_float_tolerance_error() {
+        // predicate: `isCloseTo 12.5 0.001`
+        // value: 12.6
+        let whitespace = whitespace();
         let predicate = Predicate {
-            not: true,
-            space0: whitespace(),
+            not: false,
+            space0: whitespace.clone(),
             predicate_func: PredicateFunc {
-                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-                value: PredicateFuncValue::Equal {
-                    space0: whitespace(),
-                    operator: false,
-                    value: PredicateValue::Null,
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 21)),
+                value: PredicateFuncValue::IsCloseTo {
+                    space0: whitespace.clone(),
+                    value: PredicateValue::Number(hurl_core::ast::Number::Float(Float {
+                        value: 12.5,
+                        encoded: "12.5".to_string(),
+                    })),
+                    space1: whitespace,
+                    epsilon: PredicateValue::Number(hurl_core::ast::Number::Float(Float {
+                        value: 0.001,
+                        encoded: "0.001".to_string(),
+                    })),
                 },
             },
         };
-
         let variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+
+        let value = Some(Value::Number(Number::Float(12.6)));
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &value,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::AssertFloatTolerance {
+                actual: "float <12.6>".to_string(),
+                expected: "float <12.5>".to_string(),
+                epsilon: "0.001".to_string(),
+            }
+        );
+
+        let value = Some(Value::Number(Number::Float(12.5005)));
         assert!(eval_predicate(
             &predicate,
             &variables,
-            &Some(Value::Number(Number::Integer(1))),
-            &context_dir
+            &value,
+            &context_dir,
+            &mut EvalContext::default()
         )
         .is_ok());
+    }
 
-        // startswith predicate generates a type error with an integer value
-        // predicate: `not startWith "toto"`
-        // value: 1
+    #[test]
+    fn test_predicate_is_close_to_not() {
+        // predicate: `not isCloseTo 12.5 0.001`
+        // value: 12.5005
+        let whitespace = whitespace();
         let predicate = Predicate {
             not: true,
-            space0: whitespace(),
+            space0: whitespace.clone(),
             predicate_func: PredicateFunc {
-                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-                value: PredicateFuncValue::StartWith {
-                    space0: whitespace(),
-                    value: PredicateValue::String(Template {
-                        delimiter: None,
-                        elements: vec![TemplateElement::String {
-                            value: "toto".to_string(),
-                            encoded: "toto".to_string(),
-                        }],
-                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
-                    }),
+                source_info: SourceInfo::new(Pos::new(1, 5), Pos::new(1, 25)),
+                value: PredicateFuncValue::IsCloseTo {
+                    space0: whitespace.clone(),
+                    value: PredicateValue::Number(hurl_core::ast::Number::Float(Float {
+                        value: 12.5,
+                        encoded: "12.5".to_string(),
+                    })),
+                    space1: whitespace,
+                    epsilon: PredicateValue::Number(hurl_core::ast::Number::Float(Float {
+                        value: 0.001,
+                        encoded: "0.001".to_string(),
+                    })),
                 },
             },
         };
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+
+        let value = Some(Value::Number(Number::Float(12.5005)));
         let error = eval_predicate(
             &predicate,
             &variables,
-            &Some(Value::Number(Number::Integer(1))),
+            &value,
             &context_dir,
+            &mut EvalContext::default(),
         )
         .unwrap_err();
         assert_eq!(
             error.kind,
             RunnerErrorKind::AssertFailure {
-                actual: "int <1>".to_string(),
-                expected: "not starts with string <toto>".to_string(),
-                type_mismatch: true,
+                actual: "float <12.5005>".to_string(),
+                expected: "not close to float <12.5> within 0.001".to_string(),
+                type_mismatch: false,
+                actual_value: None,
             }
         );
-    }
-
-    #[test]
-    fn test_date_predicate() {
-        // predicate: `isDate`
-        // value: 2002-06-16T10:10:10
-        let value = Value::Date(
-            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2002, 6, 16, 10, 10, 10).unwrap(),
-        );
-        let assert_result = eval_is_date(&value).unwrap();
-        assert!(assert_result.success);
-        assert!(!assert_result.type_mismatch);
-        assert_eq!(assert_result.actual, "date <2002-06-16 10:10:10 UTC>");
-        assert_eq!(assert_result.expected, "date");
 
-        // predicate: `isDate`
-        // value: "toto"
-        let value = Value::String("toto".to_string());
-        let assert_result = eval_is_date(&value).unwrap();
-        assert!(!assert_result.success);
-        assert!(!assert_result.type_mismatch);
-        assert_eq!(assert_result.actual, "string <toto>");
-        assert_eq!(assert_result.expected, "date");
+        let value = Some(Value::Number(Number::Float(12.6)));
+        assert!(eval_predicate(
+            &predicate,
+            &variables,
+            &value,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .is_ok());
     }
 
     #[test]
-    fn test_no_type_mismatch_with_none_value() {
+    fn test_predicate_is_close_to_invalid_epsilon() {
+        // predicate: `isCloseTo 12.5 {{epsilon}}`
+        let whitespace = whitespace();
+        let predicate = Predicate {
+            not: false,
+            space0: whitespace.clone(),
+            predicate_func: PredicateFunc {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 27)),
+                value: PredicateFuncValue::IsCloseTo {
+                    space0: whitespace.clone(),
+                    value: PredicateValue::Number(hurl_core::ast::Number::Float(Float {
+                        value: 12.5,
+                        encoded: "12.5".to_string(),
+                    })),
+                    space1: whitespace.clone(),
+                    epsilon: PredicateValue::Placeholder(Placeholder {
+                        space0: whitespace.clone(),
+                        expr: Expr {
+                            kind: ExprKind::Variable(Variable {
+                                name: "epsilon".to_string(),
+                                source_info: SourceInfo::new(Pos::new(1, 17), Pos::new(1, 24)),
+                            }),
+                            source_info: SourceInfo::new(Pos::new(1, 17), Pos::new(1, 24)),
+                        },
+                        space1: whitespace,
+                    }),
+                },
+            },
+        };
+        let context_dir = ContextDir::default();
+        let value = Some(Value::Number(Number::Float(12.5)));
+
+        let mut variables = VariableSet::new();
+        variables
+            .insert("epsilon".to_string(), Value::Number(Number::Integer(-1)))
+            .unwrap();
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &value,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 17), Pos::new(1, 24))
+        );
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::ExpressionInvalidType {
+                value: "integer <-1>".to_string(),
+                expecting: "number >= 0".to_string(),
+            }
+        );
+
+        let mut variables = VariableSet::new();
+        variables
+            .insert(
+                "epsilon".to_string(),
+                Value::Number(Number::Float(f64::NAN)),
+            )
+            .unwrap();
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &value,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.kind,
+            RunnerErrorKind::ExpressionInvalidType { .. }
+        ));
+
+        // The tolerance is also checked when there is no actual value.
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &None,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.kind,
+            RunnerErrorKind::ExpressionInvalidType { .. }
+        ));
+    }
+
+    #[test]
+    fn test_predicate_is_empty_are_true() {
+        // predicate: `isEmpty`
+        // value: [1]
+        let value = Value::List(vec![]);
+        let assert_result = eval_is_empty(&value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "count equals to 0");
+        assert_eq!(assert_result.expected, "count equals to 0");
+
+        // predicate: `isEmpty`
+        // value: Nodeset(0)
+        let value = Value::Nodeset(0);
+        let assert_result = eval_is_empty(&value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "count equals to 0");
+        assert_eq!(assert_result.expected, "count equals to 0");
+    }
+
+    #[test]
+    fn test_predicate_is_null() {
+        // predicate: `isNull`
+        // value: null
+        let value = Value::Null;
+        let assert_result = eval_is_null(&value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "null");
+        assert_eq!(assert_result.expected, "null");
+
+        // predicate: `isNull`
+        // value: 1
+        let value = Value::Number(Number::Integer(1));
+        let assert_result = eval_is_null(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "int <1>");
+        assert_eq!(assert_result.expected, "null");
+
+        // predicate: `isNotNull`
+        // value: null
+        let value = Value::Null;
+        let assert_result = eval_is_not_null(&value).unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(assert_result.actual, "null");
+        assert_eq!(assert_result.expected, "not null");
+
+        // predicate: `isNotNull`
+        // value: "Dune"
+        let value = Value::String("Dune".to_string());
+        let assert_result = eval_is_not_null(&value).unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.actual, "string <Dune>");
+    }
+
+    #[test]
+    fn test_predicate_is_true_is_false() {
+        // predicate: `isTrue`
+        // value: true
+        let value = Value::Bool(true);
+        let assert_result = eval_is_true(&value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "bool <true>");
+        assert_eq!(assert_result.expected, "bool <true>");
+
+        // predicate: `isFalse`
+        // value: true
+        let assert_result = eval_is_false(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "bool <true>");
+        assert_eq!(assert_result.expected, "bool <false>");
+
+        // predicate: `isTrue`
+        // value: "true"
+        let value = Value::String("true".to_string());
+        let assert_result = eval_is_true(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "string <true>");
+    }
+
+    #[test]
+    fn test_predicate_is_cookie_secure_compliant() {
+        // predicate: `isCookieSecureCompliant`
+        // value: "id=a3fWa; SameSite=None"
+        let value = Value::String("id=a3fWa; SameSite=None".to_string());
+        let assert_result = eval_is_cookie_secure_compliant(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "string <id=a3fWa; SameSite=None>");
+        assert_eq!(
+            assert_result.expected,
+            "cookie with Secure attribute when SameSite=None"
+        );
+
+        // value: "id=a3fWa; SameSite=none; Secure"
+        let value = Value::String("id=a3fWa; SameSite=none; Secure".to_string());
+        let assert_result = eval_is_cookie_secure_compliant(&value).unwrap();
+        assert!(assert_result.success);
+
+        // value: "id=a3fWa; SameSite=Lax"
+        let value = Value::String("id=a3fWa; SameSite=Lax".to_string());
+        let assert_result = eval_is_cookie_secure_compliant(&value).unwrap();
+        assert!(assert_result.success);
+
+        // value: {"name": "id", "same_site": "None"}
+        let value = Value::Object(vec![
+            ("name".to_string(), Value::String("id".to_string())),
+            ("same_site".to_string(), Value::String("None".to_string())),
+        ]);
+        let assert_result = eval_is_cookie_secure_compliant(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        // value: ["id=a3fWa; SameSite=None; Secure", "lang=en; SameSite=None"]
+        let value = Value::List(vec![
+            Value::String("id=a3fWa; SameSite=None; Secure".to_string()),
+            Value::String("lang=en; SameSite=None".to_string()),
+        ]);
+        let assert_result = eval_is_cookie_secure_compliant(&value).unwrap();
+        assert!(!assert_result.success);
+
+        // value: 1
+        let value = Value::Number(Number::Integer(1));
+        let assert_result = eval_is_cookie_secure_compliant(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+        assert_eq!(assert_result.expected, "cookie");
+    }
+
+    #[test]
+    fn test_predicate_is_true_type_mismatch() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+
+        // predicate: `not isTrue`
+        // value: 1
+        let predicate = Predicate {
+            not: true,
+            space0: whitespace(),
+            predicate_func: PredicateFunc {
+                source_info: SourceInfo::new(Pos::new(1, 0), Pos::new(1, 0)),
+                value: PredicateFuncValue::IsTrue,
+            },
+        };
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &Some(Value::Number(Number::Integer(1))),
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::AssertFailure {
+                actual: "int <1>".to_string(),
+                expected: "not bool <true>".to_string(),
+                type_mismatch: true,
+                actual_value: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_predicate_type() {
+        // predicate: `isInteger`
+        // value: 1
+        let value = Value::Number(Number::Integer(1));
+        let assert_result = eval_is_integer(&value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "int <1>");
+        assert_eq!(assert_result.expected, "integer");
+
+        // predicate: `isInteger`
+        // value: 1
+        let value = Value::Number(Number::Float(1.0));
+        let assert_result = eval_is_integer(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "float <1.0>");
+        assert_eq!(assert_result.expected, "integer");
+    }
+
+    #[test]
+    fn test_predicate_not_with_different_types() {
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+
+        // equals predicate does not generate a type error with an integer value
+        // predicate: `not == null`
+        // value: 1
+        let predicate = Predicate {
+            not: true,
+            space0: whitespace(),
+            predicate_func: PredicateFunc {
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                value: PredicateFuncValue::Equal {
+                    space0: whitespace(),
+                    operator: false,
+                    value: PredicateValue::Null,
+                },
+            },
+        };
+
+        let variables = VariableSet::new();
+        assert!(eval_predicate(
+            &predicate,
+            &variables,
+            &Some(Value::Number(Number::Integer(1))),
+            &context_dir,
+            &mut EvalContext::default()
+        )
+        .is_ok());
+
+        // startswith predicate generates a type error with an integer value
+        // predicate: `not startWith "toto"`
+        // value: 1
+        let predicate = Predicate {
+            not: true,
+            space0: whitespace(),
+            predicate_func: PredicateFunc {
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                value: PredicateFuncValue::StartWith {
+                    space0: whitespace(),
+                    value: PredicateValue::String(Template {
+                        delimiter: None,
+                        elements: vec![TemplateElement::String {
+                            value: "toto".to_string(),
+                            encoded: "toto".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    }),
+                },
+            },
+        };
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &Some(Value::Number(Number::Integer(1))),
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::AssertFailure {
+                actual: "int <1>".to_string(),
+                expected: "not starts with string <toto>".to_string(),
+                type_mismatch: true,
+                actual_value: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_date_predicate() {
+        // predicate: `isDate`
+        // value: 2002-06-16T10:10:10
+        let value = Value::Date(
+            chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2002, 6, 16, 10, 10, 10).unwrap(),
+        );
+        let assert_result = eval_is_date(&value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "date <2002-06-16 10:10:10 UTC>");
+        assert_eq!(assert_result.expected, "date");
+
+        // predicate: `isDate`
+        // value: "toto"
+        let value = Value::String("toto".to_string());
+        let assert_result = eval_is_date(&value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "string <toto>");
+        assert_eq!(assert_result.expected, "date");
+    }
+
+    #[test]
+    fn test_no_type_mismatch_with_none_value() {
         let variables = VariableSet::new();
         let current_dir = std::env::current_dir().unwrap();
         let file_root = Path::new("file_root");
@@ -1534,15 +2738,22 @@ mod tests {
             },
         };
 
-        let error = eval_predicate(&predicate, &variables, &None, &context_dir)
-            .err()
-            .unwrap();
+        let error = eval_predicate(
+            &predicate,
+            &variables,
+            &None,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(
             error.kind,
             RunnerErrorKind::AssertFailure {
                 actual: "none".to_string(),
                 expected: "null".to_string(),
                 type_mismatch: false,
+                actual_value: None,
             }
         );
 
@@ -1561,7 +2772,14 @@ mod tests {
         };
 
         let variables = VariableSet::new();
-        assert!(eval_predicate(&predicate, &variables, &None, &context_dir).is_ok());
+        assert!(eval_predicate(
+            &predicate,
+            &variables,
+            &None,
+            &context_dir,
+            &mut EvalContext::default()
+        )
+        .is_ok());
     }
 
     #[test]
@@ -1574,14 +2792,483 @@ mod tests {
             inner: regex::Regex::new(r#"a{3}"#).unwrap(),
         });
         let value = Value::String("aa".to_string());
-        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
-        let assert_result = eval_match(&expected, source_info, &variables, &value).unwrap();
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            false,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
         assert!(!assert_result.success);
         assert!(!assert_result.type_mismatch);
-        assert_eq!(assert_result.actual, "string <aa>");
+        assert_eq!(assert_result.actual, "string <aa> (no match)");
         assert_eq!(assert_result.expected, "matches regex <a{3}>");
     }
 
+    #[test]
+    fn test_predicate_match_unicode_class() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+
+        // predicate: `matches /^\p{L}+$/`
+        let expected = PredicateValue::Regex(Regex {
+            inner: regex::Regex::new(r"^\p{L}+$").unwrap(),
+        });
+        let value = Value::String("Éloïse".to_string());
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            false,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(assert_result.success);
+        let value = Value::String("Éloïse2".to_string());
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            false,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+
+        // predicate: `matches "^\\p{N}+$"`
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: r"^\p{N}+$".to_string(),
+                encoded: r"^\\p{N}+$".to_string(),
+            }],
+            source_info,
+        });
+        let value = Value::String("١٢٣".to_string());
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            false,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(assert_result.success);
+    }
+
+    #[test]
+    fn test_predicate_contains_subset() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 15));
+        let actual = Value::Object(vec![
+            ("id".to_string(), Value::Number(Number::Integer(1))),
+            ("name".to_string(), Value::String("Bob".to_string())),
+            (
+                "address".to_string(),
+                Value::Object(vec![
+                    ("city".to_string(), Value::String("Lyon".to_string())),
+                    ("zip".to_string(), Value::String("69000".to_string())),
+                ]),
+            ),
+            (
+                "tags".to_string(),
+                Value::List(vec![Value::String("admin".to_string())]),
+            ),
+        ]);
+        let object = |s: &str| {
+            let mut reader = hurl_core::reader::Reader::new(s);
+            hurl_core::parser::parse_json(&mut reader).unwrap()
+        };
+
+        // predicate: `containsSubset {"name": "Bob", "address": {"city": "Lyon"}, "tags": ["admin"]}`
+        let expected = object(r#"{"name": "Bob", "address": {"city": "Lyon"}, "tags": ["admin"]}"#);
+        let assert_result =
+            eval_contains_subset(&expected, source_info, &variables, &actual).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        // predicate: `containsSubset {"name": "Bob", "address": {"city": "Paris"}}`
+        let expected = object(r#"{"name": "Bob", "address": {"city": "Paris"}}"#);
+        let assert_result =
+            eval_contains_subset(&expected, source_info, &variables, &actual).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "string <Lyon> at path $.address.city");
+
+        // predicate: `containsSubset {"email": "bob@example.org"}`
+        let expected = object(r#"{"email": "bob@example.org"}"#);
+        let assert_result =
+            eval_contains_subset(&expected, source_info, &variables, &actual).unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(assert_result.actual, "no value at path $.email");
+
+        // value: a string
+        let assert_result = eval_contains_subset(
+            &expected,
+            source_info,
+            &variables,
+            &Value::String("Bob".to_string()),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_match_span() {
+        let variables = VariableSet::new();
+
+        // predicate: `not matches /\d+/`
+        // value: café 2024
+        let expected = PredicateValue::Regex(Regex {
+            inner: regex::Regex::new(r#"\d+"#).unwrap(),
+        });
+        let value = Value::String("café 2024".to_string());
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            true,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(
+            assert_result.actual,
+            "string <café 2024> (match at characters 5..9)"
+        );
+    }
+
+    #[test]
+    fn test_predicate_match_line() {
+        let variables = VariableSet::new();
+        let value = Value::String(
+            "INFO starting\nINFO listening\nERROR disk full\nINFO stopping".to_string(),
+        );
+
+        // predicate: `not matches /ERROR/`
+        let expected = PredicateValue::Regex(Regex {
+            inner: regex::Regex::new("ERROR").unwrap(),
+        });
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            true,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(assert_result.success);
+        assert!(assert_result
+            .actual
+            .ends_with("(match at characters 29..34, line 3)"));
+
+        // predicate: `anyLineMatches /^ERROR .*full$/`
+        let expected = PredicateValue::Regex(Regex {
+            inner: regex::Regex::new("^ERROR .*full$").unwrap(),
+        });
+        let assert_result =
+            eval_any_line_match(&expected, &variables, &value, &mut EvalContext::default())
+                .unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert!(assert_result.actual.ends_with("(match at line 3)"));
+        assert_eq!(
+            assert_result.expected,
+            "any line matches regex <^ERROR .*full$>"
+        );
+
+        // predicate: `anyLineMatches /^WARN/`
+        let expected = PredicateValue::Regex(Regex {
+            inner: regex::Regex::new("^WARN").unwrap(),
+        });
+        let assert_result =
+            eval_any_line_match(&expected, &variables, &value, &mut EvalContext::default())
+                .unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.actual.ends_with("(no line match)"));
+
+        // value: 1
+        let assert_result = eval_any_line_match(
+            &expected,
+            &variables,
+            &Value::Number(Number::Integer(1)),
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_matches_all() {
+        let variables = VariableSet::new();
+        let value = Value::String("s3cretPassw0rd".to_string());
+
+        // predicate: `matchesAll /\d/ /[a-z]/ /^.{8,}$/`
+        let patterns = ["\\d", "[a-z]", "^.{8,}$"]
+            .iter()
+            .map(|pattern| {
+                let value = PredicateValue::Regex(Regex {
+                    inner: regex::Regex::new(pattern).unwrap(),
+                });
+                (whitespace(), value)
+            })
+            .collect::<Vec<_>>();
+        let assert_result =
+            eval_matches_all(&patterns, &variables, &value, &mut EvalContext::default()).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert!(assert_result.actual.ends_with("(all regexes match)"));
+        assert_eq!(
+            assert_result.expected,
+            "matches all regexes <\\d>, <[a-z]>, <^.{8,}$>"
+        );
+
+        // predicate: `matchesAll /\d/ /[A-Z]{2}/ /^.{8,}$/`
+        let patterns = ["\\d", "[A-Z]{2}", "^.{8,}$"]
+            .iter()
+            .map(|pattern| {
+                let value = PredicateValue::Regex(Regex {
+                    inner: regex::Regex::new(pattern).unwrap(),
+                });
+                (whitespace(), value)
+            })
+            .collect::<Vec<_>>();
+        let assert_result =
+            eval_matches_all(&patterns, &variables, &value, &mut EvalContext::default()).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert!(assert_result
+            .actual
+            .ends_with("(no match for regex <[A-Z]{2}>)"));
+
+        // value: 1
+        let assert_result = eval_matches_all(
+            &patterns,
+            &variables,
+            &Value::Number(Number::Integer(1)),
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
+
+    #[test]
+    fn test_predicate_match_invalid_regex() {
+        let variables = VariableSet::new();
+        let current_dir = std::env::current_dir().unwrap();
+        let file_root = Path::new("file_root");
+        let context_dir = ContextDir::new(current_dir.as_path(), file_root);
+
+        // predicate: `matches "["`, the error points to the pattern, not to the predicate.
+        let pred_func = PredicateFunc {
+            value: PredicateFuncValue::Match {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 29), Pos::new(1, 30)),
+                },
+                value: PredicateValue::String(Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: "[".to_string(),
+                        encoded: "[".to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 30), Pos::new(1, 33)),
+                }),
+            },
+            source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 33)),
+        };
+        let value = Value::String("Hello".to_string());
+        let error = eval_predicate_func(
+            &pred_func,
+            &variables,
+            Some(&value),
+            false,
+            &context_dir,
+            &mut EvalContext::default(),
+        )
+        .unwrap_err();
+        assert_eq!(error.kind, RunnerErrorKind::InvalidRegex);
+        assert_eq!(
+            error.source_info,
+            SourceInfo::new(Pos::new(1, 30), Pos::new(1, 33))
+        );
+    }
+
+    #[test]
+    fn test_predicate_match_truncated_actual() {
+        let variables = VariableSet::new();
+
+        // predicate: `matches /^\{/`
+        // value: a long HTML body
+        let expected = PredicateValue::Regex(Regex {
+            inner: regex::Regex::new(r#"^\{"#).unwrap(),
+        });
+        let body = format!("<!DOCTYPE html><html>{}</html>", "é".repeat(200));
+        let value = Value::String(body);
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            false,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(
+            assert_result.actual,
+            format!(
+                "string <<!DOCTYPE html><html>{}...> (truncated, 228 characters) (no match)",
+                "é".repeat(59)
+            )
+        );
+    }
+
+    #[test]
+    fn test_predicate_match_actual_display() {
+        let variables = VariableSet::new();
+
+        // predicate: `matches /b+/`
+        // value: abbc
+        let expected = PredicateValue::Regex(Regex {
+            inner: regex::Regex::new(r#"b+"#).unwrap(),
+        });
+        let value = Value::String("abbc".to_string());
+
+        // A successful match is not displayed...
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            false,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(assert_result.success);
+        assert_eq!(assert_result.actual, "");
+
+        // ...unless the predicate is negated, the assert failing.
+        let assert_result = eval_match(
+            &expected,
+            &variables,
+            &value,
+            true,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert!(assert_result.success);
+        assert_eq!(
+            assert_result.actual,
+            "string <abbc> (match at characters 1..3)"
+        );
+    }
+
+    #[test]
+    fn test_predicate_match_regex_cache() {
+        let value = Value::String("id-42".to_string());
+
+        // predicate: `matches "{{prefix}}-\\d+"`
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![
+                TemplateElement::Placeholder(Placeholder {
+                    space0: whitespace(),
+                    expr: Expr {
+                        kind: ExprKind::Variable(Variable {
+                            name: "prefix".to_string(),
+                            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                        }),
+                        source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+                    },
+                    space1: whitespace(),
+                }),
+                TemplateElement::String {
+                    value: r"-\d+".to_string(),
+                    encoded: r"-\\d+".to_string(),
+                },
+            ],
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        });
+
+        // The same expanded pattern evaluated in a loop is compiled once.
+        let mut variables = VariableSet::new();
+        variables
+            .insert("prefix".to_string(), Value::String("id".to_string()))
+            .unwrap();
+        let mut eval_context = EvalContext::default();
+        for _ in 0..100 {
+            let assert_result =
+                eval_match(&expected, &variables, &value, false, &mut eval_context).unwrap();
+            assert!(assert_result.success);
+        }
+        assert_eq!(eval_context.regex_cache_len(), 1);
+
+        // A different expanded pattern is compiled once more.
+        variables
+            .insert("prefix".to_string(), Value::String("user".to_string()))
+            .unwrap();
+        for _ in 0..100 {
+            let assert_result =
+                eval_match(&expected, &variables, &value, false, &mut eval_context).unwrap();
+            assert!(!assert_result.success);
+        }
+        assert_eq!(eval_context.regex_cache_len(), 2);
+    }
+
+    #[test]
+    fn test_predicate_match_glob() {
+        let variables = VariableSet::new();
+        let source_info = SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0));
+
+        // predicate: `matchesGlob "/api/*/users"`
+        // value: /api/v1/users
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: "/api/*/users".to_string(),
+                encoded: "/api/*/users".to_string(),
+            }],
+            source_info,
+        });
+        let value = Value::String("/api/v1/users".to_string());
+        let assert_result = eval_match_glob(&expected, source_info, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual, "string </api/v1/users>");
+        assert_eq!(assert_result.expected, "matches glob </api/*/users>");
+
+        let value = Value::String("/api/v1/orders".to_string());
+        let assert_result = eval_match_glob(&expected, source_info, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        let value = Value::Number(Number::Integer(1));
+        let assert_result = eval_match_glob(&expected, source_info, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+
+        // predicate: `matchesGlob "v[12]?"`
+        let expected = PredicateValue::String(Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: "v[12]?".to_string(),
+                encoded: "v[12]?".to_string(),
+            }],
+            source_info,
+        });
+        let value = Value::String("v2a".to_string());
+        let assert_result = eval_match_glob(&expected, source_info, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        let value = Value::String("v3a".to_string());
+        let assert_result = eval_match_glob(&expected, source_info, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+    }
+
     #[test]
     fn test_predicate_is_iso_date() {
         let value = Value::String("2020-03-09T22:18:26.625Z".to_string());
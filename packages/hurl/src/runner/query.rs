@@ -19,46 +19,72 @@ use hurl_core::ast::{
     CertificateAttributeName, CookieAttribute, CookieAttributeName, CookiePath, Query, QueryValue,
     RegexValue, SourceInfo, Template,
 };
-use regex::Regex;
 use sha2::Digest;
 
 use crate::http;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::eval_context::EvalContext;
+use crate::runner::regex::eval_regex_value;
 use crate::runner::template::eval_template;
 use crate::runner::xpath::{Document, Format};
 use crate::runner::{filter, Number, Value, VariableSet};
 
 pub type QueryResult = Result<Option<Value>, RunnerError>;
 
-/// Evaluates this `query` and returns a [`QueryResult`], using the HTTP `response` and `variables`.
+/// Evaluates this `query` and returns a [`QueryResult`], using the HTTP `request` that has been
+/// sent, the HTTP `response` and `variables`.
 pub fn eval_query(
     query: &Query,
     variables: &VariableSet,
+    request: &http::Request,
     response: &http::Response,
     cache: &mut BodyCache,
+    eval_context: &mut EvalContext,
 ) -> QueryResult {
     match &query.value {
         QueryValue::Status => eval_query_status(response),
         QueryValue::Url => eval_query_url(response),
         QueryValue::Header { name, .. } => eval_query_header(response, name, variables),
+        QueryValue::Headers { name, .. } => eval_query_headers(response, name, variables),
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
         } => eval_query_cookie(response, name, attribute, variables),
         QueryValue::Body => eval_query_body(response, query.source_info),
-        QueryValue::Xpath { expr, .. } => {
-            eval_query_xpath(response, cache, expr, variables, query.source_info)
-        }
-        QueryValue::Jsonpath { expr, .. } => {
-            eval_query_jsonpath(response, cache, expr, variables, query.source_info)
-        }
+        QueryValue::Xpath { expr, .. } => eval_query_xpath(
+            response,
+            cache,
+            expr,
+            variables,
+            query.source_info,
+            eval_context,
+        ),
+        QueryValue::Jsonpath { expr, .. } => eval_query_jsonpath(
+            response,
+            cache,
+            expr,
+            variables,
+            query.source_info,
+            eval_context,
+        ),
         QueryValue::Regex { value, .. } => {
-            eval_query_regex(response, value, variables, query.source_info)
+            eval_query_regex(response, value, variables, query.source_info, eval_context)
         }
         QueryValue::Variable { name, .. } => eval_query_variable(name, variables),
         QueryValue::Duration => eval_query_duration(response),
         QueryValue::Bytes => eval_query_bytes(response, query.source_info),
+        QueryValue::RawBytes => eval_query_raw_bytes(response),
+        QueryValue::DetectedContentType => {
+            eval_query_detected_content_type(response, query.source_info)
+        }
+        QueryValue::TransferEncoding => eval_query_transfer_encoding(response),
+        QueryValue::RawResponse => eval_query_raw_response(response),
+        QueryValue::RequestBody => eval_query_request_body(request),
+        QueryValue::RequestHeader { name, .. } => {
+            eval_query_request_header(request, name, variables)
+        }
+        QueryValue::QueryParam { name, .. } => eval_query_param(request, name, variables),
         QueryValue::Sha256 => eval_query_sha256(response, query.source_info),
         QueryValue::Md5 => eval_query_md5(response, query.source_info),
         QueryValue::Certificate {
@@ -102,6 +128,26 @@ fn eval_query_header(
     }
 }
 
+/// Evaluates all the values of a response header `name`, on the HTTP `response` given a set of
+/// `variables`.
+///
+/// Contrary to the `header` query, a list is always returned, even if the header is absent or
+/// present only once, so a specific occurrence can be selected with `nth`.
+fn eval_query_headers(
+    response: &http::Response,
+    name: &Template,
+    variables: &VariableSet,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    let values = response
+        .headers
+        .values(&name)
+        .iter()
+        .map(|v| Value::String(v.to_string()))
+        .collect();
+    Ok(Some(Value::List(values)))
+}
+
 /// Evaluates a cookie query `name` with optional attributes, on the HTTP `response` given a set of `variables`.
 fn eval_query_cookie(
     response: &http::Response,
@@ -147,12 +193,13 @@ fn eval_query_xpath(
     expr: &Template,
     variables: &VariableSet,
     query_source_info: SourceInfo,
+    eval_context: &EvalContext,
 ) -> QueryResult {
     let doc = match cache.xml() {
         Some(d) => d,
         None => parse_cache_xml(response, cache, query_source_info)?,
     };
-    filter::eval_xpath_doc(doc, expr, variables)
+    filter::eval_xpath_doc(doc, expr, variables, eval_context)
 }
 
 /// Parse this HTTP `response` body to a structured XML document, and store the document to the
@@ -201,12 +248,13 @@ fn eval_query_jsonpath(
     expr: &Template,
     variables: &VariableSet,
     query_source_info: SourceInfo,
+    eval_context: &EvalContext,
 ) -> QueryResult {
     let json = match cache.json() {
         Some(j) => j,
         None => parse_cache_json(response, cache, query_source_info)?,
     };
-    filter::eval_jsonpath_json(json, expr, variables)
+    filter::eval_jsonpath_json(json, expr, variables, eval_context)
 }
 
 /// Parse this HTTP `response` body to JSON, and store the document to the response `cache`.
@@ -251,6 +299,7 @@ fn eval_query_regex(
     regex: &RegexValue,
     variables: &VariableSet,
     query_source_info: SourceInfo,
+    eval_context: &mut EvalContext,
 ) -> QueryResult {
     let s = match response.text() {
         Ok(v) => v,
@@ -262,22 +311,7 @@ fn eval_query_regex(
             ))
         }
     };
-    let re = match regex {
-        RegexValue::Template(t) => {
-            let value = eval_template(t, variables)?;
-            match Regex::new(value.as_str()) {
-                Ok(re) => re,
-                Err(_) => {
-                    return Err(RunnerError::new(
-                        t.source_info,
-                        RunnerErrorKind::InvalidRegex,
-                        false,
-                    ))
-                }
-            }
-        }
-        RegexValue::Regex(re) => re.inner.clone(),
-    };
+    let re = eval_regex_value(regex, variables, eval_context)?;
     match re.captures(s.as_str()) {
         Some(captures) => match captures.get(1) {
             Some(v) => Ok(Some(Value::String(v.as_str().to_string()))),
@@ -319,6 +353,144 @@ fn eval_query_bytes(response: &http::Response, query_source_info: SourceInfo) ->
     }
 }
 
+/// Evaluates the HTTP `response` body as raw bytes, as received on the wire.
+///
+/// Contrary to [`eval_query_bytes`], the body is not decompressed according to the
+/// `Content-Encoding` response header.
+fn eval_query_raw_bytes(response: &http::Response) -> QueryResult {
+    Ok(Some(Value::Bytes(response.body.clone())))
+}
+
+/// Evaluates the media type of the HTTP `response` body, sniffed from its content.
+///
+/// Contrary to the `Content-Type` response header, the media type is detected from the first
+/// non-whitespace bytes of the (decompressed) body:
+/// - `{` or `[`: `application/json`
+/// - `<!doctype html` or `<html` (case insensitive): `text/html`
+/// - `<?xml` or any other `<`: `application/xml`
+/// - any other valid UTF-8 body: `text/plain`, otherwise `application/octet-stream`
+///
+/// `query_source_info` is the source position of the query, used if an error is returned.
+fn eval_query_detected_content_type(
+    response: &http::Response,
+    query_source_info: SourceInfo,
+) -> QueryResult {
+    let bytes = match response.uncompress_body() {
+        Ok(s) => s,
+        Err(inner) => {
+            return Err(RunnerError::new(
+                query_source_info,
+                RunnerErrorKind::Http(inner),
+                false,
+            ));
+        }
+    };
+    Ok(Some(Value::String(detect_content_type(&bytes).to_string())))
+}
+
+/// Evaluates the transfer encoding of the HTTP `response` (`chunked` for instance).
+///
+/// A response sent without transfer encoding has no query result.
+fn eval_query_transfer_encoding(response: &http::Response) -> QueryResult {
+    Ok(response.transfer_encoding().map(Value::String))
+}
+
+/// Returns the media type of `bytes`, sniffed from its leading bytes.
+fn detect_content_type(bytes: &[u8]) -> &'static str {
+    let bytes = bytes.strip_prefix(b"\xef\xbb\xbf").unwrap_or(bytes);
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let bytes = &bytes[start..];
+    let starts_with_ignore_case = |prefix: &[u8]| {
+        bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+    };
+    match bytes.first() {
+        Some(b'{') | Some(b'[') => "application/json",
+        Some(b'<')
+            if starts_with_ignore_case(b"<!doctype html") || starts_with_ignore_case(b"<html") =>
+        {
+            "text/html"
+        }
+        Some(b'<') => "application/xml",
+        _ if std::str::from_utf8(bytes).is_ok() => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Evaluates the HTTP `response` as raw text, as received on the wire: the status line, the
+/// headers (in their received order) and the body.
+///
+/// The body is not decompressed, and invalid UTF-8 sequences are replaced with `U+FFFD`.
+fn eval_query_raw_response(response: &http::Response) -> QueryResult {
+    let mut raw = format!("{} {}\r\n", response.version, response.status);
+    for header in &response.headers {
+        raw.push_str(&format!("{}: {}\r\n", header.name, header.value));
+    }
+    raw.push_str("\r\n");
+    raw.push_str(&String::from_utf8_lossy(&response.body));
+    Ok(Some(Value::String(raw)))
+}
+
+/// Evaluates the body of the HTTP `request` that has been sent, after templating.
+///
+/// The body is returned as a string if it's valid UTF-8, as bytes otherwise.
+fn eval_query_request_body(request: &http::Request) -> QueryResult {
+    match String::from_utf8(request.body.clone()) {
+        Ok(s) => Ok(Some(Value::String(s))),
+        Err(e) => Ok(Some(Value::Bytes(e.into_bytes()))),
+    }
+}
+
+/// Evaluates a header `name` of the HTTP `request` that has been sent, given a set of `variables`.
+///
+/// Headers names are case-insensitive. If the header is repeated, all its values are returned as a
+/// list.
+fn eval_query_request_header(
+    request: &http::Request,
+    name: &Template,
+    variables: &VariableSet,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    let mut values = request
+        .headers
+        .values(&name)
+        .iter()
+        .map(|v| Value::String(v.to_string()))
+        .collect::<Vec<_>>();
+    match values.len() {
+        0 => Ok(None),
+        1 => Ok(values.pop()),
+        _ => Ok(Some(Value::List(values))),
+    }
+}
+
+/// Evaluates a query string param `name` of the HTTP `request` that has been sent, given a set of
+/// `variables`.
+///
+/// Params are matched regardless of their order in the URL. If the param is repeated, all its
+/// values are returned as a list.
+fn eval_query_param(
+    request: &http::Request,
+    name: &Template,
+    variables: &VariableSet,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    let mut values = request
+        .url
+        .query_params()
+        .into_iter()
+        .filter(|param| param.name == name)
+        .map(|param| Value::String(param.value))
+        .collect::<Vec<_>>();
+    match values.len() {
+        0 => Ok(None),
+        1 => Ok(values.pop()),
+        _ => Ok(Some(Value::List(values))),
+    }
+}
+
 /// Evaluates the SHA-256 hash of the HTTP `response` body bytes.
 ///
 /// `query_source_info` is the source position of the query, used if an error is returned.
@@ -455,7 +627,7 @@ pub mod tests {
     use hurl_core::reader::Pos;
 
     use super::*;
-    use crate::http::{HeaderVec, HttpError, HttpVersion};
+    use crate::http::{Header, HeaderVec, HttpError, HttpVersion};
 
     fn default_response() -> http::Response {
         http::Response {
@@ -466,6 +638,7 @@ pub mod tests {
             duration: Default::default(),
             url: "http://localhost".parse().unwrap(),
             certificate: None,
+            tls_version: None,
         }
     }
 
@@ -678,8 +851,10 @@ pub mod tests {
                     value: QueryValue::Status,
                 },
                 &variables,
+                &http::default_http_request(),
                 &http::hello_http_response(),
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -717,14 +892,75 @@ pub mod tests {
             eval_query(
                 &query_header,
                 &variables,
+                &http::default_http_request(),
                 &http::hello_http_response(),
-                &mut cache
+                &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap(),
             None
         );
     }
 
+    #[test]
+    fn test_headers() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let headers_query = |name: &str| Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::Headers {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: name.to_string(),
+                        encoded: name.to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 9)),
+                },
+            },
+        };
+        let mut response = http::hello_http_response();
+        response
+            .headers
+            .push(http::Header::new("Set-Cookie", "theme=light"));
+        response
+            .headers
+            .push(http::Header::new("Set-Cookie", "token=abc123"));
+
+        let eval = |name: &str, cache: &mut BodyCache| {
+            eval_query(
+                &headers_query(name),
+                &variables,
+                &http::default_http_request(),
+                &response,
+                cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap()
+        };
+
+        // `headers "Set-Cookie"`
+        assert_eq!(
+            eval("Set-Cookie", &mut cache),
+            Value::List(vec![
+                Value::String("theme=light".to_string()),
+                Value::String("token=abc123".to_string()),
+            ])
+        );
+        // `headers "Content-Type"`: a single header is also returned as a list.
+        assert_eq!(
+            eval("Content-Type", &mut cache),
+            Value::List(vec![Value::String("text/html; charset=utf-8".to_string())])
+        );
+        // `headers "Custom"`
+        assert_eq!(eval("Custom", &mut cache), Value::List(vec![]));
+    }
+
     #[test]
     fn test_header() {
         // header Content-Type
@@ -752,8 +988,10 @@ pub mod tests {
             eval_query(
                 &query_header,
                 &variables,
+                &http::default_http_request(),
                 &http::hello_http_response(),
-                &mut cache
+                &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -797,9 +1035,16 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
             Value::String("DQAAAKEaem_vYg".to_string())
         );
 
@@ -826,9 +1071,16 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
             Value::String("/accounts".to_string())
         );
 
@@ -855,9 +1107,16 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache)
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
             Value::Unit
         );
 
@@ -884,7 +1143,15 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response, &mut cache).unwrap(),
+            eval_query(
+                &query,
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
             None
         );
     }
@@ -983,8 +1250,10 @@ pub mod tests {
                     value: QueryValue::Body,
                 },
                 &variables,
+                &http::default_http_request(),
                 &http::hello_http_response(),
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -996,8 +1265,10 @@ pub mod tests {
                 value: QueryValue::Body,
             },
             &variables,
+            &http::default_http_request(),
             &http::bytes_http_response(),
             &mut cache,
+            &mut EvalContext::default(),
         )
         .err()
         .unwrap();
@@ -1022,9 +1293,16 @@ pub mod tests {
             body: vec![200],
             ..default_response()
         };
-        let error = eval_query(&xpath_users(), &variables, &http_response, &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &xpath_users(),
+            &variables,
+            &http::default_http_request(),
+            &http_response,
+            &mut cache,
+            &mut EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(
             error.kind,
@@ -1060,11 +1338,18 @@ pub mod tests {
         let error = eval_query(
             &query,
             &variables,
+            &http::default_http_request(),
             &http::xml_two_users_http_response(),
             &mut cache,
+            &mut EvalContext::default(),
         )
         .unwrap_err();
-        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidXpathEval);
+        assert_eq!(
+            error.kind,
+            RunnerErrorKind::QueryInvalidXpathEval {
+                unbound_prefix: None
+            }
+        );
         assert_eq!(error.source_info.start, Pos { line: 1, column: 7 });
     }
 
@@ -1077,8 +1362,10 @@ pub mod tests {
             eval_query(
                 &xpath_users(),
                 &variables,
+                &http::default_http_request(),
                 &http::xml_two_users_http_response(),
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1088,12 +1375,14 @@ pub mod tests {
             eval_query(
                 &xpath_count_user_query(),
                 &variables,
+                &http::default_http_request(),
                 &http::xml_two_users_http_response(),
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
-            Value::Number(Number::Float(2.0))
+            Value::Number(Number::Integer(2))
         );
     }
 
@@ -1129,8 +1418,10 @@ pub mod tests {
             eval_query(
                 &xpath_html_charset(),
                 &variables,
+                &http::default_http_request(),
                 &http::html_http_response(),
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1165,20 +1456,22 @@ pub mod tests {
         let error = eval_query(
             &jsonpath_query,
             &variables,
+            &http::default_http_request(),
             &http::json_http_response(),
             &mut cache,
+            &mut EvalContext::default(),
         )
         .unwrap_err();
         assert_eq!(
             error.source_info.start,
             Pos {
                 line: 1,
-                column: 10,
+                column: 11,
             }
         );
         assert_eq!(
             error.kind,
-            RunnerErrorKind::QueryInvalidJsonpathExpression {
+            RunnerErrorKind::QueryInvalidJsonPath {
                 value: "xxx".to_string()
             }
         );
@@ -1192,13 +1485,78 @@ pub mod tests {
             body: String::into_bytes(String::from("xxx")),
             ..default_response()
         };
-        let error = eval_query(&jsonpath_success(), &variables, &http_response, &mut cache)
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &jsonpath_success(),
+            &variables,
+            &http::default_http_request(),
+            &http_response,
+            &mut cache,
+            &mut EvalContext::default(),
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
         assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
     }
 
+    #[test]
+    fn test_query_json_empty_body() {
+        let variables = VariableSet::new();
+        let http_response = http::Response {
+            body: vec![],
+            ..default_response()
+        };
+        // jsonpath "$"
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 13)),
+            value: QueryValue::Jsonpath {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 10)),
+                },
+                expr: Template {
+                    elements: vec![TemplateElement::String {
+                        value: String::from("$"),
+                        encoded: String::from("$"),
+                    }],
+                    delimiter: Some('"'),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 13)),
+                },
+            },
+        };
+
+        // By default, an empty body is not a valid JSON.
+        let mut cache = BodyCache::new();
+        let error = eval_query(
+            &query,
+            &variables,
+            &http::default_http_request(),
+            &http_response,
+            &mut cache,
+            &mut EvalContext::default(),
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.kind, RunnerErrorKind::QueryInvalidJson);
+
+        // With `--empty-body-as-null`, the cache is initialized with a JSON null.
+        let mut cache = BodyCache::new();
+        cache.set_json(serde_json::Value::Null);
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::default_http_request(),
+                &http_response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Null
+        );
+    }
+
     #[test]
     fn test_query_json_not_found() {
         let variables = VariableSet::new();
@@ -1209,7 +1567,15 @@ pub mod tests {
             ..default_response()
         };
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http_response, &mut cache).unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http::default_http_request(),
+                &http_response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
             None
         );
     }
@@ -1223,8 +1589,10 @@ pub mod tests {
             eval_query(
                 &jsonpath_success(),
                 &variables,
+                &http::default_http_request(),
                 &http::json_http_response(),
-                &mut cache
+                &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1234,8 +1602,10 @@ pub mod tests {
             eval_query(
                 &jsonpath_errors(),
                 &variables,
+                &http::default_http_request(),
                 &http::json_http_response(),
-                &mut cache
+                &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1261,8 +1631,10 @@ pub mod tests {
             eval_query(
                 &regex_name(),
                 &variables,
+                &http::default_http_request(),
                 &http::hello_http_response(),
-                &mut cache
+                &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1272,8 +1644,10 @@ pub mod tests {
         let error = eval_query(
             &regex_invalid(),
             &variables,
+            &http::default_http_request(),
             &http::hello_http_response(),
             &mut cache,
+            &mut EvalContext::default(),
         )
         .err()
         .unwrap();
@@ -1296,8 +1670,10 @@ pub mod tests {
                     value: QueryValue::Bytes,
                 },
                 &variables,
+                &http::default_http_request(),
                 &http::hello_http_response(),
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
@@ -1305,6 +1681,414 @@ pub mod tests {
         );
     }
 
+    fn gzip_http_response() -> http::Response {
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "text/plain; charset=utf-8"));
+        headers.push(Header::new("Content-Encoding", "gzip"));
+        http::Response {
+            headers,
+            body: vec![
+                0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0xf3, 0x48, 0xcd, 0xc9,
+                0xc9, 0x57, 0x08, 0xcf, 0x2f, 0xca, 0x49, 0x51, 0x04, 0x00, 0xa3, 0x1c, 0x29, 0x1c,
+                0x0c, 0x00, 0x00, 0x00,
+            ],
+            ..default_response()
+        }
+    }
+
+    #[test]
+    fn test_query_gzip_body() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let response = gzip_http_response();
+
+        // `body` and `bytes` are transparently decoded using `Content-Encoding`.
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::Body,
+                },
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("Hello World!".to_string())
+        );
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::Bytes,
+                },
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(b"Hello World!".to_vec())
+        );
+
+        // `rawBytes` bypasses decoding.
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::RawBytes,
+                },
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(response.body.clone())
+        );
+    }
+
+    #[test]
+    fn test_query_detected_content_type() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "text/plain"));
+        let response = http::Response {
+            headers,
+            body: b"  {\"id\": 1}".to_vec(),
+            ..default_response()
+        };
+
+        assert_eq!(
+            eval_query(
+                &Query {
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                    value: QueryValue::DetectedContentType,
+                },
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("application/json".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_transfer_encoding() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::TransferEncoding,
+        };
+
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Transfer-Encoding", "chunked"));
+        let response = http::Response {
+            version: HttpVersion::Http11,
+            headers,
+            body: b"Hello World!".to_vec(),
+            ..default_response()
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::default_http_request(),
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("chunked".to_string())
+        );
+
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &http::default_http_request(),
+                &default_response(),
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_raw_response() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Content-Type", "text/plain"));
+        headers.push(Header::new("Content-Length", "5"));
+        let response = http::Response {
+            version: HttpVersion::Http11,
+            status: 200,
+            headers,
+            body: b"Hello".to_vec(),
+            ..default_response()
+        };
+
+        let value = eval_query(
+            &Query {
+                source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                value: QueryValue::RawResponse,
+            },
+            &variables,
+            &http::default_http_request(),
+            &response,
+            &mut cache,
+            &mut EvalContext::default(),
+        )
+        .unwrap()
+        .unwrap();
+        let Value::String(raw) = value else {
+            panic!("raw response should be a string");
+        };
+        assert!(raw.contains("HTTP/1.1 200"));
+        assert_eq!(
+            raw,
+            "HTTP/1.1 200\r\nContent-Type: text/plain\r\nContent-Length: 5\r\n\r\nHello"
+        );
+    }
+
+    #[test]
+    fn test_query_request_body() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let query = Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::RequestBody,
+        };
+        let url = "http://localhost:8000/users".parse().unwrap();
+
+        let request = http::Request::new(
+            "POST",
+            url,
+            HeaderVec::new(),
+            b"{\"name\":\"Bob\"}".to_vec(),
+        );
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &request,
+                &http::hello_http_response(),
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("{\"name\":\"Bob\"}".to_string())
+        );
+
+        let request = http::Request {
+            body: vec![0xff, 0xfe],
+            ..request
+        };
+        assert_eq!(
+            eval_query(
+                &query,
+                &variables,
+                &request,
+                &http::hello_http_response(),
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Bytes(vec![0xff, 0xfe])
+        );
+    }
+
+    #[test]
+    fn test_query_param() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let url = "http://localhost:8000/users?tag=a&id=1&tag=b&name=Bob%20Smith"
+            .parse()
+            .unwrap();
+        let request = http::Request::new("GET", url, HeaderVec::new(), vec![]);
+        let query_param = |name: &str| Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::QueryParam {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: name.to_string(),
+                        encoded: name.to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let response = http::hello_http_response();
+
+        assert_eq!(
+            eval_query(
+                &query_param("id"),
+                &variables,
+                &request,
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            Some(Value::String("1".to_string()))
+        );
+        assert_eq!(
+            eval_query(
+                &query_param("name"),
+                &variables,
+                &request,
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            Some(Value::String("Bob Smith".to_string()))
+        );
+        assert_eq!(
+            eval_query(
+                &query_param("tag"),
+                &variables,
+                &request,
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            Some(Value::List(vec![
+                Value::String("a".to_string()),
+                Value::String("b".to_string())
+            ]))
+        );
+        assert_eq!(
+            eval_query(
+                &query_param("page"),
+                &variables,
+                &request,
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_request_header() {
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let mut headers = HeaderVec::new();
+        headers.push(Header::new("Host", "localhost:8000"));
+        headers.push(Header::new("User-Agent", "hurl/6.0.0"));
+        headers.push(Header::new("X-Tag", "a"));
+        headers.push(Header::new("x-tag", "b"));
+        let url = "http://localhost:8000/hello".parse().unwrap();
+        let request = http::Request::new("GET", url, headers, vec![]);
+        let request_header = |name: &str| Query {
+            source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+            value: QueryValue::RequestHeader {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+                name: Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: name.to_string(),
+                        encoded: name.to_string(),
+                    }],
+                    source_info: SourceInfo::new(Pos::new(0, 0), Pos::new(0, 0)),
+                },
+            },
+        };
+        let response = http::hello_http_response();
+
+        assert_eq!(
+            eval_query(
+                &request_header("User-Agent"),
+                &variables,
+                &request,
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            Some(Value::String("hurl/6.0.0".to_string()))
+        );
+        assert_eq!(
+            eval_query(
+                &request_header("x-tag"),
+                &variables,
+                &request,
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            Some(Value::List(vec![
+                Value::String("a".to_string()),
+                Value::String("b".to_string())
+            ]))
+        );
+        assert_eq!(
+            eval_query(
+                &request_header("Authorization"),
+                &variables,
+                &request,
+                &response,
+                &mut cache,
+                &mut EvalContext::default(),
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_detect_content_type() {
+        assert_eq!(detect_content_type(b"[1, 2]"), "application/json");
+        assert_eq!(
+            detect_content_type(b"\xef\xbb\xbf<?xml version=\"1.0\"?><a/>"),
+            "application/xml"
+        );
+        assert_eq!(detect_content_type(b"<feed></feed>"), "application/xml");
+        assert_eq!(
+            detect_content_type(b"\n<!DOCTYPE html><html></html>"),
+            "text/html"
+        );
+        assert_eq!(detect_content_type(b"<HTML><body/></HTML>"), "text/html");
+        assert_eq!(detect_content_type(b"Hello"), "text/plain");
+        assert_eq!(detect_content_type(b""), "text/plain");
+        assert_eq!(
+            detect_content_type(&[0xff, 0xfe]),
+            "application/octet-stream"
+        );
+    }
+
     #[test]
     fn test_query_sha256() {
         let variables = VariableSet::new();
@@ -1317,11 +2101,13 @@ pub mod tests {
                     value: QueryValue::Sha256 {},
                 },
                 &variables,
+                &http::default_http_request(),
                 &http::Response {
                     body: vec![0xff],
                     ..default_response()
                 },
                 &mut cache,
+                &mut EvalContext::default(),
             )
             .unwrap()
             .unwrap(),
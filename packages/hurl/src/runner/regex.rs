@@ -16,27 +16,109 @@
  *
  */
 use hurl_core::ast::RegexValue;
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 
+use crate::runner::eval_context::EvalContext;
 use crate::runner::template::eval_template;
 use crate::runner::{RunnerError, RunnerErrorKind, VariableSet};
 
+/// Default maximum size in bytes of a compiled regex (see [`RegexBuilder::size_limit`]).
+pub const DEFAULT_REGEX_SIZE_LIMIT: usize = 1024 * 1024;
+
+/// Compiles a regex `pattern` evaluated at runtime.
+///
+/// Regexes are compiled with the `regex` crate, whose matching runs in linear time in the size of
+/// the input, so a pathological pattern can't hang the runner. As a consequence, backreferences
+/// (`\1`, `\k<name>`) are not supported and are explicitly rejected. The size of the compiled
+/// regex is bounded by `size_limit` bytes. Unicode support is enabled (the `unicode` features are
+/// default features of the `regex` crate), so property classes like `\p{L}` can be used.
+pub fn new_regex(pattern: &str, size_limit: usize) -> Result<Regex, RunnerErrorKind> {
+    if has_backreference(pattern) {
+        let reason = "backreferences are not supported".to_string();
+        return Err(RunnerErrorKind::UnsupportedRegex(reason));
+    }
+    match RegexBuilder::new(pattern)
+        .unicode(true)
+        .size_limit(size_limit)
+        .dfa_size_limit(size_limit)
+        .build()
+    {
+        Ok(re) => Ok(re),
+        Err(regex::Error::CompiledTooBig(limit)) => {
+            let reason = format!("compiled regex exceeds the size limit of {limit} bytes");
+            Err(RunnerErrorKind::UnsupportedRegex(reason))
+        }
+        Err(_) => Err(RunnerErrorKind::InvalidRegex),
+    }
+}
+
+/// Returns true if this regex `pattern` uses a backreference (`\1` to `\9` or `\k<name>`).
+fn has_backreference(pattern: &str) -> bool {
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            continue;
+        }
+        match chars.next() {
+            Some('1'..='9') => return true,
+            Some('k') if chars.peek() == Some(&'<') => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
 pub fn eval_regex_value(
     regex_value: &RegexValue,
     variables: &VariableSet,
+    eval_context: &mut EvalContext,
 ) -> Result<Regex, RunnerError> {
     match regex_value {
         RegexValue::Template(t) => {
             let value = eval_template(t, variables)?;
-            match Regex::new(value.as_str()) {
+            match eval_context.new_regex(value.as_str()) {
                 Ok(re) => Ok(re),
-                Err(_) => Err(RunnerError::new(
-                    t.source_info,
-                    RunnerErrorKind::InvalidRegex,
-                    false,
-                )),
+                Err(kind) => Err(RunnerError::new(t.source_info, kind, false)),
             }
         }
         RegexValue::Regex(re) => Ok(re.inner.clone()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_regex() {
+        assert!(new_regex(r"^\d{4}-\d{2}$", DEFAULT_REGEX_SIZE_LIMIT)
+            .unwrap()
+            .is_match("2024-01"));
+        assert_eq!(
+            new_regex("[", DEFAULT_REGEX_SIZE_LIMIT).unwrap_err(),
+            RunnerErrorKind::InvalidRegex
+        );
+    }
+
+    #[test]
+    fn test_new_regex_backreference() {
+        assert_eq!(
+            new_regex(r"(a)\1", DEFAULT_REGEX_SIZE_LIMIT).unwrap_err(),
+            RunnerErrorKind::UnsupportedRegex("backreferences are not supported".to_string())
+        );
+        assert_eq!(
+            new_regex(r"(?<x>a)\k<x>", DEFAULT_REGEX_SIZE_LIMIT).unwrap_err(),
+            RunnerErrorKind::UnsupportedRegex("backreferences are not supported".to_string())
+        );
+        // Escaped backslash followed by a digit is not a backreference.
+        assert!(new_regex(r"\\1", DEFAULT_REGEX_SIZE_LIMIT)
+            .unwrap()
+            .is_match(r"\1"));
+    }
+
+    #[test]
+    fn test_new_regex_too_large() {
+        let error = new_regex(r"(?:\w{100}){100}", DEFAULT_REGEX_SIZE_LIMIT).unwrap_err();
+        assert!(matches!(error, RunnerErrorKind::UnsupportedRegex(_)));
+    }
+}
@@ -20,6 +20,7 @@ use hurl_core::ast::{Base64, Body, Bytes, Hex, Response, SourceInfo, StatusValue
 use crate::http;
 use crate::runner::cache::BodyCache;
 use crate::runner::error::{RunnerError, RunnerErrorKind};
+use crate::runner::eval_context::EvalContext;
 use crate::runner::result::{AssertResult, CaptureResult};
 use crate::runner::{assert, body, capture, json, multiline, template, Value, VariableSet};
 use crate::util::path::ContextDir;
@@ -57,12 +58,21 @@ pub fn eval_version_status_asserts(
 ///
 /// The `cache` is used to store XML / JSON structured response data and avoid redundant parsing
 /// operation on the response.
+///
+/// When `json_float_precision` is set, JSON bodies are compared structurally, floats being equal
+/// up to this number of decimal places. When `ignore_header_case` is set, implicit header asserts
+/// compare values ignoring ASCII case.
+#[allow(clippy::too_many_arguments)]
 pub fn eval_asserts(
     response: &Response,
     variables: &VariableSet,
+    http_request: &http::Request,
     http_response: &http::Response,
     cache: &mut BodyCache,
     context_dir: &ContextDir,
+    json_float_precision: Option<u32>,
+    ignore_header_case: bool,
+    eval_context: &mut EvalContext,
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
 
@@ -74,6 +84,7 @@ pub fn eval_asserts(
                     actual: Err(e),
                     expected: String::new(),
                     source_info: header.key.source_info,
+                    ignore_case: ignore_header_case,
                 };
                 asserts.push(result);
             }
@@ -90,6 +101,7 @@ pub fn eval_asserts(
                                 )),
                                 expected,
                                 source_info: header.key.source_info,
+                                ignore_case: ignore_header_case,
                             };
                             asserts.push(result);
                         } else if actuals.len() == 1 {
@@ -98,6 +110,7 @@ pub fn eval_asserts(
                                 actual: Ok(actual),
                                 expected,
                                 source_info: header.value.source_info,
+                                ignore_case: ignore_header_case,
                             };
                             asserts.push(result);
                         } else {
@@ -113,7 +126,8 @@ pub fn eval_asserts(
                                     .join(", ")
                             );
                             for value in actuals {
-                                if value == expected {
+                                if assert::header_value_equal(value, &expected, ignore_header_case)
+                                {
                                     actual = value.to_string();
                                     break;
                                 }
@@ -122,6 +136,7 @@ pub fn eval_asserts(
                                 actual: Ok(actual),
                                 expected,
                                 source_info: header.value.source_info,
+                                ignore_case: ignore_header_case,
                             };
                             asserts.push(result);
                         }
@@ -131,6 +146,7 @@ pub fn eval_asserts(
                             actual: Err(e),
                             expected,
                             source_info: header.value.source_info,
+                            ignore_case: ignore_header_case,
                         };
                         asserts.push(result);
                     }
@@ -141,14 +157,27 @@ pub fn eval_asserts(
 
     // Second, evaluates implicit asserts on response body.
     if let Some(body) = &response.body {
-        let assert = eval_implicit_body_asserts(body, variables, http_response, context_dir);
+        let assert = eval_implicit_body_asserts(
+            body,
+            variables,
+            http_response,
+            context_dir,
+            json_float_precision,
+        );
         asserts.push(assert);
     }
 
     // Then, checks all the explicit asserts.
     for assert in response.asserts() {
-        let assert_result =
-            assert::eval_explicit_assert(assert, variables, http_response, cache, context_dir);
+        let assert_result = assert::eval_explicit_assert(
+            assert,
+            variables,
+            http_request,
+            http_response,
+            cache,
+            context_dir,
+            eval_context,
+        );
         asserts.push(assert_result);
     }
     asserts
@@ -160,6 +189,7 @@ fn eval_implicit_body_asserts(
     variables: &VariableSet,
     http_response: &http::Response,
     context_dir: &ContextDir,
+    json_float_precision: Option<u32>,
 ) -> AssertResult {
     match &spec_body.value {
         Bytes::Json(value) => {
@@ -181,6 +211,16 @@ fn eval_implicit_body_asserts(
                     ))
                 }
             };
+            // Within the float precision, an actual body equal to the expected one is reported as
+            // the expected value so the assert succeeds.
+            let actual = match (&expected, actual, json_float_precision) {
+                (Ok(Value::String(e)), Ok(Value::String(a)), Some(precision))
+                    if json_text_equal(e, &a, precision) =>
+                {
+                    Ok(Value::String(e.clone()))
+                }
+                (_, actual, _) => actual,
+            };
             AssertResult::Body {
                 actual,
                 expected,
@@ -350,13 +390,22 @@ fn eval_implicit_body_asserts(
 /// Evaluates captures from this HTTP `http_response`, given a set of `variables`.
 pub fn eval_captures(
     response: &Response,
+    http_request: &http::Request,
     http_response: &http::Response,
     cache: &mut BodyCache,
     variables: &mut VariableSet,
+    eval_context: &mut EvalContext,
 ) -> Result<Vec<CaptureResult>, RunnerError> {
     let mut captures = vec![];
     for capture in response.captures() {
-        let capture_result = capture::eval_capture(capture, variables, http_response, cache)?;
+        let capture_result = capture::eval_capture(
+            capture,
+            variables,
+            http_request,
+            http_response,
+            cache,
+            eval_context,
+        )?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         let name = capture_result.name.clone();
@@ -370,10 +419,58 @@ pub fn eval_captures(
     Ok(captures)
 }
 
+/// Returns `true` if the JSON texts `expected` and `actual` are structurally equal, floats being
+/// compared up to `precision` decimal places.
+fn json_text_equal(expected: &str, actual: &str, precision: u32) -> bool {
+    let (Ok(expected), Ok(actual)) = (
+        serde_json::from_str::<serde_json::Value>(expected),
+        serde_json::from_str::<serde_json::Value>(actual),
+    ) else {
+        return false;
+    };
+    let epsilon = 10f64.powi(-(precision as i32));
+    json_value_equal(&expected, &actual, epsilon)
+}
+
+/// Returns `true` if `expected` and `actual` are equal, floats differing by less than `epsilon`.
+/// Integers are compared exactly.
+fn json_value_equal(
+    expected: &serde_json::Value,
+    actual: &serde_json::Value,
+    epsilon: f64,
+) -> bool {
+    use serde_json::Value as Json;
+    match (expected, actual) {
+        (Json::Number(e), Json::Number(a)) => {
+            if (e.is_i64() || e.is_u64()) && (a.is_i64() || a.is_u64()) {
+                return e == a;
+            }
+            match (e.as_f64(), a.as_f64()) {
+                (Some(e), Some(a)) => (e - a).abs() < epsilon,
+                _ => false,
+            }
+        }
+        (Json::Array(e), Json::Array(a)) => {
+            e.len() == a.len()
+                && e.iter()
+                    .zip(a.iter())
+                    .all(|(e, a)| json_value_equal(e, a, epsilon))
+        }
+        (Json::Object(e), Json::Object(a)) => {
+            e.len() == a.len()
+                && e.iter().all(|(key, e)| match a.get(key) {
+                    Some(a) => json_value_equal(e, a, epsilon),
+                    None => false,
+                })
+        }
+        (e, a) => e == a,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use hurl_core::ast::{
-        LineTerminator, Section, SectionValue, Status, Version, VersionValue, Whitespace,
+        JsonValue, LineTerminator, Section, SectionValue, Status, Version, VersionValue, Whitespace,
     };
     use hurl_core::reader::Pos;
 
@@ -437,19 +534,25 @@ mod tests {
             eval_asserts(
                 &user_response(),
                 &variables,
+                &http::default_http_request(),
                 &http::xml_two_users_http_response(),
                 &mut cache,
                 &context_dir,
+                None,
+                false,
+                &mut EvalContext::default(),
             ),
             vec![AssertResult::Explicit {
                 actual: Ok(Some(Value::Number(Number::Integer(2)))),
                 source_info: SourceInfo::new(Pos::new(1, 22), Pos::new(1, 24)),
+                filters_source_info: vec![SourceInfo::new(Pos::new(1, 16), Pos::new(1, 21))],
                 predicate_result: Some(Err(RunnerError::new(
                     SourceInfo::new(Pos::new(1, 0), Pos::new(1, 0)),
                     RunnerErrorKind::AssertFailure {
                         actual: "int <2>".to_string(),
                         expected: "int <3>".to_string(),
                         type_mismatch: false,
+                        actual_value: None,
                     },
                     true
                 ))),
@@ -457,6 +560,237 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_eval_implicit_json_body_asserts_float_precision() {
+        let variables = VariableSet::new();
+        let context_dir = ContextDir::default();
+        let whitespace = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 1)),
+        };
+        let spec_body = Body {
+            line_terminators: vec![],
+            space0: whitespace.clone(),
+            value: Bytes::Json(JsonValue::Number("0.3".to_string())),
+            line_terminator0: LineTerminator {
+                space0: whitespace.clone(),
+                comment: None,
+                newline: whitespace,
+            },
+        };
+        let mut http_response = http::xml_two_users_http_response();
+        http_response.body = b"0.30000000000000004".to_vec();
+
+        // By default, JSON bodies are compared as text.
+        let assert =
+            eval_implicit_body_asserts(&spec_body, &variables, &http_response, &context_dir, None);
+        assert!(assert.error().is_some());
+
+        let assert = eval_implicit_body_asserts(
+            &spec_body,
+            &variables,
+            &http_response,
+            &context_dir,
+            Some(6),
+        );
+        assert!(assert.error().is_none());
+
+        // Not within the precision.
+        let assert = eval_implicit_body_asserts(
+            &spec_body,
+            &variables,
+            &http_response,
+            &context_dir,
+            Some(17),
+        );
+        assert!(assert.error().is_some());
+    }
+
+    #[test]
+    fn test_json_text_equal() {
+        assert!(json_text_equal("0.3", "0.30000000000000004", 6));
+        assert!(json_text_equal(
+            r#"{"a": [1, 2.5], "b": null}"#,
+            r#"{"b":null,"a":[1,2.5000001]}"#,
+            6
+        ));
+        assert!(!json_text_equal("0.3", "0.31", 6));
+        // Integers are compared exactly.
+        assert!(!json_text_equal("1", "2", 0));
+        assert!(!json_text_equal(r#"{"a": 1}"#, r#"{"a": 1, "b": 2}"#, 6));
+        assert!(!json_text_equal("0.3", "not json", 6));
+    }
+
+    #[test]
+    pub fn test_eval_header_asserts_env() {
+        std::env::set_var("HURL_TEST_CONTENT_LENGTH", "12");
+        let content = "GET http://localhost:8000/users\n\
+                       HTTP 200\n\
+                       Content-Length: {{getEnv HURL_TEST_CONTENT_LENGTH}}\n\
+                       Content-Length: {{getEnv HURL_TEST_UNDEFINED 12}}\n\
+                       Content-Length: {{getEnv HURL_TEST_UNDEFINED}}\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = hurl_file.entries[0].response.as_ref().unwrap();
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let context_dir = ContextDir::default();
+
+        let asserts = eval_asserts(
+            response,
+            &variables,
+            &http::default_http_request(),
+            &http::xml_two_users_http_response(),
+            &mut cache,
+            &context_dir,
+            None,
+            false,
+            &mut EvalContext::default(),
+        );
+        let headers = asserts
+            .iter()
+            .filter(|a| matches!(a, AssertResult::Header { .. }))
+            .collect::<Vec<_>>();
+        assert_eq!(headers.len(), 3);
+        assert!(headers[0].error().is_none());
+        assert!(headers[1].error().is_none());
+        assert_eq!(
+            headers[2].error().unwrap().kind,
+            RunnerErrorKind::EnvVariableNotDefined {
+                name: "HURL_TEST_UNDEFINED".to_string()
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_eval_header_asserts_ignore_case() {
+        let content = "GET http://localhost:8000/users\n\
+                       HTTP 200\n\
+                       Content-Type: TEXT/HTML; Charset=UTF-8\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = hurl_file.entries[0].response.as_ref().unwrap();
+        let variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let context_dir = ContextDir::default();
+
+        let eval = |cache: &mut BodyCache, ignore_header_case: bool| {
+            eval_asserts(
+                response,
+                &variables,
+                &http::default_http_request(),
+                &http::xml_two_users_http_response(),
+                cache,
+                &context_dir,
+                None,
+                ignore_header_case,
+                &mut EvalContext::default(),
+            )
+        };
+
+        let asserts = eval(&mut cache, false);
+        assert_eq!(
+            asserts[0].error().unwrap().kind,
+            RunnerErrorKind::AssertHeaderValueError {
+                actual: "text/html; charset=utf-8".to_string()
+            }
+        );
+
+        let asserts = eval(&mut cache, true);
+        assert!(asserts[0].error().is_none());
+    }
+
+    #[test]
+    pub fn test_eval_request_body_asserts() {
+        let content = "POST http://localhost:8000/users\n\
+                       HTTP 200\n\
+                       [Captures]\n\
+                       user: xpath \"string(//user[1])\"\n\
+                       [Asserts]\n\
+                       requestBody contains \"{{user}}\"\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = hurl_file.entries[0].response.as_ref().unwrap();
+        let url = "http://localhost:8000/users".parse().unwrap();
+        let http_request = http::Request::new(
+            "POST",
+            url,
+            http::HeaderVec::new(),
+            b"{\"name\":\"Bob\"}".to_vec(),
+        );
+        let http_response = http::xml_two_users_http_response();
+        let mut variables = VariableSet::new();
+        let mut cache = BodyCache::new();
+        let context_dir = ContextDir::default();
+
+        let captures = eval_captures(
+            response,
+            &http_request,
+            &http_response,
+            &mut cache,
+            &mut variables,
+            &mut EvalContext::default(),
+        )
+        .unwrap();
+        assert_eq!(captures[0].value, Value::String("Bob".to_string()));
+
+        let asserts = eval_asserts(
+            response,
+            &variables,
+            &http_request,
+            &http_response,
+            &mut cache,
+            &context_dir,
+            None,
+            false,
+            &mut EvalContext::default(),
+        );
+        let explicit_asserts = asserts
+            .iter()
+            .filter(|a| matches!(a, AssertResult::Explicit { .. }))
+            .collect::<Vec<_>>();
+        assert_eq!(explicit_asserts.len(), 1);
+        assert!(explicit_asserts[0].error().is_none());
+    }
+
+    #[test]
+    pub fn test_eval_query_param_asserts() {
+        let content = "GET http://localhost:8000/users?page=2&id={{id}}&tag=a&tag=b\n\
+                       HTTP 200\n\
+                       [Asserts]\n\
+                       queryParam \"id\" == \"{{id}}\"\n\
+                       queryParam \"page\" exists\n\
+                       queryParam \"tag\" count == 2\n\
+                       queryParam \"unknown\" not exists\n";
+        let hurl_file = hurl_core::parser::parse_hurl_file(content).unwrap();
+        let response = hurl_file.entries[0].response.as_ref().unwrap();
+        let url = "http://localhost:8000/users?page=2&id=42&tag=a&tag=b"
+            .parse()
+            .unwrap();
+        let http_request = http::Request::new("GET", url, http::HeaderVec::new(), vec![]);
+        let mut variables = VariableSet::new();
+        variables
+            .insert("id".to_string(), Value::String("42".to_string()))
+            .unwrap();
+        let mut cache = BodyCache::new();
+        let context_dir = ContextDir::default();
+
+        let asserts = eval_asserts(
+            response,
+            &variables,
+            &http_request,
+            &http::hello_http_response(),
+            &mut cache,
+            &context_dir,
+            None,
+            false,
+            &mut EvalContext::default(),
+        );
+        let explicit_asserts = asserts
+            .iter()
+            .filter(|a| matches!(a, AssertResult::Explicit { .. }))
+            .collect::<Vec<_>>();
+        assert_eq!(explicit_asserts.len(), 4);
+        assert!(explicit_asserts.iter().all(|a| a.error().is_none()));
+    }
+
     #[test]
     pub fn test_eval_version_status_asserts() {
         assert_eq!(
@@ -484,14 +818,16 @@ mod tests {
         assert_eq!(
             eval_captures(
                 &user_response(),
+                &http::default_http_request(),
                 &http::xml_two_users_http_response(),
                 &mut cache,
                 &mut variables,
+                &mut EvalContext::default(),
             )
             .unwrap(),
             vec![CaptureResult {
                 name: "UserCount".to_string(),
-                value: Value::Number(Number::Float(2.0)),
+                value: Value::Number(Number::Integer(2)),
             }]
         );
     }
@@ -15,6 +15,7 @@
  * limitations under the License.
  *
  */
+use std::collections::HashMap;
 use std::time::Duration;
 
 use hurl_core::ast::SourceInfo;
@@ -24,6 +25,7 @@ use crate::http::{Call, Cookie, CurlCmd};
 use crate::runner::error::RunnerError;
 use crate::runner::output::Output;
 use crate::runner::value::Value;
+use crate::runner::warning::Warning;
 use crate::runner::RunnerErrorKind;
 use crate::util::path::ContextDir;
 use crate::util::term::Stdout;
@@ -69,6 +71,90 @@ impl HurlResult {
         }
         errors
     }
+
+    /// Returns all the warnings of this `HurlResult`, in entries execution order.
+    ///
+    /// Contrary to errors, warnings don't fail the run.
+    pub fn warnings(&self) -> Vec<&Warning> {
+        self.entries
+            .iter()
+            .flat_map(|entry| entry.warnings.iter())
+            .collect()
+    }
+
+    /// Returns the captured values of this run, by capture name.
+    ///
+    /// Entries are processed in execution order: when several entries (or several retries of the
+    /// same entry) capture a value with the same name, the last captured value wins.
+    pub fn captures(&self) -> HashMap<String, Value> {
+        self.entries
+            .iter()
+            .flat_map(|entry| entry.captures.iter())
+            .map(|capture| (capture.name.clone(), capture.value.clone()))
+            .collect()
+    }
+
+    /// Returns the cookies of this run in the Netscape cookies.txt format.
+    ///
+    /// The returned jar can be used as a cookies input file of a subsequent run (see `--cookie`),
+    /// to chain runs sharing cookies.
+    pub fn to_netscape_cookie_jar(&self) -> String {
+        let mut s =
+            "# Netscape HTTP Cookie File\n# This file was generated by Hurl\n\n".to_string();
+        for cookie in &self.cookies {
+            s.push_str(&cookie.to_string());
+            s.push('\n');
+        }
+        s
+    }
+
+    /// Returns a histogram of the entries transfer durations, given the upper bounds in
+    /// milliseconds (sorted in increasing order) of the buckets.
+    ///
+    /// Each entry execution (including retries) is counted in the first bucket `[min, max)` that
+    /// contains its duration, the last bucket having no upper bound. With `n` bounds, the histogram
+    /// has `n + 1` buckets. [`DEFAULT_DURATION_BUCKETS`] can be used as bounds, on a log scale.
+    pub fn duration_histogram(&self, bounds_ms: &[u64]) -> Vec<DurationBucket> {
+        let mut buckets = Vec::with_capacity(bounds_ms.len() + 1);
+        let mut min_ms = 0;
+        for &max_ms in bounds_ms {
+            buckets.push(DurationBucket {
+                min_ms,
+                max_ms: Some(max_ms),
+                count: 0,
+            });
+            min_ms = max_ms;
+        }
+        buckets.push(DurationBucket {
+            min_ms,
+            max_ms: None,
+            count: 0,
+        });
+        for entry in &self.entries {
+            let time_in_ms = entry.transfer_duration.as_millis() as u64;
+            let index = bounds_ms
+                .iter()
+                .position(|&max_ms| time_in_ms < max_ms)
+                .unwrap_or(bounds_ms.len());
+            buckets[index].count += 1;
+        }
+        buckets
+    }
+}
+
+/// Default upper bounds in milliseconds of the duration histogram buckets, on a 1-2.5-5 log scale.
+pub const DEFAULT_DURATION_BUCKETS: [u64; 10] =
+    [10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// A bucket of a duration histogram (see [`HurlResult::duration_histogram`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DurationBucket {
+    /// Lower bound in milliseconds of this bucket (inclusive).
+    pub min_ms: u64,
+    /// Upper bound in milliseconds of this bucket (exclusive), `None` for the last bucket.
+    pub max_ms: Option<u64>,
+    /// Number of entries whose duration is in this bucket.
+    pub count: usize,
 }
 
 /// Represents the execution result of an entry.
@@ -95,6 +181,8 @@ pub struct EntryResult {
     pub compressed: bool,
     /// The debug curl command line from this entry result.
     pub curl_cmd: CurlCmd,
+    /// List of warnings, that don't fail the entry (deprecated TLS version for instance).
+    pub warnings: Vec<Warning>,
 }
 
 impl Default for EntryResult {
@@ -109,6 +197,7 @@ impl Default for EntryResult {
             transfer_duration: Duration::from_millis(0),
             compressed: false,
             curl_cmd: CurlCmd::default(),
+            warnings: vec![],
         }
     }
 }
@@ -148,10 +237,13 @@ pub enum AssertResult {
         source_info: SourceInfo,
     },
     /// Implicit HTTP response header assert.
+    ///
+    /// If `ignore_case` is set, the actual and expected values are compared ignoring ASCII case.
     Header {
         actual: Result<String, RunnerError>,
         expected: String,
         source_info: SourceInfo,
+        ignore_case: bool,
     },
     /// Implicit HTTP response body assert.
     Body {
@@ -163,6 +255,8 @@ pub enum AssertResult {
     Explicit {
         actual: Result<Option<Value>, RunnerError>,
         source_info: SourceInfo,
+        /// Source info of each filter of the assert.
+        filters_source_info: Vec<SourceInfo>,
         predicate_result: Option<PredicateResult>,
     },
 }
@@ -213,3 +307,129 @@ impl EntryResult {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Number;
+
+    #[test]
+    fn test_captures() {
+        let capture = |name: &str, value: Value| CaptureResult {
+            name: name.to_string(),
+            value,
+        };
+        let result = HurlResult {
+            entries: vec![
+                EntryResult {
+                    entry_index: 1,
+                    captures: vec![
+                        capture("token", Value::String("abc".to_string())),
+                        capture("id", Value::Number(Number::Integer(1))),
+                    ],
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 2,
+                    captures: vec![],
+                    ..Default::default()
+                },
+                EntryResult {
+                    entry_index: 3,
+                    captures: vec![
+                        capture("id", Value::Number(Number::Integer(2))),
+                        capture("done", Value::Bool(true)),
+                    ],
+                    ..Default::default()
+                },
+            ],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+
+        let captures = result.captures();
+        assert_eq!(captures.len(), 3);
+        assert_eq!(captures["token"], Value::String("abc".to_string()));
+        assert_eq!(captures["id"], Value::Number(Number::Integer(2)));
+        assert_eq!(captures["done"], Value::Bool(true));
+    }
+
+    #[test]
+    fn test_duration_histogram() {
+        let entry = |time_in_ms: u64| EntryResult {
+            transfer_duration: Duration::from_millis(time_in_ms),
+            ..Default::default()
+        };
+        let result = HurlResult {
+            entries: vec![
+                entry(0),
+                entry(9),
+                entry(10),
+                entry(42),
+                entry(60),
+                entry(12000),
+            ],
+            duration: Duration::from_millis(12121),
+            success: true,
+            cookies: vec![],
+            timestamp: 1,
+        };
+
+        assert_eq!(
+            result.duration_histogram(&[10, 50]),
+            vec![
+                DurationBucket {
+                    min_ms: 0,
+                    max_ms: Some(10),
+                    count: 2,
+                },
+                DurationBucket {
+                    min_ms: 10,
+                    max_ms: Some(50),
+                    count: 2,
+                },
+                DurationBucket {
+                    min_ms: 50,
+                    max_ms: None,
+                    count: 2,
+                },
+            ]
+        );
+
+        let histogram = result.duration_histogram(&DEFAULT_DURATION_BUCKETS);
+        assert_eq!(histogram.len(), 11);
+        assert_eq!(
+            histogram.iter().map(|b| b.count).collect::<Vec<_>>(),
+            vec![2, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_to_netscape_cookie_jar() {
+        let result = HurlResult {
+            entries: vec![],
+            duration: Duration::from_millis(100),
+            success: true,
+            cookies: vec![Cookie {
+                domain: "localhost".to_string(),
+                include_subdomain: "FALSE".to_string(),
+                path: "/".to_string(),
+                https: "FALSE".to_string(),
+                expires: "0".to_string(),
+                name: "session".to_string(),
+                value: "abc123".to_string(),
+                http_only: true,
+            }],
+            timestamp: 1,
+        };
+        assert_eq!(
+            result.to_netscape_cookie_jar(),
+            "# Netscape HTTP Cookie File\n\
+             # This file was generated by Hurl\n\
+             \n\
+             #HttpOnly_localhost\tFALSE\t/\tFALSE\t0\tsession\tabc123\n"
+        );
+    }
+}
@@ -21,6 +21,8 @@ use hurl_core::ast::Entry;
 use hurl_core::typing::{BytesPerSec, Count};
 
 use crate::http::{IpResolve, RequestedHttpVersion};
+use crate::runner::eval_context::{DEFAULT_EVAL_TIMEOUT, DEFAULT_XPATH_OP_LIMIT};
+use crate::runner::regex::DEFAULT_REGEX_SIZE_LIMIT;
 use crate::runner::Output;
 use crate::util::path::ContextDir;
 
@@ -36,13 +38,17 @@ pub struct RunnerOptionsBuilder {
     continue_on_error: bool,
     cookie_input_file: Option<String>,
     delay: Duration,
+    empty_body_as_null: bool,
+    eval_timeout: Duration,
     follow_location: bool,
     follow_location_trusted: bool,
     from_entry: Option<usize>,
     http_version: RequestedHttpVersion,
     ignore_asserts: bool,
+    ignore_header_case: bool,
     insecure: bool,
     ip_resolve: IpResolve,
+    json_float_precision: Option<u32>,
     max_filesize: Option<u64>,
     max_recv_speed: Option<BytesPerSec>,
     max_redirect: Count,
@@ -56,6 +62,7 @@ pub struct RunnerOptionsBuilder {
     post_entry: Option<fn() -> bool>,
     pre_entry: Option<fn(&Entry) -> bool>,
     proxy: Option<String>,
+    regex_size_limit: usize,
     repeat: Option<Count>,
     resolves: Vec<String>,
     retry: Option<Count>,
@@ -67,6 +74,7 @@ pub struct RunnerOptionsBuilder {
     unix_socket: Option<String>,
     user: Option<String>,
     user_agent: Option<String>,
+    xpath_op_limit: u64,
 }
 
 impl Default for RunnerOptionsBuilder {
@@ -83,13 +91,17 @@ impl Default for RunnerOptionsBuilder {
             continue_on_error: false,
             cookie_input_file: None,
             delay: Duration::from_millis(0),
+            empty_body_as_null: false,
+            eval_timeout: DEFAULT_EVAL_TIMEOUT,
             follow_location: false,
             follow_location_trusted: false,
             from_entry: None,
             http_version: RequestedHttpVersion::default(),
             ignore_asserts: false,
+            ignore_header_case: false,
             insecure: false,
             ip_resolve: IpResolve::default(),
+            json_float_precision: None,
             max_filesize: None,
             max_recv_speed: None,
             max_redirect: Count::Finite(50),
@@ -103,6 +115,7 @@ impl Default for RunnerOptionsBuilder {
             post_entry: None,
             pre_entry: None,
             proxy: None,
+            regex_size_limit: DEFAULT_REGEX_SIZE_LIMIT,
             repeat: None,
             resolves: vec![],
             retry: None,
@@ -114,6 +127,7 @@ impl Default for RunnerOptionsBuilder {
             unix_socket: None,
             user: None,
             user_agent: None,
+            xpath_op_limit: DEFAULT_XPATH_OP_LIMIT,
         }
     }
 }
@@ -181,6 +195,22 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Evaluates an empty response body as JSON `null` in JSONPath queries.
+    ///
+    /// By default, an empty body is not a valid JSON and JSONPath queries fail.
+    pub fn empty_body_as_null(&mut self, empty_body_as_null: bool) -> &mut Self {
+        self.empty_body_as_null = empty_body_as_null;
+        self
+    }
+
+    /// Sets maximum time allowed for the evaluation of a JSONPath expression.
+    ///
+    /// Default 60 seconds.
+    pub fn eval_timeout(&mut self, eval_timeout: Duration) -> &mut Self {
+        self.eval_timeout = eval_timeout;
+        self
+    }
+
     /// Sets root file system to import files in Hurl.
     ///
     /// This is used for both files in multipart form data and request body.
@@ -247,6 +277,12 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Compares header values of implicit header asserts ignoring ASCII case.
+    pub fn ignore_header_case(&mut self, ignore_header_case: bool) -> &mut Self {
+        self.ignore_header_case = ignore_header_case;
+        self
+    }
+
     /// Allows Hurl to perform “insecure” SSL connections and transfers.
     pub fn insecure(&mut self, insecure: bool) -> &mut Self {
         self.insecure = insecure;
@@ -259,6 +295,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Compares JSON body asserts structurally, with floats equal up to `json_float_precision` decimal places.
+    ///
+    /// Integers are always compared exactly. By default, JSON bodies are compared as text.
+    pub fn json_float_precision(&mut self, json_float_precision: Option<u32>) -> &mut Self {
+        self.json_float_precision = json_float_precision;
+        self
+    }
+
     /// Set the file size limit
     pub fn max_filesize(&mut self, max_filesize: Option<u64>) -> &mut Self {
         self.max_filesize = max_filesize;
@@ -343,6 +387,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the maximum size in bytes of a regex compiled at runtime.
+    ///
+    /// Default is 1 MB.
+    pub fn regex_size_limit(&mut self, regex_size_limit: usize) -> &mut Self {
+        self.regex_size_limit = regex_size_limit;
+        self
+    }
+
     /// Set the number of repetition for a given entry.
     pub fn repeat(&mut self, repeat: Option<Count>) -> &mut Self {
         self.repeat = repeat;
@@ -408,6 +460,15 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets maximum number of libxml2 operations allowed for the evaluation of a XPath
+    /// expression.
+    ///
+    /// Default is 100,000,000 operations.
+    pub fn xpath_op_limit(&mut self, xpath_op_limit: u64) -> &mut Self {
+        self.xpath_op_limit = xpath_op_limit;
+        self
+    }
+
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
@@ -419,6 +480,8 @@ impl RunnerOptionsBuilder {
             connect_timeout: self.connect_timeout,
             connects_to: self.connects_to.clone(),
             delay: self.delay,
+            empty_body_as_null: self.empty_body_as_null,
+            eval_timeout: self.eval_timeout,
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
@@ -427,8 +490,10 @@ impl RunnerOptionsBuilder {
             from_entry: self.from_entry,
             http_version: self.http_version,
             ignore_asserts: self.ignore_asserts,
+            ignore_header_case: self.ignore_header_case,
             insecure: self.insecure,
             ip_resolve: self.ip_resolve,
+            json_float_precision: self.json_float_precision,
             max_filesize: self.max_filesize,
             max_recv_speed: self.max_recv_speed,
             max_redirect: self.max_redirect,
@@ -442,6 +507,7 @@ impl RunnerOptionsBuilder {
             post_entry: self.post_entry,
             pre_entry: self.pre_entry,
             proxy: self.proxy.clone(),
+            regex_size_limit: self.regex_size_limit,
             repeat: self.repeat,
             resolves: self.resolves.clone(),
             retry: self.retry,
@@ -453,6 +519,7 @@ impl RunnerOptionsBuilder {
             unix_socket: self.unix_socket.clone(),
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            xpath_op_limit: self.xpath_op_limit,
         }
     }
 }
@@ -467,6 +534,8 @@ pub struct RunnerOptions {
     pub(crate) connect_timeout: Duration,
     pub(crate) connects_to: Vec<String>,
     pub(crate) delay: Duration,
+    pub(crate) empty_body_as_null: bool,
+    pub(crate) eval_timeout: Duration,
     pub(crate) context_dir: ContextDir,
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
@@ -475,8 +544,10 @@ pub struct RunnerOptions {
     pub(crate) from_entry: Option<usize>,
     pub(crate) http_version: RequestedHttpVersion,
     pub(crate) ignore_asserts: bool,
+    pub(crate) ignore_header_case: bool,
     pub(crate) ip_resolve: IpResolve,
     pub(crate) insecure: bool,
+    pub(crate) json_float_precision: Option<u32>,
     pub(crate) max_filesize: Option<u64>,
     pub(crate) max_recv_speed: Option<BytesPerSec>,
     pub(crate) max_redirect: Count,
@@ -490,6 +561,7 @@ pub struct RunnerOptions {
     pub(crate) post_entry: Option<fn() -> bool>,
     pub(crate) pre_entry: Option<fn(&Entry) -> bool>,
     pub(crate) proxy: Option<String>,
+    pub(crate) regex_size_limit: usize,
     pub(crate) repeat: Option<Count>,
     pub(crate) resolves: Vec<String>,
     pub(crate) retry: Option<Count>,
@@ -501,6 +573,7 @@ pub struct RunnerOptions {
     pub(crate) unix_socket: Option<String>,
     pub(crate) user: Option<String>,
     pub(crate) user_agent: Option<String>,
+    pub(crate) xpath_op_limit: u64,
 }
 
 impl Default for RunnerOptions {
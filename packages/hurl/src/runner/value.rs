@@ -127,6 +127,70 @@ impl Value {
             _ => None,
         }
     }
+
+    /// Returns `true` if this value is a structured value (a list or an object).
+    pub fn is_structured(&self) -> bool {
+        matches!(self, Value::List(_) | Value::Object(_))
+    }
+
+    /// Returns a pretty, multi-line representation of this value, used to display structured
+    /// values in assert failures.
+    ///
+    /// Lists and objects are rendered with one element per line, indented by two spaces for each
+    /// nesting level:
+    ///
+    /// ```text
+    /// {
+    ///   "name": "Bob",
+    ///   "tags": [
+    ///     "admin",
+    ///     "user"
+    ///   ]
+    /// }
+    /// ```
+    pub fn to_display_pretty(&self) -> String {
+        let mut s = String::new();
+        self.write_display_pretty(&mut s, 0);
+        s
+    }
+
+    fn write_display_pretty(&self, s: &mut String, level: usize) {
+        let indent = "  ".repeat(level + 1);
+        let end_indent = "  ".repeat(level);
+        match self {
+            Value::List(values) if !values.is_empty() => {
+                s.push_str("[\n");
+                for (i, value) in values.iter().enumerate() {
+                    s.push_str(&indent);
+                    value.write_display_pretty(s, level + 1);
+                    if i < values.len() - 1 {
+                        s.push(',');
+                    }
+                    s.push('\n');
+                }
+                s.push_str(&end_indent);
+                s.push(']');
+            }
+            Value::Object(values) if !values.is_empty() => {
+                s.push_str("{\n");
+                for (i, (key, value)) in values.iter().enumerate() {
+                    s.push_str(&indent);
+                    s.push_str(&format!("{key:?}: "));
+                    value.write_display_pretty(s, level + 1);
+                    if i < values.len() - 1 {
+                        s.push(',');
+                    }
+                    s.push('\n');
+                }
+                s.push_str(&end_indent);
+                s.push('}');
+            }
+            Value::List(_) => s.push_str("[]"),
+            Value::Object(_) => s.push_str("{}"),
+            Value::String(value) => s.push_str(&format!("{value:?}")),
+            value => s.push_str(&value.to_string()),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -174,4 +238,37 @@ mod tests {
             "2000-02-01T12:00:00.123456Z"
         );
     }
+
+    #[test]
+    fn test_to_display_pretty() {
+        let value = Value::Object(vec![
+            ("name".to_string(), Value::String("Bob".to_string())),
+            ("age".to_string(), Value::Number(Number::Integer(27))),
+            (
+                "tags".to_string(),
+                Value::List(vec![
+                    Value::String("admin".to_string()),
+                    Value::Object(vec![("active".to_string(), Value::Bool(true))]),
+                ]),
+            ),
+            ("friends".to_string(), Value::List(vec![])),
+            ("address".to_string(), Value::Null),
+        ]);
+        assert_eq!(
+            value.to_display_pretty(),
+            r#"{
+  "name": "Bob",
+  "age": 27,
+  "tags": [
+    "admin",
+    {
+      "active": true
+    }
+  ],
+  "friends": [],
+  "address": null
+}"#
+        );
+        assert_eq!(Value::Number(Number::Integer(1)).to_display_pretty(), "1");
+    }
 }
@@ -0,0 +1,280 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Typed variables loaded from JSON or TOML files.
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use hurl_core::ast::is_variable_reserved;
+
+use crate::runner::{Number, Value};
+
+/// Format of a variables file.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum VariablesFormat {
+    Json,
+    Toml,
+}
+
+impl VariablesFormat {
+    /// Returns the format of a variables file from its `path` extension (`.json` or `.toml`).
+    pub fn from_path(path: &Path) -> Option<VariablesFormat> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Some(VariablesFormat::Json),
+            Some("toml") => Some(VariablesFormat::Toml),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for VariablesFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            VariablesFormat::Json => "JSON",
+            VariablesFormat::Toml => "TOML",
+        };
+        write!(f, "{value}")
+    }
+}
+
+/// Errors raised when parsing a variables file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VariablesFileError {
+    /// The content is not a valid JSON or TOML document.
+    InvalidSyntax {
+        format: VariablesFormat,
+        message: String,
+    },
+    /// The root of the document is not a JSON object.
+    NotAnObject,
+    /// A variable name conflicts with a function name.
+    ReservedName(String),
+}
+
+impl fmt::Display for VariablesFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariablesFileError::InvalidSyntax { format, message } => {
+                write!(f, "Variables file is not a valid {format}: {message}")
+            }
+            VariablesFileError::NotAnObject => {
+                write!(f, "Variables JSON file must be an object")
+            }
+            VariablesFileError::ReservedName(name) => write!(
+                f,
+                "Variable {name} conflicts with the {name} function, use a different name."
+            ),
+        }
+    }
+}
+
+/// Parses a variables file `content` in `format` into a map of variables.
+///
+/// Each property of the JSON root object (or each key of the TOML document) is a variable, whose
+/// value keeps its type: numbers, booleans, null, lists and objects are converted to their
+/// corresponding [`Value`]. TOML datetimes are converted to strings.
+///
+/// The returned map can be used to build the [`crate::runner::VariableSet`] of a run.
+pub fn parse_variables(
+    content: &str,
+    format: VariablesFormat,
+) -> Result<HashMap<String, Value>, VariablesFileError> {
+    let variables = match format {
+        VariablesFormat::Json => parse_json(content)?,
+        VariablesFormat::Toml => parse_toml(content)?,
+    };
+    if let Some(name) = variables.keys().find(|name| is_variable_reserved(name)) {
+        return Err(VariablesFileError::ReservedName(name.clone()));
+    }
+    Ok(variables)
+}
+
+fn parse_json(content: &str) -> Result<HashMap<String, Value>, VariablesFileError> {
+    let json = match serde_json::from_str::<serde_json::Value>(content) {
+        Ok(json) => json,
+        Err(e) => {
+            return Err(VariablesFileError::InvalidSyntax {
+                format: VariablesFormat::Json,
+                message: e.to_string(),
+            })
+        }
+    };
+    let serde_json::Value::Object(map) = json else {
+        return Err(VariablesFileError::NotAnObject);
+    };
+    let variables = map
+        .iter()
+        .map(|(name, value)| (name.to_string(), Value::from_json(value)))
+        .collect();
+    Ok(variables)
+}
+
+fn parse_toml(content: &str) -> Result<HashMap<String, Value>, VariablesFileError> {
+    // A TOML document is always a table.
+    let table = match toml::from_str::<toml::value::Table>(content) {
+        Ok(table) => table,
+        Err(e) => {
+            return Err(VariablesFileError::InvalidSyntax {
+                format: VariablesFormat::Toml,
+                message: e.to_string(),
+            })
+        }
+    };
+    let variables = table
+        .iter()
+        .map(|(name, value)| (name.to_string(), from_toml(value)))
+        .collect();
+    Ok(variables)
+}
+
+/// Converts a TOML `value` to a [`Value`].
+fn from_toml(value: &toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s.clone()),
+        toml::Value::Integer(i) => Value::Number(Number::Integer(*i)),
+        toml::Value::Float(f) => Value::Number(Number::Float(*f)),
+        toml::Value::Boolean(b) => Value::Bool(*b),
+        toml::Value::Datetime(d) => Value::String(d.to_string()),
+        toml::Value::Array(values) => Value::List(values.iter().map(from_toml).collect()),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .iter()
+                .map(|(name, value)| (name.to_string(), from_toml(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(variables: HashMap<String, Value>) -> Vec<(String, Value)> {
+        let mut variables = variables.into_iter().collect::<Vec<_>>();
+        variables.sort_by(|(a, _), (b, _)| a.cmp(b));
+        variables
+    }
+
+    fn expected_variables() -> Vec<(String, Value)> {
+        vec![
+            ("enabled".to_string(), Value::Bool(true)),
+            ("host".to_string(), Value::String("example.net".to_string())),
+            ("id".to_string(), Value::Number(Number::Integer(1234))),
+            ("ratio".to_string(), Value::Number(Number::Float(0.5))),
+            (
+                "tags".to_string(),
+                Value::List(vec![
+                    Value::String("a".to_string()),
+                    Value::Number(Number::Integer(1)),
+                ]),
+            ),
+            (
+                "user".to_string(),
+                Value::Object(vec![
+                    ("age".to_string(), Value::Number(Number::Integer(42))),
+                    ("name".to_string(), Value::String("Bob".to_string())),
+                ]),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let content = r#"{
+            "host": "example.net",
+            "id": 1234,
+            "ratio": 0.5,
+            "enabled": true,
+            "tags": ["a", 1],
+            "user": {"name": "Bob", "age": 42}
+        }"#;
+        let variables = parse_variables(content, VariablesFormat::Json).unwrap();
+        assert_eq!(sorted(variables), expected_variables());
+
+        let variables = parse_variables(r#"{"token": null}"#, VariablesFormat::Json).unwrap();
+        assert_eq!(variables.get("token"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn test_parse_toml() {
+        let content = r#"
+host = "example.net"
+id = 1234
+ratio = 0.5
+enabled = true
+tags = ["a", 1]
+
+[user]
+name = "Bob"
+age = 42
+"#;
+        let variables = parse_variables(content, VariablesFormat::Toml).unwrap();
+        assert_eq!(sorted(variables), expected_variables());
+
+        let variables = parse_variables("date = 2024-01-10", VariablesFormat::Toml).unwrap();
+        assert_eq!(
+            variables.get("date"),
+            Some(&Value::String("2024-01-10".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_variables_error() {
+        assert_eq!(
+            parse_variables("[1, 2]", VariablesFormat::Json).unwrap_err(),
+            VariablesFileError::NotAnObject
+        );
+        assert_eq!(
+            parse_variables(r#"{"newUuid": 1}"#, VariablesFormat::Json)
+                .unwrap_err()
+                .to_string(),
+            "Variable newUuid conflicts with the newUuid function, use a different name."
+        );
+        assert_eq!(
+            parse_variables("newDate = 1", VariablesFormat::Toml).unwrap_err(),
+            VariablesFileError::ReservedName("newDate".to_string())
+        );
+        assert!(matches!(
+            parse_variables("{", VariablesFormat::Json).unwrap_err(),
+            VariablesFileError::InvalidSyntax {
+                format: VariablesFormat::Json,
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse_variables("host = ", VariablesFormat::Toml).unwrap_err(),
+            VariablesFileError::InvalidSyntax {
+                format: VariablesFormat::Toml,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            VariablesFormat::from_path(Path::new("vars.json")),
+            Some(VariablesFormat::Json)
+        );
+        assert_eq!(
+            VariablesFormat::from_path(Path::new("conf/vars.toml")),
+            Some(VariablesFormat::Toml)
+        );
+        assert_eq!(VariablesFormat::from_path(Path::new("vars.env")), None);
+    }
+}
@@ -0,0 +1,146 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2024 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use hurl_core::ast::SourceInfo;
+
+use crate::http::Call;
+
+/// TLS versions older than TLS 1.2, deprecated by [RFC 8996](https://www.rfc-editor.org/rfc/rfc8996).
+const DEPRECATED_TLS_VERSIONS: [&str; 5] = ["SSLv2", "SSLv3", "TLSv1", "TLSv1.0", "TLSv1.1"];
+
+/// A warning raised during the execution of an entry.
+///
+/// Contrary to a [`crate::runner::RunnerError`], a warning doesn't fail an entry: it signals a
+/// condition worth surfacing, like a deprecated TLS version negotiated with the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    /// Source information of the entry raising this warning.
+    pub source_info: SourceInfo,
+    pub kind: WarningKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WarningKind {
+    /// A TLS version older than TLS 1.2 has been negotiated.
+    DeprecatedTlsVersion { version: String },
+}
+
+impl Warning {
+    /// Creates a new warning.
+    pub fn new(source_info: SourceInfo, kind: WarningKind) -> Self {
+        Warning { source_info, kind }
+    }
+
+    /// Returns a stable identifier for this warning.
+    pub fn code(&self) -> &'static str {
+        match self.kind {
+            WarningKind::DeprecatedTlsVersion { .. } => "deprecated_tls_version",
+        }
+    }
+
+    /// Returns a human readable message for this warning.
+    pub fn message(&self) -> String {
+        match &self.kind {
+            WarningKind::DeprecatedTlsVersion { version } => {
+                format!(
+                    "deprecated TLS version {version} negotiated, TLS 1.2 or later should be used"
+                )
+            }
+        }
+    }
+}
+
+/// Returns the warnings raised by the HTTP `calls` of an entry, with `source_info`.
+///
+/// A given warning is only raised once per entry, even if several calls (redirections for
+/// instance) trigger it.
+pub fn eval_warnings(calls: &[Call], source_info: SourceInfo) -> Vec<Warning> {
+    let mut warnings = vec![];
+    for call in calls {
+        let Some(version) = &call.response.tls_version else {
+            continue;
+        };
+        if !DEPRECATED_TLS_VERSIONS.contains(&version.as_str()) {
+            continue;
+        }
+        let kind = WarningKind::DeprecatedTlsVersion {
+            version: version.clone(),
+        };
+        let warning = Warning::new(source_info, kind);
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use hurl_core::reader::Pos;
+
+    use super::*;
+    use crate::http::{HeaderVec, HttpVersion, Request, Response, Url};
+
+    fn call(tls_version: Option<&str>) -> Call {
+        let url = "https://localhost:8001/hello".parse::<Url>().unwrap();
+        Call {
+            request: Request::new("GET", url.clone(), HeaderVec::new(), vec![]),
+            response: Response {
+                version: HttpVersion::Http11,
+                status: 200,
+                headers: HeaderVec::new(),
+                body: vec![],
+                duration: Default::default(),
+                url,
+                certificate: None,
+                tls_version: tls_version.map(|v| v.to_string()),
+            },
+            timings: Default::default(),
+            h2_frames: vec![],
+        }
+    }
+
+    #[test]
+    fn eval_warnings_deprecated_tls() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 35));
+
+        // A server only accepting TLS 1.1, followed by a redirection on the same server.
+        let calls = vec![call(Some("TLSv1.1")), call(Some("TLSv1.1"))];
+        let warnings = eval_warnings(&calls, source_info);
+        assert_eq!(
+            warnings,
+            vec![Warning::new(
+                source_info,
+                WarningKind::DeprecatedTlsVersion {
+                    version: "TLSv1.1".to_string()
+                }
+            )]
+        );
+        assert_eq!(warnings[0].code(), "deprecated_tls_version");
+        assert_eq!(
+            warnings[0].message(),
+            "deprecated TLS version TLSv1.1 negotiated, TLS 1.2 or later should be used"
+        );
+    }
+
+    #[test]
+    fn eval_warnings_none() {
+        let source_info = SourceInfo::new(Pos::new(1, 1), Pos::new(1, 35));
+        let calls = vec![call(None), call(Some("TLSv1.2")), call(Some("TLSv1.3"))];
+        assert!(eval_warnings(&calls, source_info).is_empty());
+    }
+}
@@ -16,19 +16,26 @@
  *
  */
 use std::ffi::{CStr, CString};
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_ulong};
 use std::ptr;
 
 use libxml::bindings::{htmlReadMemory, xmlReadMemory};
 use libxml::parser::{ParseFormat, Parser, XmlParseError};
+use libxml::tree::{Node, NodeType};
 
 use crate::runner::{Number, Value};
 
 /// An error for XPath evaluation.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum XPathError {
     Eval,
+    /// The namespace prefix used in the expression is not bound to any namespace.
+    UnboundPrefix(String),
     Unsupported,
+    /// The evaluation has exceeded its maximum number of libxml2 operations.
+    OpLimit {
+        op_limit: u64,
+    },
 }
 
 /// A structure to hold a libxml document tree.
@@ -68,14 +75,137 @@ impl Document {
         Ok(doc)
     }
 
-    /// Evaluates a XPath 1.0 expression `expr` against a document.
-    pub fn eval_xpath(&self, expr: &str) -> Result<Value, XPathError> {
+    /// Evaluates a XPath 1.0 expression `expr` against a document, aborting the evaluation
+    /// after `op_limit` libxml2 operations.
+    pub fn eval_xpath(&self, expr: &str, op_limit: u64) -> Result<Value, XPathError> {
+        let support_ns = match self.format {
+            Format::Html => false,
+            Format::Xml => true,
+        };
+        libxml_eval_xpath(&self.inner, expr, support_ns, &[], op_limit)
+    }
+
+    /// Evaluates a XPath 1.0 expression `expr` against a document, binding each `(prefix, href)`
+    /// of `namespaces` in the XPath context.
+    ///
+    /// The bindings take precedence over the namespaces declared in the document.
+    pub fn eval_xpath_with_namespaces(
+        &self,
+        expr: &str,
+        namespaces: &[(String, String)],
+        op_limit: u64,
+    ) -> Result<Value, XPathError> {
+        let support_ns = match self.format {
+            Format::Html => false,
+            Format::Xml => true,
+        };
+        libxml_eval_xpath(&self.inner, expr, support_ns, namespaces, op_limit)
+    }
+
+    /// Evaluates a XPath 1.0 expression `expr` against a document, and returns the value of the
+    /// attribute `attr` of the first selected element.
+    ///
+    /// Returns `None` if no element is selected, or if the element has no attribute `attr`.
+    pub fn eval_xpath_attr(
+        &self,
+        expr: &str,
+        attr: &str,
+        op_limit: u64,
+    ) -> Result<Option<String>, XPathError> {
         let support_ns = match self.format {
             Format::Html => false,
             Format::Xml => true,
         };
-        libxml_eval_xpath(&self.inner, expr, support_ns)
+        let nodes = libxml_eval_xpath_nodes(&self.inner, expr, support_ns, op_limit)?;
+        Ok(nodes.first().and_then(|node| node.get_attribute(attr)))
+    }
+
+    /// Evaluates a XPath 1.0 expression `expr` against a document, and returns the markup of the
+    /// first selected node, including the node itself.
+    ///
+    /// Returns `None` if no node is selected.
+    pub fn eval_xpath_outer_html(
+        &self,
+        expr: &str,
+        op_limit: u64,
+    ) -> Result<Option<String>, XPathError> {
+        let support_ns = match self.format {
+            Format::Html => false,
+            Format::Xml => true,
+        };
+        let nodes = libxml_eval_xpath_nodes(&self.inner, expr, support_ns, op_limit)?;
+        Ok(nodes.first().map(|node| self.inner.node_to_string(node)))
+    }
+
+    /// Converts this document to an object value.
+    ///
+    /// The root element is converted to an object with a single key, the name of the element.
+    /// Each element is converted using the following convention:
+    /// - attributes are keys prefixed with `@`,
+    /// - child elements are keys with their (prefixed) name, repeated elements are grouped in a list,
+    /// - text content (trimmed) is under the `#text` key,
+    /// - an element with only text is converted to a string, and an empty element to null.
+    pub fn to_value(&self) -> Value {
+        match self.inner.get_root_element() {
+            Some(root) => Value::Object(vec![(node_name(&root), element_to_value(&root))]),
+            None => Value::Null,
+        }
+    }
+}
+
+/// Returns the name of an element `node`, with its namespace prefix if any.
+fn node_name(node: &Node) -> String {
+    match node.get_namespace() {
+        Some(ns) if !ns.get_prefix().is_empty() => {
+            format!("{}:{}", ns.get_prefix(), node.get_name())
+        }
+        _ => node.get_name(),
+    }
+}
+
+/// Converts an element `node` to a value, see [`Document::to_value`].
+fn element_to_value(node: &Node) -> Value {
+    let mut attributes = node.get_properties().into_iter().collect::<Vec<_>>();
+    attributes.sort();
+    let mut props = attributes
+        .into_iter()
+        .map(|(name, value)| (format!("@{name}"), Value::String(value)))
+        .collect::<Vec<_>>();
+
+    let mut text = String::new();
+    for child in node.get_child_nodes() {
+        match child.get_type() {
+            Some(NodeType::ElementNode) => {
+                let name = node_name(&child);
+                let value = element_to_value(&child);
+                match props.iter_mut().find(|(n, _)| *n == name) {
+                    Some((_, Value::List(values))) => values.push(value),
+                    Some((_, existing)) => {
+                        let first = std::mem::replace(existing, Value::Null);
+                        *existing = Value::List(vec![first, value]);
+                    }
+                    None => props.push((name, value)),
+                }
+            }
+            Some(NodeType::TextNode) | Some(NodeType::CDataSectionNode) => {
+                text.push_str(&child.get_content());
+            }
+            _ => {}
+        }
+    }
+
+    let text = text.trim();
+    if props.is_empty() {
+        return if text.is_empty() {
+            Value::Null
+        } else {
+            Value::String(text.to_string())
+        };
+    }
+    if !text.is_empty() {
+        props.push(("#text".to_string(), Value::String(text.to_string())));
     }
+    Value::Object(props)
 }
 
 /// FIXME: Here are some patched functions of libxml crate.
@@ -137,47 +267,114 @@ extern "C" {
     );
 }
 
-/// Registers all XML namespaces from a document `doc` to a `context`.
-fn register_namespaces(doc: &libxml::tree::Document, context: &libxml::xpath::Context) {
+/// Registers all XML namespaces from a document `doc` to a `context`, and returns the registered
+/// prefixes.
+fn register_namespaces(
+    doc: &libxml::tree::Document,
+    context: &libxml::xpath::Context,
+) -> Vec<String> {
     // We walk through the xml document to register each namespace,
     // so we can eval xpath queries with namespace. For convenience, we register the
     // first default namespace with _ prefix. Other default namespaces are not registered
     // and should be referenced vi `local-name` or `name` XPath functions.
     let namespaces = document_namespaces(doc);
     let mut default_registered = false;
+    let mut prefixes = vec![];
 
     for n in namespaces {
         if n.prefix.is_empty() {
             if !default_registered {
                 context.register_namespace("_", &n.href).unwrap();
                 default_registered = true;
+                prefixes.push("_".to_string());
             }
         } else {
             context.register_namespace(&n.prefix, &n.href).unwrap();
+            prefixes.push(n.prefix);
+        }
+    }
+    prefixes
+}
+
+/// Returns the namespace prefixes used in the XPath expression `expr`, in order of appearance.
+///
+/// Axis separators (`child::`) and string literals are not taken into account.
+fn xpath_prefixes(expr: &str) -> Vec<String> {
+    let is_name_start = |c: char| c.is_alphabetic() || c == '_';
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-' || c == '.';
+    let chars = expr.chars().collect::<Vec<_>>();
+    let mut prefixes: Vec<String> = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' || c == '\'' {
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                i += 1;
+            }
+            i += 1;
+        } else if is_name_start(c) {
+            let start = i;
+            while i < chars.len() && is_name_char(chars[i]) {
+                i += 1;
+            }
+            let next = chars.get(i + 1).copied();
+            if chars.get(i) == Some(&':') && next.is_some_and(|n| is_name_start(n) || n == '*') {
+                let prefix = chars[start..i].iter().collect::<String>();
+                if !prefixes.contains(&prefix) {
+                    prefixes.push(prefix);
+                }
+            }
+        } else {
+            i += 1;
         }
     }
+    prefixes
 }
 
-/// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using namespace.
+/// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, optionally using
+/// namespace, with additional `namespaces` bindings.
+///
+/// The evaluation is aborted after `op_limit` libxml2 operations.
 fn libxml_eval_xpath(
     doc: &libxml::tree::Document,
     expr: &str,
     support_ns: bool,
+    namespaces: &[(String, String)],
+    op_limit: u64,
 ) -> Result<Value, XPathError> {
     let context = libxml::xpath::Context::new(doc).expect("error setting context in xpath module");
+    set_op_limit(&context, op_limit);
 
     // libxml2 prints to stdout warning and errors, so we mut it.
     unsafe {
         libxml::bindings::initGenericErrorDefaultFunc(&mut Some(silentErrorFunc));
     }
 
+    // The `xml` prefix is always bound.
+    let mut prefixes = vec!["xml".to_string()];
     if support_ns {
-        register_namespaces(doc, &context);
+        prefixes.extend(register_namespaces(doc, &context));
+    }
+    for (prefix, href) in namespaces {
+        if context.register_namespace(prefix, href).is_err() {
+            return Err(XPathError::Eval);
+        }
+        prefixes.push(prefix.clone());
     }
 
     let result = match context.evaluate(expr) {
         Ok(object) => object,
-        Err(_) => return Err(XPathError::Eval),
+        Err(_) if op_limit_exceeded(&context) => return Err(XPathError::OpLimit { op_limit }),
+        Err(_) => {
+            let unbound = xpath_prefixes(expr)
+                .into_iter()
+                .find(|p| !prefixes.contains(p));
+            return match unbound {
+                Some(prefix) => Err(XPathError::UnboundPrefix(prefix)),
+                None => Err(XPathError::Eval),
+            };
+        }
     };
 
     match unsafe { *result.ptr }.type_ {
@@ -203,6 +400,63 @@ fn libxml_eval_xpath(
     }
 }
 
+/// Evaluates a XPath 1.0 expression `expr` against an libxml2 document `doc`, and returns
+/// the selected nodes.
+///
+/// Returns [`XPathError::Unsupported`] if `expr` doesn't evaluate to a node-set. The evaluation
+/// is aborted after `op_limit` libxml2 operations.
+fn libxml_eval_xpath_nodes(
+    doc: &libxml::tree::Document,
+    expr: &str,
+    support_ns: bool,
+    op_limit: u64,
+) -> Result<Vec<Node>, XPathError> {
+    let context = libxml::xpath::Context::new(doc).expect("error setting context in xpath module");
+    set_op_limit(&context, op_limit);
+
+    // libxml2 prints to stdout warning and errors, so we mut it.
+    unsafe {
+        libxml::bindings::initGenericErrorDefaultFunc(&mut Some(silentErrorFunc));
+    }
+
+    if support_ns {
+        register_namespaces(doc, &context);
+    }
+
+    let result = match context.evaluate(expr) {
+        Ok(object) => object,
+        Err(_) if op_limit_exceeded(&context) => return Err(XPathError::OpLimit { op_limit }),
+        Err(_) => return Err(XPathError::Eval),
+    };
+
+    match unsafe { *result.ptr }.type_ {
+        libxml::bindings::xmlXPathObjectType_XPATH_NODESET => Ok(result.get_nodes_as_vec()),
+        _ => Err(XPathError::Unsupported),
+    }
+}
+
+/// Limits the number of operations libxml2 runs to evaluate an expression in `context` to
+/// `op_limit`.
+///
+/// libxml2 checks this limit while evaluating, and aborts the evaluation once it's exceeded: a
+/// pathological document or expression can't hang the run.
+fn set_op_limit(context: &libxml::xpath::Context, op_limit: u64) {
+    let op_limit = c_ulong::try_from(op_limit).unwrap_or(c_ulong::MAX);
+    unsafe {
+        let ptr = context.as_ptr();
+        (*ptr).opLimit = op_limit;
+        (*ptr).opCount = 0;
+    }
+}
+
+/// Returns `true` if an evaluation in `context` has been aborted by its operations limit.
+fn op_limit_exceeded(context: &libxml::xpath::Context) -> bool {
+    unsafe {
+        let ptr = context.as_ptr();
+        (*ptr).opLimit != 0 && (*ptr).opCount >= (*ptr).opLimit
+    }
+}
+
 /// A XML namespace
 #[derive(Debug, PartialEq, Eq)]
 struct Namespace {
@@ -256,6 +510,7 @@ fn namespaces(node: &libxml::tree::Node) -> Vec<Namespace> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::runner::eval_context::DEFAULT_XPATH_OP_LIMIT;
 
     #[test]
     fn test_xml() {
@@ -270,22 +525,25 @@ mod tests {
 
         let xpath = "count(//food/*)";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::Number(Number::from(3.0))
         );
 
         let xpath = "//food/*";
-        assert_eq!(doc.eval_xpath(xpath).unwrap(), Value::Nodeset(3));
+        assert_eq!(
+            doc.eval_xpath(xpath, DEFAULT_XPATH_OP_LIMIT).unwrap(),
+            Value::Nodeset(3)
+        );
 
         let xpath = "count(//*[@type='fruit'])";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::Number(Number::from(2.0))
         );
 
         let xpath = "number(//food/banana/@price)";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::Number(Number::from(1.1))
         );
     }
@@ -295,11 +553,157 @@ mod tests {
         let xml = "<a/>";
         let doc = Document::parse(xml, Format::Xml).unwrap();
 
-        assert_eq!(doc.eval_xpath("^^^").unwrap_err(), XPathError::Eval);
-        assert_eq!(doc.eval_xpath("//").unwrap_err(), XPathError::Eval);
+        assert_eq!(
+            doc.eval_xpath("^^^", DEFAULT_XPATH_OP_LIMIT).unwrap_err(),
+            XPathError::Eval
+        );
+        assert_eq!(
+            doc.eval_xpath("//", DEFAULT_XPATH_OP_LIMIT).unwrap_err(),
+            XPathError::Eval
+        );
         // assert_eq!(1,2);
     }
 
+    #[test]
+    fn test_error_op_limit() {
+        let xml = format!("<a>{}</a>", "<b/>".repeat(100));
+        let doc = Document::parse(&xml, Format::Xml).unwrap();
+
+        let expr = "count(//*[count(//*) > 0])";
+        assert_eq!(
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
+            Value::Number(Number::from(101.0))
+        );
+        assert_eq!(
+            doc.eval_xpath(expr, 1000).unwrap_err(),
+            XPathError::OpLimit { op_limit: 1000 }
+        );
+        assert_eq!(
+            doc.eval_xpath_outer_html("//b[count(//*) > 0]", 1000)
+                .unwrap_err(),
+            XPathError::OpLimit { op_limit: 1000 }
+        );
+        assert_eq!(
+            doc.eval_xpath_attr("//b[count(//*) > 0]", "id", 1000)
+                .unwrap_err(),
+            XPathError::OpLimit { op_limit: 1000 }
+        );
+
+        // The limit is per evaluation.
+        for _ in 0..10 {
+            assert_eq!(
+                doc.eval_xpath("count(//b)", 1000).unwrap(),
+                Value::Number(Number::from(100.0))
+            );
+        }
+    }
+
+    #[test]
+    fn test_xpath_attr() {
+        let html = r#"<html><body><a href="https://hurl.dev">Hurl</a><a>No link</a></body></html>"#;
+        let doc = Document::parse(html, Format::Html).unwrap();
+
+        assert_eq!(
+            doc.eval_xpath_attr("//a", "href", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            Some("https://hurl.dev".to_string())
+        );
+        assert_eq!(
+            doc.eval_xpath_attr("//a[2]", "href", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            doc.eval_xpath_attr("//a", "title", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            doc.eval_xpath_attr("//img", "src", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            doc.eval_xpath_attr("count(//a)", "href", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap_err(),
+            XPathError::Unsupported
+        );
+        assert_eq!(
+            doc.eval_xpath_attr("^^^", "href", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap_err(),
+            XPathError::Eval
+        );
+    }
+
+    #[test]
+    fn test_xpath_outer_html() {
+        let html = r#"<html><body><p>Hello <b>world</b>!</p></body></html>"#;
+        let doc = Document::parse(html, Format::Html).unwrap();
+
+        assert_eq!(
+            doc.eval_xpath_outer_html("//b", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            Some("<b>world</b>".to_string())
+        );
+        assert_eq!(
+            doc.eval_xpath_outer_html("//i", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            doc.eval_xpath_outer_html("string(//b)", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap_err(),
+            XPathError::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_to_value() {
+        let xml = r#"<a x="1">hi</a>"#;
+        let doc = Document::parse(xml, Format::Xml).unwrap();
+        assert_eq!(
+            doc.to_value(),
+            Value::Object(vec![(
+                "a".to_string(),
+                Value::Object(vec![
+                    ("@x".to_string(), Value::String("1".to_string())),
+                    ("#text".to_string(), Value::String("hi".to_string())),
+                ])
+            )])
+        );
+
+        let xml = r#"<?xml version="1.0"?>
+<books>
+  <book id="1"><title>Dune</title></book>
+  <book id="2"><title>Foundation</title><draft/></book>
+  <count>2</count>
+</books>"#;
+        let doc = Document::parse(xml, Format::Xml).unwrap();
+        assert_eq!(
+            doc.to_value(),
+            Value::Object(vec![(
+                "books".to_string(),
+                Value::Object(vec![
+                    (
+                        "book".to_string(),
+                        Value::List(vec![
+                            Value::Object(vec![
+                                ("@id".to_string(), Value::String("1".to_string())),
+                                ("title".to_string(), Value::String("Dune".to_string())),
+                            ]),
+                            Value::Object(vec![
+                                ("@id".to_string(), Value::String("2".to_string())),
+                                ("title".to_string(), Value::String("Foundation".to_string())),
+                                ("draft".to_string(), Value::Null),
+                            ]),
+                        ])
+                    ),
+                    ("count".to_string(), Value::String("2".to_string())),
+                ])
+            )])
+        );
+    }
+
     // TBC!!!
     // Invalid XML not detected at parsing??? => goes into an eval error
     #[test]
@@ -315,7 +719,8 @@ mod tests {
         let doc = Document::parse(xml, Format::Xml).unwrap();
 
         assert_eq!(
-            doc.eval_xpath("normalize-space(//data)").unwrap(),
+            doc.eval_xpath("normalize-space(//data)", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
             Value::String(String::from("café"))
         );
     }
@@ -326,7 +731,8 @@ mod tests {
         let doc = Document::parse(html, Format::Html).unwrap();
 
         assert_eq!(
-            doc.eval_xpath("normalize-space(//data)").unwrap(),
+            doc.eval_xpath("normalize-space(//data)", DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
             Value::String(String::from("café"))
         );
     }
@@ -344,7 +750,7 @@ mod tests {
         let doc = Document::parse(html, Format::Html).unwrap();
         let xpath = "normalize-space(/html/head/meta/@charset)";
         assert_eq!(
-            doc.eval_xpath(xpath).unwrap(),
+            doc.eval_xpath(xpath, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String(String::from("UTF-8"))
         );
     }
@@ -354,7 +760,10 @@ mod tests {
         let html = r#"<html></html>"#;
         let doc = Document::parse(html, Format::Html).unwrap();
         let xpath = "boolean(count(//a[contains(@href,'xxx')]))";
-        assert_eq!(doc.eval_xpath(xpath).unwrap(), Value::Bool(false));
+        assert_eq!(
+            doc.eval_xpath(xpath, DEFAULT_XPATH_OP_LIMIT).unwrap(),
+            Value::Bool(false)
+        );
     }
 
     #[test]
@@ -362,7 +771,10 @@ mod tests {
         let html = r#"<html></html>"#;
         let doc = Document::parse(html, Format::Html).unwrap();
         let xpath = "strong(//head/title)";
-        assert_eq!(doc.eval_xpath(xpath).unwrap_err(), XPathError::Eval);
+        assert_eq!(
+            doc.eval_xpath(xpath, DEFAULT_XPATH_OP_LIMIT).unwrap_err(),
+            XPathError::Eval
+        );
     }
 
     #[test]
@@ -379,26 +791,26 @@ mod tests {
 
         let expr = "string(//a:books/b:book/b:title)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("Dune".to_string())
         );
 
         let expr = "string(//a:books/b:book/c:author)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("Franck Herbert".to_string())
         );
 
         let expr = "string(//*[name()='a:books']/*[name()='b:book']/*[name()='c:author'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("Franck Herbert".to_string())
         );
 
         let expr =
             "string(//*[local-name()='books']/*[local-name()='book']/*[local-name()='author'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("Franck Herbert".to_string())
         );
     }
@@ -414,19 +826,19 @@ mod tests {
 
         let expr = "string(//_:svg/_:text)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("SVG".to_string())
         );
 
         let expr = "string(//*[name()='svg']/*[name()='text'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("SVG".to_string())
         );
 
         let expr = "string(//*[local-name()='svg']/*[local-name()='text'])";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("SVG".to_string())
         );
     }
@@ -452,19 +864,19 @@ mod tests {
 
         let expr = "string(//soap:Envelope/soap:Body/ns1:OTA_AirAvailRS/@TransactionIdentifier)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("TID$16459590516432752971.demo2144".to_string())
         );
 
         let expr = "string(//*[name()='soap:Envelope']/*[name()='soap:Body']/*[name()='ns1:OTA_AirAvailRS']/@TransactionIdentifier)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("TID$16459590516432752971.demo2144".to_string())
         );
 
         let expr = "string(//*[local-name()='Envelope']/*[local-name()='Body']/*[local-name()='OTA_AirAvailRS']/@TransactionIdentifier)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("TID$16459590516432752971.demo2144".to_string())
         );
     }
@@ -490,20 +902,75 @@ mod tests {
 
         let expr = "string(//_:book/_:title)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("Cheaper by the Dozen".to_string())
         );
 
         let expr = "string(//_:book/isbn:number)";
         assert_eq!(
-            doc.eval_xpath(expr).unwrap(),
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
             Value::String("1568491379".to_string())
         );
 
         let expr = "//*[name()='book']/*[name()='notes']";
-        assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(1));
+        assert_eq!(
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
+            Value::Nodeset(1)
+        );
 
         let expr = "//_:book/_:notes/*[local-name()='p']";
-        assert_eq!(doc.eval_xpath(expr).unwrap(), Value::Nodeset(1));
+        assert_eq!(
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap(),
+            Value::Nodeset(1)
+        );
+    }
+
+    #[test]
+    fn test_namespaces_bindings() {
+        let xml = r#"<?xml version="1.0"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+    <entry><title>First</title></entry>
+    <entry><title>Second</title></entry>
+</feed>"#;
+        let doc = Document::parse(xml, Format::Xml).unwrap();
+        let namespaces = vec![(
+            "atom".to_string(),
+            "http://www.w3.org/2005/Atom".to_string(),
+        )];
+
+        let expr = "//atom:entry";
+        assert_eq!(
+            doc.eval_xpath_with_namespaces(expr, &namespaces, DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            Value::Nodeset(2)
+        );
+        assert_eq!(
+            doc.eval_xpath(expr, DEFAULT_XPATH_OP_LIMIT).unwrap_err(),
+            XPathError::UnboundPrefix("atom".to_string())
+        );
+
+        let expr = "string(//atom:entry[2]/atom:title)";
+        assert_eq!(
+            doc.eval_xpath_with_namespaces(expr, &namespaces, DEFAULT_XPATH_OP_LIMIT)
+                .unwrap(),
+            Value::String("Second".to_string())
+        );
+
+        let expr = "//atom:entry/dc:title";
+        assert_eq!(
+            doc.eval_xpath_with_namespaces(expr, &namespaces, DEFAULT_XPATH_OP_LIMIT)
+                .unwrap_err(),
+            XPathError::UnboundPrefix("dc".to_string())
+        );
+    }
+
+    #[test]
+    fn test_xpath_prefixes() {
+        assert!(xpath_prefixes("//entry").is_empty());
+        assert_eq!(
+            xpath_prefixes("//atom:feed/atom:entry[@xml:lang='fr']/dc:*"),
+            vec!["atom".to_string(), "xml".to_string(), "dc".to_string()]
+        );
+        assert!(xpath_prefixes("child::entry[contains(., 'a:b')]").is_empty());
     }
 }
@@ -22,7 +22,7 @@ use hurl_core::error::{DisplaySourceError, OutputFormat};
 use hurl_core::input::Input;
 use hurl_core::text::{Format, Style, StyledString};
 
-use crate::runner::Value;
+use crate::runner::{RunnerError, Value};
 use crate::util::term::Stderr;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -276,15 +276,17 @@ impl Logger {
         self.error_rich(&message);
     }
 
-    pub fn error_runtime_rich<E: DisplaySourceError>(
+    /// Logs a runtime `error`, structured actual values of assert failures being displayed on
+    /// multiple lines.
+    pub fn error_runtime_rich(
         &mut self,
         content: &str,
         filename: Option<&Input>,
-        error: &E,
+        error: &RunnerError,
         entry_src_info: SourceInfo,
     ) {
         let filename = filename.map_or(String::new(), |f| f.to_string());
-        let message = error.to_string(
+        let message = error.to_pretty().to_string(
             &filename,
             content,
             Some(entry_src_info),
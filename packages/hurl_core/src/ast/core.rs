@@ -316,6 +316,10 @@ pub enum QueryValue {
         space0: Whitespace,
         name: Template,
     },
+    Headers {
+        space0: Whitespace,
+        name: Template,
+    },
     Cookie {
         space0: Whitespace,
         expr: CookiePath,
@@ -339,6 +343,20 @@ pub enum QueryValue {
     },
     Duration,
     Bytes,
+    RawBytes,
+    DetectedContentType,
+    /// Transfer encoding of the response (`chunked` for instance).
+    TransferEncoding,
+    RawResponse,
+    RequestBody,
+    RequestHeader {
+        space0: Whitespace,
+        name: Template,
+    },
+    QueryParam {
+        space0: Whitespace,
+        name: Template,
+    },
     Sha256,
     Md5,
     Certificate {
@@ -481,6 +499,10 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    ContainsSubset {
+        space0: Whitespace,
+        object: json::Value,
+    },
     Include {
         space0: Whitespace,
         value: PredicateValue,
@@ -489,6 +511,34 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    MatchGlob {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    AnyLineMatch {
+        space0: Whitespace,
+        value: PredicateValue,
+    },
+    /// Patterns of a `matchesAll` predicate, each one preceded by its whitespace.
+    MatchesAll {
+        patterns: Vec<(Whitespace, PredicateValue)>,
+    },
+    HasLength {
+        space0: Whitespace,
+        n: u64,
+    },
+    IsInRange {
+        space0: Whitespace,
+        min: PredicateValue,
+        space1: Whitespace,
+        max: PredicateValue,
+    },
+    IsCloseTo {
+        space0: Whitespace,
+        value: PredicateValue,
+        space1: Whitespace,
+        epsilon: PredicateValue,
+    },
     IsInteger,
     IsFloat,
     IsBoolean,
@@ -499,6 +549,11 @@ pub enum PredicateFuncValue {
     Exist,
     IsEmpty,
     IsNumber,
+    IsNull,
+    IsNotNull,
+    IsTrue,
+    IsFalse,
+    IsCookieSecureCompliant,
 }
 
 //
@@ -623,6 +678,13 @@ pub enum Number {
     BigInteger(String),
 }
 
+/// A number, given as a literal or as a placeholder (`{{total}}`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NumberValue {
+    Literal(Number),
+    Placeholder(Placeholder),
+}
+
 // keep Number terminology for both Integer and Decimal Numbers
 // different representation for the same float value
 // 1.01 and 1.010
@@ -708,7 +770,7 @@ pub struct Expr {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ExprKind {
     Variable(Variable),
-    Function(Function),
+    Function(Box<Function>),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -719,14 +781,25 @@ pub struct Variable {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Function {
+    GetEnv {
+        space0: Whitespace,
+        name: String,
+        default: Option<(Whitespace, String)>,
+    },
     NewDate,
     NewUuid,
+    RandomInt {
+        space0: Whitespace,
+        min: i64,
+        space1: Whitespace,
+        max: i64,
+    },
 }
 
 /// Check that variable name is not reserved
 /// (would conflicts with an existing function)
 pub fn is_variable_reserved(name: &str) -> bool {
-    ["getEnv", "newDate", "newUuid"].contains(&name)
+    ["getEnv", "newDate", "newUuid", "randomInt"].contains(&name)
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -911,51 +984,152 @@ pub struct Filter {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum FilterValue {
+    Base64UrlSafeDecode,
+    Base64UrlSafeEncode,
+    BytesLength,
+    CamelCase,
+    Clamp {
+        space0: Whitespace,
+        min: Number,
+        space1: Whitespace,
+        max: Number,
+    },
     Count,
+    CountMatches {
+        space0: Whitespace,
+        pattern: RegexValue,
+    },
     DaysAfterNow,
     DaysBeforeNow,
     Decode {
         space0: Whitespace,
         encoding: Template,
     },
+    Diff {
+        space0: Whitespace,
+        other: json::Value,
+    },
     Format {
         space0: Whitespace,
         fmt: Template,
     },
     HtmlEscape,
     HtmlUnescape,
+    Join {
+        space0: Whitespace,
+        sep: Template,
+    },
+    JsonEscape,
     JsonPath {
         space0: Whitespace,
         expr: Template,
+        /// Optional expected type of the matched value (`as number`).
+        expected_type: Option<(Whitespace, Whitespace, String)>,
+    },
+    JsonPathPointers {
+        space0: Whitespace,
+        expr: Template,
+    },
+    JsonQuote,
+    JsonUnescape,
+    JsonMerge {
+        space0: Whitespace,
+        other: json::Value,
     },
+    KebabCase,
+    Ndjson,
+    NormalizeWhitespace,
     Nth {
         space0: Whitespace,
         n: u64,
     },
+    Optional,
+    ParseCsv {
+        space0: Whitespace,
+        has_header: bool,
+    },
+    PercentOf {
+        space0: Whitespace,
+        total: NumberValue,
+    },
     Regex {
         space0: Whitespace,
         value: RegexValue,
     },
+    RegexAll {
+        space0: Whitespace,
+        value: RegexValue,
+    },
+    Repeat {
+        space0: Whitespace,
+        count: i64,
+    },
     Replace {
         space0: Whitespace,
         old_value: RegexValue,
         space1: Whitespace,
         new_value: Template,
     },
+    ReplaceLiteral {
+        space0: Whitespace,
+        old_value: Template,
+        space1: Whitespace,
+        new_value: Template,
+    },
+    RetryAfterSeconds,
+    SnakeCase,
     Split {
         space0: Whitespace,
         sep: Template,
     },
+    StripPrefix {
+        space0: Whitespace,
+        prefix: Template,
+    },
+    StripSuffix {
+        space0: Whitespace,
+        suffix: Template,
+    },
+    TitleCase,
+    ToBool,
     ToDate {
         space0: Whitespace,
         fmt: Template,
     },
     ToFloat,
     ToInt,
-    UrlDecode,
-    UrlEncode,
+    Type,
+    UrlDecode {
+        /// Optional decoding mode (`urlDecode form`).
+        mode: Option<(Whitespace, String)>,
+    },
+    UrlEncode {
+        /// Optional encoding mode (`urlEncode component`).
+        mode: Option<(Whitespace, String)>,
+    },
+    UrlHost,
+    UrlPath,
+    UrlQuery,
+    UrlScheme,
+    XmlToJson,
     XPath {
         space0: Whitespace,
         expr: Template,
+        /// Optional namespace bindings (`namespaces {"atom": "http://www.w3.org/2005/Atom"}`).
+        namespaces: Option<(Whitespace, Whitespace, json::Value)>,
+    },
+    XPathAttr {
+        space0: Whitespace,
+        expr: Template,
+        space1: Whitespace,
+        attr: Template,
+    },
+    XPathOuterHtml {
+        space0: Whitespace,
+        expr: Template,
+    },
+    XPathXml {
+        space0: Whitespace,
+        expr: Template,
     },
 }
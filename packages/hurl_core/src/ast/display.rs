@@ -18,9 +18,9 @@
 use crate::ast::{
     BooleanOption, CookieAttribute, CookieAttributeName, CookiePath, CountOption, DurationOption,
     Expr, ExprKind, Float, Function, Hex, Method, MultilineString, MultilineStringAttribute,
-    MultilineStringKind, NaturalOption, Number, Placeholder, PredicateFuncValue, Regex, Status,
-    StatusValue, Template, TemplateElement, Variable, VariableDefinition, VariableValue, Version,
-    VersionValue,
+    MultilineStringKind, NaturalOption, Number, NumberValue, Placeholder, PredicateFuncValue,
+    Regex, Status, StatusValue, Template, TemplateElement, Variable, VariableDefinition,
+    VariableValue, Version, VersionValue,
 };
 use core::fmt;
 
@@ -131,8 +131,25 @@ impl fmt::Display for Variable {
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Function::GetEnv {
+                space0,
+                name,
+                default,
+            } => {
+                write!(f, "getEnv{}{name}", space0.value)?;
+                if let Some((space1, default)) = default {
+                    write!(f, "{}{default}", space1.value)?;
+                }
+                Ok(())
+            }
             Function::NewDate => write!(f, "newDate"),
             Function::NewUuid => write!(f, "newUuid"),
+            Function::RandomInt {
+                space0,
+                min,
+                space1,
+                max,
+            } => write!(f, "randomInt{}{min}{}{max}", space0.value, space1.value),
         }
     }
 }
@@ -230,6 +247,15 @@ impl fmt::Display for NaturalOption {
     }
 }
 
+impl fmt::Display for NumberValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberValue::Literal(v) => write!(f, "{}", v),
+            NumberValue::Placeholder(v) => write!(f, "{}", v),
+        }
+    }
+}
+
 impl fmt::Display for CountOption {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -314,8 +340,15 @@ impl PredicateFuncValue {
             PredicateFuncValue::StartWith { .. } => "startsWith".to_string(),
             PredicateFuncValue::EndWith { .. } => "endsWith".to_string(),
             PredicateFuncValue::Contain { .. } => "contains".to_string(),
+            PredicateFuncValue::ContainsSubset { .. } => "containsSubset".to_string(),
             PredicateFuncValue::Include { .. } => "includes".to_string(),
             PredicateFuncValue::Match { .. } => "matches".to_string(),
+            PredicateFuncValue::MatchGlob { .. } => "matchesGlob".to_string(),
+            PredicateFuncValue::AnyLineMatch { .. } => "anyLineMatches".to_string(),
+            PredicateFuncValue::MatchesAll { .. } => "matchesAll".to_string(),
+            PredicateFuncValue::HasLength { .. } => "hasLength".to_string(),
+            PredicateFuncValue::IsInRange { .. } => "isInRange".to_string(),
+            PredicateFuncValue::IsCloseTo { .. } => "isCloseTo".to_string(),
             PredicateFuncValue::IsInteger => "isInteger".to_string(),
             PredicateFuncValue::IsFloat => "isFloat".to_string(),
             PredicateFuncValue::IsBoolean => "isBoolean".to_string(),
@@ -326,6 +359,11 @@ impl PredicateFuncValue {
             PredicateFuncValue::Exist => "exists".to_string(),
             PredicateFuncValue::IsEmpty => "isEmpty".to_string(),
             PredicateFuncValue::IsNumber => "isNumber".to_string(),
+            PredicateFuncValue::IsNull => "isNull".to_string(),
+            PredicateFuncValue::IsNotNull => "isNotNull".to_string(),
+            PredicateFuncValue::IsTrue => "isTrue".to_string(),
+            PredicateFuncValue::IsFalse => "isFalse".to_string(),
+            PredicateFuncValue::IsCookieSecureCompliant => "isCookieSecureCompliant".to_string(),
         }
     }
 }
@@ -20,9 +20,9 @@ use crate::ast::{
     CookieAttribute, CookiePath, CountOption, DurationOption, Entry, EntryOption, File, FileParam,
     FileValue, Filter, FilterValue, GraphQl, GraphQlVariables, Hex, HurlFile, JsonValue, KeyValue,
     LineTerminator, Method, MultilineString, MultilineStringKind, MultipartParam, NaturalOption,
-    OptionKind, Placeholder, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, Query,
-    QueryValue, Regex, RegexValue, Request, Response, Section, SectionValue, Status, Template,
-    TemplateElement, VariableDefinition, VariableValue, Version, Whitespace,
+    NumberValue, OptionKind, Placeholder, Predicate, PredicateFunc, PredicateFuncValue,
+    PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response, Section, SectionValue,
+    Status, Template, TemplateElement, VariableDefinition, VariableValue, Version, Whitespace,
 };
 use crate::typing::Count;
 use std::fmt::Display;
@@ -375,6 +375,11 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(name);
             }
+            QueryValue::Headers { space0, name } => {
+                self.fmt_span("query-type", "headers");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
             QueryValue::Cookie { space0, expr } => {
                 self.fmt_span("query-type", "cookie");
                 self.fmt_space(space0);
@@ -403,6 +408,21 @@ impl HtmlFormatter {
             }
             QueryValue::Duration => self.fmt_span("query-type", "duration"),
             QueryValue::Bytes => self.fmt_span("query-type", "bytes"),
+            QueryValue::RawBytes => self.fmt_span("query-type", "rawBytes"),
+            QueryValue::DetectedContentType => self.fmt_span("query-type", "detectedContentType"),
+            QueryValue::TransferEncoding => self.fmt_span("query-type", "transferEncoding"),
+            QueryValue::RawResponse => self.fmt_span("query-type", "rawResponse"),
+            QueryValue::RequestBody => self.fmt_span("query-type", "requestBody"),
+            QueryValue::RequestHeader { space0, name } => {
+                self.fmt_span("query-type", "requestHeader");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
+            QueryValue::QueryParam { space0, name } => {
+                self.fmt_span("query-type", "queryParam");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
             QueryValue::Sha256 => self.fmt_span("query-type", "sha256"),
             QueryValue::Md5 => self.fmt_span("query-type", "md5"),
             QueryValue::Certificate {
@@ -527,6 +547,10 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::ContainsSubset { space0, object } => {
+                self.fmt_space(space0);
+                self.fmt_json_value(object);
+            }
             PredicateFuncValue::Include { space0, value } => {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
@@ -535,6 +559,46 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::MatchGlob { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
+            PredicateFuncValue::AnyLineMatch { space0, value } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+            }
+            PredicateFuncValue::MatchesAll { patterns } => {
+                for (space, value) in patterns {
+                    self.fmt_space(space);
+                    self.fmt_predicate_value(value);
+                }
+            }
+            PredicateFuncValue::HasLength { space0, n } => {
+                self.fmt_space(space0);
+                self.fmt_number(n);
+            }
+            PredicateFuncValue::IsInRange {
+                space0,
+                min,
+                space1,
+                max,
+            } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(min);
+                self.fmt_space(space1);
+                self.fmt_predicate_value(max);
+            }
+            PredicateFuncValue::IsCloseTo {
+                space0,
+                value,
+                space1,
+                epsilon,
+            } => {
+                self.fmt_space(space0);
+                self.fmt_predicate_value(value);
+                self.fmt_space(space1);
+                self.fmt_predicate_value(epsilon);
+            }
             PredicateFuncValue::IsInteger => {}
             PredicateFuncValue::IsFloat => {}
             PredicateFuncValue::IsBoolean => {}
@@ -545,6 +609,11 @@ impl HtmlFormatter {
             PredicateFuncValue::Exist => {}
             PredicateFuncValue::IsEmpty => {}
             PredicateFuncValue::IsNumber => {}
+            PredicateFuncValue::IsNull => {}
+            PredicateFuncValue::IsNotNull => {}
+            PredicateFuncValue::IsTrue => {}
+            PredicateFuncValue::IsFalse => {}
+            PredicateFuncValue::IsCookieSecureCompliant => {}
         }
     }
 
@@ -716,6 +785,13 @@ impl HtmlFormatter {
         }
     }
 
+    fn fmt_number_value(&mut self, value: &NumberValue) {
+        match value {
+            NumberValue::Literal(value) => self.fmt_number(value),
+            NumberValue::Placeholder(value) => self.fmt_placeholder(value),
+        }
+    }
+
     fn fmt_number<T: Sized + Display>(&mut self, value: T) {
         self.fmt_span("number", &value.to_string());
     }
@@ -796,7 +872,25 @@ impl HtmlFormatter {
 
     fn fmt_filter_value(&mut self, filter_value: &FilterValue) {
         match filter_value {
+            FilterValue::BytesLength => self.fmt_span("filter-type", "bytesLength"),
+            FilterValue::Clamp {
+                space0,
+                min,
+                space1,
+                max,
+            } => {
+                self.fmt_span("filter-type", "clamp");
+                self.fmt_space(space0);
+                self.fmt_number(min);
+                self.fmt_space(space1);
+                self.fmt_number(max);
+            }
             FilterValue::Count => self.fmt_span("filter-type", "count"),
+            FilterValue::CountMatches { space0, pattern } => {
+                self.fmt_span("filter-type", "countMatches");
+                self.fmt_space(space0);
+                self.fmt_regex_value(pattern);
+            }
             FilterValue::DaysAfterNow => self.fmt_span("filter-type", "daysAfterNow"),
             FilterValue::DaysBeforeNow => self.fmt_span("filter-type", "daysBeforeNow"),
             FilterValue::Decode { space0, encoding } => {
@@ -804,6 +898,11 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(encoding);
             }
+            FilterValue::Diff { space0, other } => {
+                self.fmt_span("filter-type", "diff");
+                self.fmt_space(space0);
+                self.fmt_json_value(other);
+            }
             FilterValue::Format { space0, fmt } => {
                 self.fmt_span("filter-type", "format");
                 self.fmt_space(space0);
@@ -811,21 +910,68 @@ impl HtmlFormatter {
             }
             FilterValue::HtmlEscape => self.fmt_span("filter-type", "htmlEscape"),
             FilterValue::HtmlUnescape => self.fmt_span("filter-type", "htmlUnescape"),
-            FilterValue::JsonPath { space0, expr } => {
+            FilterValue::JsonEscape => self.fmt_span("filter-type", "jsonEscape"),
+            FilterValue::JsonQuote => self.fmt_span("filter-type", "jsonQuote"),
+            FilterValue::JsonUnescape => self.fmt_span("filter-type", "jsonUnescape"),
+            FilterValue::JsonPath {
+                space0,
+                expr,
+                expected_type,
+            } => {
                 self.fmt_span("filter-type", "jsonpath");
                 self.fmt_space(space0);
                 self.fmt_template(expr);
+                if let Some((space1, space2, name)) = expected_type {
+                    self.fmt_space(space1);
+                    self.fmt_span("filter-type", "as");
+                    self.fmt_space(space2);
+                    self.fmt_span("filter-type", name);
+                }
             }
+            FilterValue::JsonPathPointers { space0, expr } => {
+                self.fmt_span("filter-type", "jsonpathPointers");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+            }
+            FilterValue::JsonMerge { space0, other } => {
+                self.fmt_span("filter-type", "jsonMerge");
+                self.fmt_space(space0);
+                self.fmt_json_value(other);
+            }
+            FilterValue::Ndjson => self.fmt_span("filter-type", "ndjson"),
+            FilterValue::RetryAfterSeconds => self.fmt_span("filter-type", "retryAfterSeconds"),
+            FilterValue::NormalizeWhitespace => self.fmt_span("filter-type", "normalizeWhitespace"),
             FilterValue::Nth { space0, n: value } => {
                 self.fmt_span("filter-type", "nth");
                 self.fmt_space(space0);
                 self.fmt_number(value);
             }
+            FilterValue::Optional => self.fmt_span("filter-type", "?"),
+            FilterValue::ParseCsv { space0, has_header } => {
+                self.fmt_span("filter-type", "parseCsv");
+                self.fmt_space(space0);
+                self.fmt_bool(*has_header);
+            }
+            FilterValue::PercentOf { space0, total } => {
+                self.fmt_span("filter-type", "percentOf");
+                self.fmt_space(space0);
+                self.fmt_number_value(total);
+            }
             FilterValue::Regex { space0, value } => {
                 self.fmt_span("filter-type", "regex");
                 self.fmt_space(space0);
                 self.fmt_regex_value(value);
             }
+            FilterValue::RegexAll { space0, value } => {
+                self.fmt_span("filter-type", "regexAll");
+                self.fmt_space(space0);
+                self.fmt_regex_value(value);
+            }
+            FilterValue::Repeat { space0, count } => {
+                self.fmt_span("filter-type", "repeat");
+                self.fmt_space(space0);
+                self.fmt_number(count);
+            }
             FilterValue::Replace {
                 space0,
                 old_value,
@@ -838,11 +984,49 @@ impl HtmlFormatter {
                 self.fmt_space(space1);
                 self.fmt_template(new_value);
             }
+            FilterValue::ReplaceLiteral {
+                space0,
+                old_value,
+                space1,
+                new_value,
+            } => {
+                self.fmt_span("filter-type", "replaceLiteral");
+                self.fmt_space(space0);
+                self.fmt_template(old_value);
+                self.fmt_space(space1);
+                self.fmt_template(new_value);
+            }
+            FilterValue::Join { space0, sep } => {
+                self.fmt_span("filter-type", "join");
+                self.fmt_space(space0);
+                self.fmt_template(sep);
+            }
             FilterValue::Split { space0, sep } => {
                 self.fmt_span("filter-type", "split");
                 self.fmt_space(space0);
                 self.fmt_template(sep);
             }
+            FilterValue::StripPrefix { space0, prefix } => {
+                self.fmt_span("filter-type", "stripPrefix");
+                self.fmt_space(space0);
+                self.fmt_template(prefix);
+            }
+            FilterValue::StripSuffix { space0, suffix } => {
+                self.fmt_span("filter-type", "stripSuffix");
+                self.fmt_space(space0);
+                self.fmt_template(suffix);
+            }
+            FilterValue::TitleCase => self.fmt_span("filter-type", "titleCase"),
+            FilterValue::CamelCase => self.fmt_span("filter-type", "camelCase"),
+            FilterValue::Base64UrlSafeDecode => {
+                self.fmt_span("filter-type", "base64UrlSafeDecode");
+            }
+            FilterValue::Base64UrlSafeEncode => {
+                self.fmt_span("filter-type", "base64UrlSafeEncode");
+            }
+            FilterValue::KebabCase => self.fmt_span("filter-type", "kebabCase"),
+            FilterValue::SnakeCase => self.fmt_span("filter-type", "snakeCase"),
+            FilterValue::ToBool => self.fmt_span("filter-type", "toBool"),
             FilterValue::ToDate { space0, fmt } => {
                 self.fmt_span("filter-type", "toDate");
                 self.fmt_space(space0);
@@ -850,12 +1034,62 @@ impl HtmlFormatter {
             }
             FilterValue::ToFloat => self.fmt_span("filter-type", "toFloat"),
             FilterValue::ToInt => self.fmt_span("filter-type", "toInt"),
-            FilterValue::UrlDecode => self.fmt_span("filter-type", "urlDecode"),
-            FilterValue::UrlEncode => self.fmt_span("filter-type", "urlEncode"),
-            FilterValue::XPath { space0, expr } => {
+            FilterValue::Type => self.fmt_span("filter-type", "type"),
+            FilterValue::UrlDecode { mode } => {
+                self.fmt_span("filter-type", "urlDecode");
+                if let Some((space0, mode)) = mode {
+                    self.fmt_space(space0);
+                    self.fmt_span("filter-type", mode);
+                }
+            }
+            FilterValue::UrlEncode { mode } => {
+                self.fmt_span("filter-type", "urlEncode");
+                if let Some((space0, mode)) = mode {
+                    self.fmt_space(space0);
+                    self.fmt_span("filter-type", mode);
+                }
+            }
+            FilterValue::UrlHost => self.fmt_span("filter-type", "urlHost"),
+            FilterValue::UrlPath => self.fmt_span("filter-type", "urlPath"),
+            FilterValue::UrlQuery => self.fmt_span("filter-type", "urlQuery"),
+            FilterValue::UrlScheme => self.fmt_span("filter-type", "urlScheme"),
+            FilterValue::XmlToJson => self.fmt_span("filter-type", "xmlToJson"),
+            FilterValue::XPath {
+                space0,
+                expr,
+                namespaces,
+            } => {
                 self.fmt_span("filter-type", "xpath");
                 self.fmt_space(space0);
                 self.fmt_template(expr);
+                if let Some((space1, space2, namespaces)) = namespaces {
+                    self.fmt_space(space1);
+                    self.fmt_span("filter-type", "namespaces");
+                    self.fmt_space(space2);
+                    self.fmt_json_value(namespaces);
+                }
+            }
+            FilterValue::XPathAttr {
+                space0,
+                expr,
+                space1,
+                attr,
+            } => {
+                self.fmt_span("filter-type", "xpathAttr");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+                self.fmt_space(space1);
+                self.fmt_template(attr);
+            }
+            FilterValue::XPathOuterHtml { space0, expr } => {
+                self.fmt_span("filter-type", "xpathOuterHtml");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+            }
+            FilterValue::XPathXml { space0, expr } => {
+                self.fmt_span("filter-type", "xpathXml");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
             }
         };
     }
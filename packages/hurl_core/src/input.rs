@@ -20,10 +20,11 @@ use std::io::{ErrorKind, Read};
 use std::path::{Path, PathBuf};
 use std::{fmt, fs, io};
 
-/// Represents the input of read operation: can be either a file or standard input.
+/// Represents the input of read operation: can be either a file, standard input or an in-memory
+/// content.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Input {
-    /// Kind of input: either sourced from a file source, or from standard input.
+    /// Kind of input: either sourced from a file source, from standard input or from memory.
     kind: InputKind,
 }
 
@@ -50,6 +51,17 @@ impl Input {
         Ok(Input { kind })
     }
 
+    /// Creates an input from an in-memory `content`, identified by a virtual `name`.
+    /// The name is used in place of a file path (in error messages, reports etc...) and doesn't
+    /// have to reference an existing file.
+    pub fn from_memory(name: &str, content: &str) -> Self {
+        let kind = InputKind::Memory {
+            name: name.to_string(),
+            content: content.to_string(),
+        };
+        Input { kind }
+    }
+
     pub fn kind(&self) -> &InputKind {
         &self.kind
     }
@@ -68,6 +80,9 @@ pub enum InputKind {
     /// Read from standard input. Input is read once and the stdin string is cached and can be read
     /// multiple times.
     Stdin(String),
+    /// Read from memory, for instance when Hurl content is run by an embedder. The `name` is
+    /// a virtual name for the `content`.
+    Memory { name: String, content: String },
 }
 
 impl fmt::Display for InputKind {
@@ -75,6 +90,7 @@ impl fmt::Display for InputKind {
         let output = match self {
             InputKind::File(file) => file.to_string_lossy().to_string(),
             InputKind::Stdin(_) => "-".to_string(),
+            InputKind::Memory { name, .. } => name.clone(),
         };
         write!(f, "{output}")
     }
@@ -106,6 +122,7 @@ impl InputKind {
                 string_from_utf8(buffer)
             }
             InputKind::Stdin(cached) => Ok(cached.clone()),
+            InputKind::Memory { content, .. } => Ok(content.clone()),
         }
     }
 }
@@ -161,4 +178,14 @@ pub mod tests {
             "incomplete utf-8 byte sequence from index 0"
         );
     }
+
+    #[test]
+    fn test_memory_input() {
+        let input = Input::from_memory("suite/login", "GET http://localhost:8000\n");
+        assert_eq!(input.to_string(), "suite/login");
+        assert_eq!(
+            input.read_to_string().unwrap(),
+            "GET http://localhost:8000\n"
+        );
+    }
 }
@@ -27,7 +27,7 @@ pub fn parse(reader: &mut Reader) -> ParseResult<Expr> {
     let start = reader.cursor().pos;
     let save_state = reader.cursor();
     let kind = match function::parse(reader) {
-        Ok(function) => ExprKind::Function(function),
+        Ok(function) => ExprKind::Function(Box::new(function)),
         Err(e) => {
             if e.recoverable {
                 reader.seek(save_state);
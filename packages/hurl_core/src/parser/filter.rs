@@ -15,10 +15,12 @@
  * limitations under the License.
  *
  */
-use crate::ast::{Filter, FilterValue, SourceInfo, Whitespace};
+use crate::ast::{Filter, FilterValue, JsonValue, NumberValue, SourceInfo, Whitespace};
 use crate::combinator::{choice, ParseError as ParseErrorTrait};
-use crate::parser::number::natural;
-use crate::parser::primitives::{one_or_more_spaces, try_literal, zero_or_more_spaces};
+use crate::parser::json;
+use crate::parser::number::{integer, natural, number};
+use crate::parser::placeholder;
+use crate::parser::primitives::{boolean, one_or_more_spaces, try_literal, zero_or_more_spaces};
 use crate::parser::query::regex_value;
 use crate::parser::string::quoted_template;
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
@@ -53,23 +55,60 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     let start = reader.cursor();
     let value = choice(
         &[
+            base64_url_safe_decode_filter,
+            base64_url_safe_encode_filter,
+            bytes_length_filter,
+            camel_case_filter,
+            clamp_filter,
+            count_matches_filter,
             count_filter,
             days_after_now_filter,
             days_before_now_filter,
             decode_filter,
+            diff_filter,
             format_filter,
             html_decode_filter,
             html_encode_filter,
+            join_filter,
+            json_escape_filter,
+            json_quote_filter,
+            json_unescape_filter,
+            jsonpath_pointers_filter,
             jsonpath_filter,
+            json_merge_filter,
+            kebab_case_filter,
+            ndjson_filter,
+            normalize_whitespace_filter,
             nth_filter,
+            optional_filter,
+            parse_csv_filter,
+            percent_of_filter,
+            regex_all_filter,
             regex_filter,
+            repeat_filter,
+            replace_literal_filter,
             replace_filter,
+            retry_after_seconds_filter,
+            snake_case_filter,
             split_filter,
+            strip_prefix_filter,
+            strip_suffix_filter,
+            title_case_filter,
+            to_bool_filter,
             to_float_filter,
             to_int_filter,
             to_date_filter,
+            type_filter,
             url_decode_filter,
             url_encode_filter,
+            url_host_filter,
+            url_path_filter,
+            url_query_filter,
+            url_scheme_filter,
+            xml_to_json_filter,
+            xpath_attr_filter,
+            xpath_outer_html_filter,
+            xpath_xml_filter,
             xpath_filter,
         ],
         reader,
@@ -92,11 +131,37 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     Ok(Filter { source_info, value })
 }
 
+fn bytes_length_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("bytesLength", reader)?;
+    Ok(FilterValue::BytesLength)
+}
+
+fn clamp_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("clamp", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let min = number(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader)?;
+    let max = number(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Clamp {
+        space0,
+        min,
+        space1,
+        max,
+    })
+}
+
 fn count_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("count", reader)?;
     Ok(FilterValue::Count)
 }
 
+fn count_matches_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("countMatches", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let pattern = regex_value(reader)?;
+    Ok(FilterValue::CountMatches { space0, pattern })
+}
+
 fn days_after_now_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("daysAfterNow", reader)?;
     Ok(FilterValue::DaysAfterNow)
@@ -114,6 +179,20 @@ fn decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Decode { space0, encoding })
 }
 
+fn diff_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("diff", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let start = reader.cursor();
+    let other = json::parse(reader).map_err(|e| e.to_non_recoverable())?;
+    if !matches!(other, JsonValue::Object { .. } | JsonValue::List { .. }) {
+        let kind = ParseErrorKind::Expecting {
+            value: "JSON object or list".to_string(),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    Ok(FilterValue::Diff { space0, other })
+}
+
 fn format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("format", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -131,11 +210,88 @@ fn html_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::HtmlUnescape)
 }
 
+fn json_escape_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonEscape", reader)?;
+    Ok(FilterValue::JsonEscape)
+}
+
+fn json_quote_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonQuote", reader)?;
+    Ok(FilterValue::JsonQuote)
+}
+
+fn json_unescape_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonUnescape", reader)?;
+    Ok(FilterValue::JsonUnescape)
+}
+
 fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("jsonpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
     let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
-    Ok(FilterValue::JsonPath { space0, expr })
+    let expected_type = jsonpath_expected_type(reader)?;
+    Ok(FilterValue::JsonPath {
+        space0,
+        expr,
+        expected_type,
+    })
+}
+
+/// Types that can be expected from a `jsonpath` filter.
+const JSONPATH_TYPES: [&str; 8] = [
+    "boolean", "float", "integer", "list", "null", "number", "object", "string",
+];
+
+/// Parses the optional expected type of a `jsonpath` filter (`jsonpath "$.id" as number`).
+fn jsonpath_expected_type(
+    reader: &mut Reader,
+) -> ParseResult<Option<(Whitespace, Whitespace, String)>> {
+    let save = reader.cursor();
+    let Ok(space0) = one_or_more_spaces(reader) else {
+        reader.seek(save);
+        return Ok(None);
+    };
+    if try_literal("as", reader).is_err() {
+        reader.seek(save);
+        return Ok(None);
+    }
+    let Ok(space1) = one_or_more_spaces(reader) else {
+        reader.seek(save);
+        return Ok(None);
+    };
+    let start = reader.cursor();
+    let name = reader.read_while(|c| c.is_ascii_alphabetic());
+    if !JSONPATH_TYPES.contains(&name.as_str()) {
+        let kind = ParseErrorKind::Expecting {
+            value: JSONPATH_TYPES.join(", "),
+        };
+        return Err(ParseError::new(start.pos, false, kind));
+    }
+    Ok(Some((space0, space1, name)))
+}
+
+fn jsonpath_pointers_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonpathPointers", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::JsonPathPointers { space0, expr })
+}
+
+fn json_merge_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonMerge", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let other = json::object_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::JsonMerge { space0, other })
+}
+
+fn ndjson_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("ndjson", reader)?;
+    Ok(FilterValue::Ndjson)
+}
+
+fn normalize_whitespace_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("normalizeWhitespace", reader)?;
+    Ok(FilterValue::NormalizeWhitespace)
 }
 
 fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
@@ -145,6 +301,18 @@ fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Nth { space0, n })
 }
 
+fn optional_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("?", reader)?;
+    Ok(FilterValue::Optional)
+}
+
+fn parse_csv_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("parseCsv", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let has_header = boolean(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::ParseCsv { space0, has_header })
+}
+
 fn regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("regex", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -152,6 +320,50 @@ fn regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Regex { space0, value })
 }
 
+fn percent_of_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("percentOf", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let total = number_value(reader)?;
+    Ok(FilterValue::PercentOf { space0, total })
+}
+
+/// Parses a number, given as a literal or as a placeholder.
+fn number_value(reader: &mut Reader) -> ParseResult<NumberValue> {
+    let start = reader.cursor();
+    match number(reader) {
+        Ok(value) => Ok(NumberValue::Literal(value)),
+        Err(_) => {
+            reader.seek(start);
+            let placeholder = placeholder::parse(reader).map_err(|e| {
+                let kind = ParseErrorKind::Expecting {
+                    value: "number".to_string(),
+                };
+                ParseError::new(e.pos, false, kind)
+            })?;
+            Ok(NumberValue::Placeholder(placeholder))
+        }
+    }
+}
+
+fn regex_all_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("regexAll", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = regex_value(reader)?;
+    Ok(FilterValue::RegexAll { space0, value })
+}
+
+fn repeat_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("repeat", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let count = integer(reader)?;
+    Ok(FilterValue::Repeat { space0, count })
+}
+
+fn retry_after_seconds_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("retryAfterSeconds", reader)?;
+    Ok(FilterValue::RetryAfterSeconds)
+}
+
 fn replace_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("replace", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -166,6 +378,27 @@ fn replace_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     })
 }
 
+fn replace_literal_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("replaceLiteral", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let old_value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader)?;
+    let new_value = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::ReplaceLiteral {
+        space0,
+        old_value,
+        space1,
+        new_value,
+    })
+}
+
+fn join_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("join", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let sep = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::Join { space0, sep })
+}
+
 fn split_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("split", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -173,6 +406,55 @@ fn split_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Split { space0, sep })
 }
 
+fn strip_prefix_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("stripPrefix", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let prefix = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::StripPrefix { space0, prefix })
+}
+
+fn strip_suffix_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("stripSuffix", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let suffix = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::StripSuffix { space0, suffix })
+}
+
+fn base64_url_safe_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64UrlSafeDecode", reader)?;
+    Ok(FilterValue::Base64UrlSafeDecode)
+}
+
+fn base64_url_safe_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64UrlSafeEncode", reader)?;
+    Ok(FilterValue::Base64UrlSafeEncode)
+}
+
+fn camel_case_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("camelCase", reader)?;
+    Ok(FilterValue::CamelCase)
+}
+
+fn kebab_case_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("kebabCase", reader)?;
+    Ok(FilterValue::KebabCase)
+}
+
+fn snake_case_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("snakeCase", reader)?;
+    Ok(FilterValue::SnakeCase)
+}
+
+fn title_case_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("titleCase", reader)?;
+    Ok(FilterValue::TitleCase)
+}
+
+fn to_bool_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("toBool", reader)?;
+    Ok(FilterValue::ToBool)
+}
+
 fn to_date_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("toDate", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -190,26 +472,145 @@ fn to_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::ToInt)
 }
 
+fn type_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("type", reader)?;
+    Ok(FilterValue::Type)
+}
+
 fn url_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("urlEncode", reader)?;
-    Ok(FilterValue::UrlEncode)
+    let mode = url_encoding_mode(reader);
+    Ok(FilterValue::UrlEncode { mode })
+}
+
+fn xml_to_json_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("xmlToJson", reader)?;
+    Ok(FilterValue::XmlToJson)
 }
 
 fn url_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("urlDecode", reader)?;
-    Ok(FilterValue::UrlDecode)
+    let mode = url_encoding_mode(reader);
+    Ok(FilterValue::UrlDecode { mode })
+}
+
+/// Modes of the `urlEncode` and `urlDecode` filters.
+const URL_ENCODING_MODES: [&str; 2] = ["component", "form"];
+
+/// Parses the optional mode of a `urlEncode` or `urlDecode` filter (`urlEncode form`).
+///
+/// Any other word is left unread, as it can be the next filter or a predicate.
+fn url_encoding_mode(reader: &mut Reader) -> Option<(Whitespace, String)> {
+    let save = reader.cursor();
+    let Ok(space0) = one_or_more_spaces(reader) else {
+        reader.seek(save);
+        return None;
+    };
+    let name = reader.read_while(|c| c.is_ascii_alphanumeric());
+    if !URL_ENCODING_MODES.contains(&name.as_str()) {
+        reader.seek(save);
+        return None;
+    }
+    Some((space0, name))
+}
+
+fn url_host_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("urlHost", reader)?;
+    Ok(FilterValue::UrlHost)
+}
+
+fn url_path_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("urlPath", reader)?;
+    Ok(FilterValue::UrlPath)
+}
+
+fn url_query_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("urlQuery", reader)?;
+    Ok(FilterValue::UrlQuery)
+}
+
+fn url_scheme_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("urlScheme", reader)?;
+    Ok(FilterValue::UrlScheme)
 }
 
 fn xpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("xpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
     let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
-    Ok(FilterValue::XPath { space0, expr })
+    let namespaces = xpath_namespaces(reader)?;
+    Ok(FilterValue::XPath {
+        space0,
+        expr,
+        namespaces,
+    })
+}
+
+/// Parses the optional namespace bindings of a `xpath` filter
+/// (`xpath "//atom:entry" namespaces {"atom": "http://www.w3.org/2005/Atom"}`).
+///
+/// Each binding maps a prefix to a namespace URI, given as a string.
+fn xpath_namespaces(
+    reader: &mut Reader,
+) -> ParseResult<Option<(Whitespace, Whitespace, JsonValue)>> {
+    let save = reader.cursor();
+    let Ok(space0) = one_or_more_spaces(reader) else {
+        reader.seek(save);
+        return Ok(None);
+    };
+    if try_literal("namespaces", reader).is_err() {
+        reader.seek(save);
+        return Ok(None);
+    }
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let start = reader.cursor();
+    let namespaces = json::object_value(reader).map_err(|e| e.to_non_recoverable())?;
+    if let JsonValue::Object { elements, .. } = &namespaces {
+        if elements
+            .iter()
+            .any(|e| !matches!(e.value, JsonValue::String(_)))
+        {
+            let kind = ParseErrorKind::Expecting {
+                value: "namespace URI string".to_string(),
+            };
+            return Err(ParseError::new(start.pos, false, kind));
+        }
+    }
+    Ok(Some((space0, space1, namespaces)))
+}
+
+fn xpath_attr_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("xpathAttr", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader)?;
+    let attr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::XPathAttr {
+        space0,
+        expr,
+        space1,
+        attr,
+    })
+}
+
+fn xpath_outer_html_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("xpathOuterHtml", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::XPathOuterHtml { space0, expr })
+}
+
+fn xpath_xml_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("xpathXml", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(FilterValue::XPathXml { space0, expr })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ast::{Number, RegexValue};
     use crate::parser::ParseErrorKind;
     use crate::reader::Pos;
 
@@ -225,6 +626,257 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_count_matches() {
+        let mut reader = Reader::new("countMatches \"err\"");
+        let filter = filter(&mut reader).unwrap();
+        assert_eq!(
+            filter.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 19))
+        );
+        let FilterValue::CountMatches {
+            pattern: RegexValue::Template(pattern),
+            ..
+        } = filter.value
+        else {
+            panic!("countMatches filter expected");
+        };
+        assert_eq!(pattern.to_string(), "err");
+    }
+
+    #[test]
+    fn test_regex_all() {
+        let mut reader = Reader::new("regexAll /(\\d+)/");
+        let parsed = filter(&mut reader).unwrap();
+        assert_eq!(
+            parsed.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 17))
+        );
+        assert!(matches!(
+            parsed.value,
+            FilterValue::RegexAll {
+                value: RegexValue::Regex(_),
+                ..
+            }
+        ));
+
+        let mut reader = Reader::new("regex /(\\d+)/");
+        let parsed = filter(&mut reader).unwrap();
+        assert!(matches!(parsed.value, FilterValue::Regex { .. }));
+    }
+
+    #[test]
+    fn test_replace_literal() {
+        let mut reader = Reader::new("replaceLiteral \"a.b\" \"$1\"");
+        let parsed = filter(&mut reader).unwrap();
+        assert_eq!(
+            parsed.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 26))
+        );
+        let FilterValue::ReplaceLiteral { old_value, .. } = parsed.value else {
+            panic!("replaceLiteral filter expected");
+        };
+        assert_eq!(old_value.to_string(), "a.b");
+
+        let mut reader = Reader::new("replaceLiteral /a.b/ \"-\"");
+        assert!(filter(&mut reader).is_err());
+
+        let mut reader = Reader::new("replace \".\" \"-\"");
+        let parsed = filter(&mut reader).unwrap();
+        assert!(matches!(parsed.value, FilterValue::Replace { .. }));
+    }
+
+    #[test]
+    fn test_url_encoding_mode() {
+        let mut reader = Reader::new("urlEncode form");
+        let parsed = filter(&mut reader).unwrap();
+        let FilterValue::UrlEncode {
+            mode: Some((_, mode)),
+        } = parsed.value
+        else {
+            panic!("urlEncode filter with mode expected");
+        };
+        assert_eq!(mode, "form");
+        assert_eq!(reader.cursor().pos, Pos::new(1, 15));
+
+        let mut reader = Reader::new("urlDecode component");
+        let parsed = filter(&mut reader).unwrap();
+        assert!(matches!(
+            parsed.value,
+            FilterValue::UrlDecode { mode: Some(_) }
+        ));
+
+        // The next word is another filter.
+        let mut reader = Reader::new("urlDecode format \"%Y\"");
+        let parsed = filter(&mut reader).unwrap();
+        assert_eq!(parsed.value, FilterValue::UrlDecode { mode: None });
+        assert_eq!(reader.cursor().pos, Pos::new(1, 10));
+    }
+
+    #[test]
+    fn test_percent_of() {
+        let mut reader = Reader::new("percentOf 200");
+        let parsed = filter(&mut reader).unwrap();
+        assert_eq!(
+            parsed.value,
+            FilterValue::PercentOf {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                total: NumberValue::Literal(Number::Integer(200)),
+            }
+        );
+
+        let mut reader = Reader::new("percentOf {{total}}");
+        let parsed = filter(&mut reader).unwrap();
+        assert!(matches!(
+            parsed.value,
+            FilterValue::PercentOf {
+                total: NumberValue::Placeholder(_),
+                ..
+            }
+        ));
+
+        let mut reader = Reader::new("percentOf total");
+        let error = filter(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 11));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "number".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_jsonpath_expected_type() {
+        let mut reader = Reader::new("jsonpath \"$.id\" as number");
+        let parsed = filter(&mut reader).unwrap();
+        assert_eq!(
+            parsed.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 26))
+        );
+        let FilterValue::JsonPath { expected_type, .. } = parsed.value else {
+            panic!("jsonpath filter expected");
+        };
+        assert_eq!(expected_type.unwrap().2, "number");
+
+        // Without expected type, the following spaces are not consumed.
+        let mut reader = Reader::new("jsonpath \"$.id\" == 1");
+        let parsed = filter(&mut reader).unwrap();
+        let FilterValue::JsonPath { expected_type, .. } = parsed.value else {
+            panic!("jsonpath filter expected");
+        };
+        assert_eq!(expected_type, None);
+        assert_eq!(reader.cursor().index, 15);
+
+        let mut reader = Reader::new("jsonpath \"$.id\" as uuid");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(err.pos, Pos::new(1, 20));
+        assert!(!err.recoverable);
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Expecting {
+                value: "boolean, float, integer, list, null, number, object, string".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_xpath_namespaces() {
+        let mut reader = Reader::new(
+            "xpath \"//atom:entry\" namespaces {\"atom\": \"http://www.w3.org/2005/Atom\"}",
+        );
+        let parsed = filter(&mut reader).unwrap();
+        assert_eq!(
+            parsed.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 72))
+        );
+        let FilterValue::XPath { namespaces, .. } = parsed.value else {
+            panic!("xpath filter expected");
+        };
+        assert_eq!(
+            namespaces.unwrap().2.to_string(),
+            "{\"atom\": \"http://www.w3.org/2005/Atom\"}"
+        );
+
+        // Without namespaces, the following spaces are not consumed.
+        let mut reader = Reader::new("xpath \"//entry\" count");
+        let parsed = filter(&mut reader).unwrap();
+        let FilterValue::XPath { namespaces, .. } = parsed.value else {
+            panic!("xpath filter expected");
+        };
+        assert_eq!(namespaces, None);
+        assert_eq!(reader.cursor().index, 15);
+
+        let mut reader = Reader::new("xpath \"//atom:entry\" namespaces {\"atom\": 1}");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(err.pos, Pos::new(1, 33));
+        assert!(!err.recoverable);
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::Expecting {
+                value: "namespace URI string".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut reader = Reader::new("diff {\"a\": 2, \"b\": 3}");
+        let parsed = filter(&mut reader).unwrap();
+        assert_eq!(
+            parsed.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 22))
+        );
+        let FilterValue::Diff { other, .. } = parsed.value else {
+            panic!("diff filter expected");
+        };
+        assert_eq!(other.to_string(), "{\"a\": 2, \"b\": 3}");
+
+        let mut reader = Reader::new("diff 1");
+        let error = filter(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 6 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::Expecting {
+                value: "JSON object or list".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_xpath_xml() {
+        let mut reader = Reader::new("xpathXml \"//soap:Body\"");
+        let filter = filter(&mut reader).unwrap();
+        assert_eq!(
+            filter.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 23))
+        );
+        let FilterValue::XPathXml { expr, .. } = filter.value else {
+            panic!("xpathXml filter expected");
+        };
+        assert_eq!(expr.to_string(), "//soap:Body");
+    }
+
+    #[test]
+    fn test_xpath_attr() {
+        let mut reader = Reader::new("xpathAttr \"//a\" \"href\"");
+        let filter = filter(&mut reader).unwrap();
+        assert_eq!(
+            filter.source_info,
+            SourceInfo::new(Pos::new(1, 1), Pos::new(1, 23))
+        );
+        let FilterValue::XPathAttr { expr, attr, .. } = filter.value else {
+            panic!("xpathAttr filter expected");
+        };
+        assert_eq!(expr.to_string(), "//a");
+        assert_eq!(attr.to_string(), "href");
+    }
+
     #[test]
     fn test_error() {
         let mut reader = Reader::new("xcount");
@@ -16,6 +16,9 @@
  *
  */
 use crate::ast::Function;
+use crate::combinator::ParseError as ParseErrorTrait;
+use crate::parser::number::integer;
+use crate::parser::primitives::one_or_more_spaces;
 use crate::parser::{ParseError, ParseErrorKind, ParseResult};
 use crate::reader::Reader;
 
@@ -26,8 +29,10 @@ pub fn parse(reader: &mut Reader) -> ParseResult<Function> {
     let start = reader.cursor();
     let function_name = reader.read_while(|c| c.is_alphanumeric() || c == '_' || c == '-');
     match function_name.as_str() {
+        "getEnv" => get_env(reader),
         "newDate" => Ok(Function::NewDate),
         "newUuid" => Ok(Function::NewUuid),
+        "randomInt" => random_int(reader),
         _ => Err(ParseError::new(
             start.pos,
             true,
@@ -38,8 +43,72 @@ pub fn parse(reader: &mut Reader) -> ParseResult<Function> {
     }
 }
 
+/// Parses the arguments of the `getEnv` function: the name of an environment variable, followed by
+/// an optional default value (`getEnv HOST localhost`).
+fn get_env(reader: &mut Reader) -> ParseResult<Function> {
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let start = reader.cursor();
+    let name = reader.read_while(|c| c.is_ascii_alphanumeric() || c == '_');
+    if name.is_empty() {
+        return Err(ParseError::new(
+            start.pos,
+            false,
+            ParseErrorKind::Expecting {
+                value: "environment variable name".to_string(),
+            },
+        ));
+    }
+    let save = reader.cursor();
+    let default = match one_or_more_spaces(reader) {
+        Ok(space1) => {
+            let default = reader.read_while(|c| !c.is_whitespace());
+            if default.is_empty() {
+                reader.seek(save);
+                None
+            } else {
+                Some((space1, default))
+            }
+        }
+        Err(_) => {
+            reader.seek(save);
+            None
+        }
+    };
+    Ok(Function::GetEnv {
+        space0,
+        name,
+        default,
+    })
+}
+
+/// Parses the arguments of the `randomInt` function: the inclusive bounds of the generated integer
+/// (`randomInt 1 100`).
+fn random_int(reader: &mut Reader) -> ParseResult<Function> {
+    let space0 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let min = integer(reader).map_err(|e| e.to_non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.to_non_recoverable())?;
+    let start = reader.cursor();
+    let max = integer(reader).map_err(|e| e.to_non_recoverable())?;
+    if max < min {
+        return Err(ParseError::new(
+            start.pos,
+            false,
+            ParseErrorKind::Expecting {
+                value: format!("integer greater than or equal to {min}"),
+            },
+        ));
+    }
+    Ok(Function::RandomInt {
+        space0,
+        min,
+        space1,
+        max,
+    })
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::ast::{SourceInfo, Whitespace};
     use crate::reader::Pos;
 
     use super::*;
@@ -57,4 +126,70 @@ mod tests {
         assert_eq!(err.pos, Pos::new(1, 1));
         assert_eq!(err.recoverable, true);
     }
+
+    #[test]
+    fn test_get_env() {
+        let mut reader = Reader::new("getEnv HOME");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            Function::GetEnv {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                name: "HOME".to_string(),
+                default: None,
+            }
+        );
+
+        let mut reader = Reader::new("getEnv API_HOST localhost:8000 ");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            Function::GetEnv {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 7), Pos::new(1, 8)),
+                },
+                name: "API_HOST".to_string(),
+                default: Some((
+                    Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(Pos::new(1, 16), Pos::new(1, 17)),
+                    },
+                    "localhost:8000".to_string()
+                )),
+            }
+        );
+        assert_eq!(reader.cursor().pos, Pos::new(1, 31));
+
+        let mut reader = Reader::new("getEnv");
+        let err = parse(&mut reader).unwrap_err();
+        assert_eq!(err.pos, Pos::new(1, 7));
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_random_int() {
+        let mut reader = Reader::new("randomInt -10 10");
+        assert_eq!(
+            parse(&mut reader).unwrap(),
+            Function::RandomInt {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                min: -10,
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                },
+                max: 10,
+            }
+        );
+
+        let mut reader = Reader::new("randomInt 10 1");
+        let err = parse(&mut reader).unwrap_err();
+        assert_eq!(err.pos, Pos::new(1, 14));
+        assert!(!err.recoverable);
+    }
 }
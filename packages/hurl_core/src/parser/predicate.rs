@@ -16,9 +16,11 @@
  *
  */
 use crate::ast::{
-    Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo, Whitespace,
+    Number, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, SourceInfo, Whitespace,
 };
-use crate::combinator::choice;
+use crate::combinator::{choice, ParseError as ParseErrorTrait};
+use crate::parser::json;
+use crate::parser::number::natural;
 use crate::parser::predicate_value::predicate_value;
 use crate::parser::primitives::{
     one_or_more_spaces, try_literal, try_literals, zero_or_more_spaces,
@@ -81,9 +83,16 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             less_predicate,
             start_with_predicate,
             end_with_predicate,
+            contains_subset_predicate,
             contain_predicate,
             include_predicate,
+            match_glob_predicate,
+            matches_all_predicate,
             match_predicate,
+            any_line_match_predicate,
+            has_length_predicate,
+            is_in_range_predicate,
+            is_close_to_predicate,
             integer_predicate,
             float_predicate,
             boolean_predicate,
@@ -94,6 +103,11 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             exist_predicate,
             is_empty_predicate,
             is_number_predicate,
+            is_null_predicate,
+            is_not_null_predicate,
+            is_true_predicate,
+            is_false_predicate,
+            is_cookie_secure_compliant_predicate,
         ],
         reader,
     ) {
@@ -310,6 +324,13 @@ fn contain_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Contain { space0, value })
 }
 
+fn contains_subset_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("containsSubset", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let object = json::object_value(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(PredicateFuncValue::ContainsSubset { space0, object })
+}
+
 fn include_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("includes", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -332,6 +353,137 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn any_line_match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("anyLineMatches", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(value, PredicateValue::String(_)) && !matches!(value, PredicateValue::Regex(_)) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::AnyLineMatch { space0, value })
+}
+
+fn matches_all_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("matchesAll", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let mut patterns = vec![(space0, regex_pattern(reader)?)];
+    loop {
+        let save = reader.cursor();
+        let Ok(space) = one_or_more_spaces(reader) else {
+            reader.seek(save);
+            break;
+        };
+        // Patterns are listed until the end of the predicate (a comment or a newline).
+        if !matches!(reader.peek(), Some('"') | Some('/') | Some('`')) {
+            reader.seek(save);
+            break;
+        }
+        patterns.push((space, regex_pattern(reader)?));
+    }
+    Ok(PredicateFuncValue::MatchesAll { patterns })
+}
+
+/// Parses a predicate value that is a regex pattern, either a string or a regex literal.
+fn regex_pattern(reader: &mut Reader) -> ParseResult<PredicateValue> {
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(value, PredicateValue::String(_)) && !matches!(value, PredicateValue::Regex(_)) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(value)
+}
+
+fn match_glob_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("matchesGlob", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(value, PredicateValue::String(_)) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::MatchGlob { space0, value })
+}
+
+fn has_length_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("hasLength", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let n = natural(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(PredicateFuncValue::HasLength { space0, n })
+}
+
+fn is_in_range_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isInRange", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let min = numeric_value(reader)?;
+    let space1 = one_or_more_spaces(reader)?;
+    let max = numeric_value(reader)?;
+    Ok(PredicateFuncValue::IsInRange {
+        space0,
+        min,
+        space1,
+        max,
+    })
+}
+
+fn is_close_to_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isCloseTo", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let value = numeric_value(reader)?;
+    let space1 = one_or_more_spaces(reader)?;
+    let save = reader.cursor();
+    let epsilon = numeric_value(reader)?;
+    // The tolerance can't be negative.
+    let negative = match &epsilon {
+        PredicateValue::Number(Number::Float(f)) => f.value < 0.0,
+        PredicateValue::Number(Number::Integer(i)) => *i < 0,
+        PredicateValue::Number(Number::BigInteger(s)) => s.starts_with('-'),
+        _ => false,
+    };
+    if negative {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(PredicateFuncValue::IsCloseTo {
+        space0,
+        value,
+        space1,
+        epsilon,
+    })
+}
+
+/// Parses a numeric predicate value, either a number or a placeholder.
+fn numeric_value(reader: &mut Reader) -> ParseResult<PredicateValue> {
+    let save = reader.cursor();
+    let value = predicate_value(reader)?;
+    if !matches!(
+        value,
+        PredicateValue::Number(_) | PredicateValue::Placeholder(_)
+    ) {
+        return Err(ParseError::new(
+            save.pos,
+            false,
+            ParseErrorKind::PredicateValue,
+        ));
+    }
+    Ok(value)
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger)
@@ -382,11 +534,36 @@ fn is_number_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::IsNumber)
 }
 
+fn is_null_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isNull", reader)?;
+    Ok(PredicateFuncValue::IsNull)
+}
+
+fn is_not_null_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isNotNull", reader)?;
+    Ok(PredicateFuncValue::IsNotNull)
+}
+
+fn is_true_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isTrue", reader)?;
+    Ok(PredicateFuncValue::IsTrue)
+}
+
+fn is_false_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isFalse", reader)?;
+    Ok(PredicateFuncValue::IsFalse)
+}
+
+fn is_cookie_secure_compliant_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isCookieSecureCompliant", reader)?;
+    Ok(PredicateFuncValue::IsCookieSecureCompliant)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ast::{
-        Expr, ExprKind, Float, Number, Placeholder, Template, TemplateElement, Variable,
+        Expr, ExprKind, Float, JsonValue, Number, Placeholder, Template, TemplateElement, Variable,
     };
     use crate::reader::Pos;
 
@@ -445,6 +622,204 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_null_predicate() {
+        let mut reader = Reader::new("isNull");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsNull
+        );
+
+        let mut reader = Reader::new("isNotNull");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsNotNull
+        );
+        assert_eq!(reader.cursor().pos, Pos::new(1, 10));
+    }
+
+    #[test]
+    fn test_match_glob_predicate() {
+        let mut reader = Reader::new("matchesGlob \"/api/*/users\"");
+        let value = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(value, PredicateFuncValue::MatchGlob { .. }));
+        assert_eq!(reader.cursor().pos, Pos::new(1, 27));
+
+        let mut reader = Reader::new("matchesGlob /api/");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 13));
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_any_line_match_predicate() {
+        let mut reader = Reader::new("anyLineMatches /^ERROR/");
+        let value = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(value, PredicateFuncValue::AnyLineMatch { .. }));
+        assert_eq!(reader.cursor().pos, Pos::new(1, 24));
+
+        let mut reader = Reader::new("anyLineMatches 12");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 16));
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_matches_all_predicate() {
+        let mut reader = Reader::new("matchesAll /\\d/ \"[a-z]\" /^.{8,}$/ # comment");
+        let value = predicate_func_value(&mut reader).unwrap();
+        let PredicateFuncValue::MatchesAll { patterns } = value else {
+            panic!("matchesAll predicate expected");
+        };
+        assert_eq!(patterns.len(), 3);
+        assert!(matches!(patterns[0].1, PredicateValue::Regex(_)));
+        assert!(matches!(patterns[1].1, PredicateValue::String(_)));
+        assert!(matches!(patterns[2].1, PredicateValue::Regex(_)));
+        assert_eq!(reader.cursor().pos, Pos::new(1, 34));
+
+        // `matches` is still parsed as a single pattern predicate
+        let mut reader = Reader::new("matches /a/");
+        let value = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(value, PredicateFuncValue::Match { .. }));
+
+        let mut reader = Reader::new("matchesAll /a/ 12");
+        let value = predicate_func_value(&mut reader).unwrap();
+        assert!(matches!(value, PredicateFuncValue::MatchesAll { .. }));
+        assert_eq!(reader.cursor().pos, Pos::new(1, 15));
+
+        let mut reader = Reader::new("matchesAll 12");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 12));
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_contains_subset_predicate() {
+        let mut reader = Reader::new("containsSubset {}");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::ContainsSubset {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 16)),
+                },
+                object: JsonValue::Object {
+                    space0: String::new(),
+                    elements: vec![],
+                },
+            }
+        );
+
+        let mut reader = Reader::new("containsSubset [1]");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 16));
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_has_length_predicate() {
+        let mut reader = Reader::new("hasLength 3");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::HasLength {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                n: 3,
+            }
+        );
+
+        let mut reader = Reader::new("hasLength -1");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 11));
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_is_in_range_predicate() {
+        let mut reader = Reader::new("isInRange 200 299");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsInRange {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                min: PredicateValue::Number(Number::Integer(200)),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                },
+                max: PredicateValue::Number(Number::Integer(299)),
+            }
+        );
+        assert_eq!(reader.cursor().index, 17);
+
+        let mut reader = Reader::new("isInRange 200 \"299\"");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 15));
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
+    #[test]
+    fn test_is_close_to_predicate() {
+        let mut reader = Reader::new("isCloseTo 3 0.001");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsCloseTo {
+                space0: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 10), Pos::new(1, 11)),
+                },
+                value: PredicateValue::Number(Number::Integer(3)),
+                space1: Whitespace {
+                    value: " ".to_string(),
+                    source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 13)),
+                },
+                epsilon: PredicateValue::Number(Number::Float(Float {
+                    value: 0.001,
+                    encoded: "0.001".to_string(),
+                })),
+            }
+        );
+        assert_eq!(reader.cursor().index, 17);
+
+        let mut reader = Reader::new("isCloseTo 3 -0.001");
+        let error = predicate_func_value(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos::new(1, 13));
+        assert!(!error.recoverable);
+        assert_eq!(error.kind, ParseErrorKind::PredicateValue);
+    }
+
+    #[test]
+    fn test_is_true_is_false_predicate() {
+        let mut reader = Reader::new("isTrue");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsTrue
+        );
+
+        let mut reader = Reader::new("isFalse");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsFalse
+        );
+    }
+
+    #[test]
+    fn test_is_cookie_secure_compliant_predicate() {
+        let mut reader = Reader::new("isCookieSecureCompliant");
+        assert_eq!(
+            predicate_func_value(&mut reader).unwrap(),
+            PredicateFuncValue::IsCookieSecureCompliant
+        );
+    }
+
     #[test]
     fn test_predicate_func() {
         let mut reader = Reader::new("tata == 1");
@@ -38,6 +38,7 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
         &[
             status_query,
             url_query,
+            headers_query,
             header_query,
             cookie_query,
             body_query,
@@ -47,6 +48,13 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
             variable_query,
             duration_query,
             bytes_query,
+            raw_bytes_query,
+            detected_content_type_query,
+            transfer_encoding_query,
+            raw_response_query,
+            request_body_query,
+            request_header_query,
+            query_param_query,
             sha256_query,
             md5_query,
             certificate_query,
@@ -72,6 +80,13 @@ fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Header { space0, name })
 }
 
+fn headers_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("headers", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::Headers { space0, name })
+}
+
 fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("cookie", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -169,6 +184,45 @@ fn bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Bytes)
 }
 
+fn raw_bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("rawBytes", reader)?;
+    Ok(QueryValue::RawBytes)
+}
+
+fn detected_content_type_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("detectedContentType", reader)?;
+    Ok(QueryValue::DetectedContentType)
+}
+
+fn transfer_encoding_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("transferEncoding", reader)?;
+    Ok(QueryValue::TransferEncoding)
+}
+
+fn raw_response_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("rawResponse", reader)?;
+    Ok(QueryValue::RawResponse)
+}
+
+fn request_body_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("requestBody", reader)?;
+    Ok(QueryValue::RequestBody)
+}
+
+fn request_header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("requestHeader", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::RequestHeader { space0, name })
+}
+
+fn query_param_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("queryParam", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.to_non_recoverable())?;
+    Ok(QueryValue::QueryParam { space0, name })
+}
+
 fn sha256_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("sha256", reader)?;
     Ok(QueryValue::Sha256)
@@ -244,6 +298,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_request_body_query() {
+        let mut reader = Reader::new("requestBody");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 12)),
+                value: QueryValue::RequestBody
+            }
+        );
+    }
+
+    #[test]
+    fn test_query_param_query() {
+        let mut reader = Reader::new("queryParam \"id\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 16)),
+                value: QueryValue::QueryParam {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 11), Pos::new(1, 12)),
+                    },
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "id".to_string(),
+                            encoded: "id".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 12), Pos::new(1, 16)),
+                    },
+                }
+            }
+        );
+
+        let mut reader = Reader::new("queryParam id");
+        let error = query(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 12
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_request_header_query() {
+        let mut reader = Reader::new("requestHeader \"User-Agent\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 27)),
+                value: QueryValue::RequestHeader {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 14), Pos::new(1, 15)),
+                    },
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "User-Agent".to_string(),
+                            encoded: "User-Agent".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 15), Pos::new(1, 27)),
+                    },
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn test_headers_query() {
+        let mut reader = Reader::new("headers \"Set-Cookie\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(Pos::new(1, 1), Pos::new(1, 21)),
+                value: QueryValue::Headers {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(Pos::new(1, 8), Pos::new(1, 9)),
+                    },
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "Set-Cookie".to_string(),
+                            encoded: "Set-Cookie".to_string(),
+                        }],
+                        source_info: SourceInfo::new(Pos::new(1, 9), Pos::new(1, 21)),
+                    },
+                }
+            }
+        );
+
+        // `header` query is still parsed.
+        let mut reader = Reader::new("header \"Set-Cookie\"");
+        assert!(matches!(
+            query(&mut reader).unwrap().value,
+            QueryValue::Header { .. }
+        ));
+    }
+
     #[test]
     fn test_header_query() {
         let mut reader = Reader::new("header \"Foo\"");
@@ -402,7 +561,7 @@ mod tests {
                 },
                 Filter {
                     source_info: SourceInfo::new(Pos::new(1, 6), Pos::new(1, 15)),
-                    value: FilterValue::UrlDecode,
+                    value: FilterValue::UrlDecode { mode: None },
                 }
             )]
         );
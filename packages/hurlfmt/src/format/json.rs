@@ -22,9 +22,9 @@ use hurl_core::ast::{
     Assert, Base64, Body, BooleanOption, Bytes, Capture, CertificateAttributeName, Comment, Cookie,
     CountOption, DurationOption, Entry, EntryOption, File, FileParam, Filter, FilterValue, Header,
     Hex, HurlFile, JsonListElement, JsonValue, KeyValue, MultilineString, MultilineStringKind,
-    MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate, PredicateFuncValue,
-    PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response, StatusValue,
-    VersionValue,
+    MultipartParam, NaturalOption, NumberValue, OptionKind, Placeholder, Predicate,
+    PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response,
+    StatusValue, VersionValue,
 };
 use hurl_core::typing::{Count, Duration};
 
@@ -467,6 +467,10 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             attributes.push(("type".to_string(), JValue::String("header".to_string())));
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
+        QueryValue::Headers { name, .. } => {
+            attributes.push(("type".to_string(), JValue::String("headers".to_string())));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
         QueryValue::Cookie { expr, .. } => {
             attributes.push(("type".to_string(), JValue::String("cookie".to_string())));
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
@@ -489,6 +493,44 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Bytes => {
             attributes.push(("type".to_string(), JValue::String("bytes".to_string())));
         }
+        QueryValue::RawBytes => {
+            attributes.push(("type".to_string(), JValue::String("rawBytes".to_string())));
+        }
+        QueryValue::DetectedContentType => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("detectedContentType".to_string()),
+            ));
+        }
+        QueryValue::TransferEncoding => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("transferEncoding".to_string()),
+            ));
+        }
+        QueryValue::RawResponse => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("rawResponse".to_string()),
+            ));
+        }
+        QueryValue::RequestBody => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("requestBody".to_string()),
+            ));
+        }
+        QueryValue::RequestHeader { name, .. } => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("requestHeader".to_string()),
+            ));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
+        QueryValue::QueryParam { name, .. } => {
+            attributes.push(("type".to_string(), JValue::String("queryParam".to_string())));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
         QueryValue::Sha256 => {
             attributes.push(("type".to_string(), JValue::String("sha256".to_string())));
         }
@@ -590,6 +632,13 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("contain".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::ContainsSubset { object, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("containsSubset".to_string()),
+                ));
+                attributes.push(("value".to_string(), object.to_json()));
+            }
             PredicateFuncValue::Include { value, .. } => {
                 attributes.push(("type".to_string(), JValue::String("include".to_string())));
                 add_predicate_value(&mut attributes, value);
@@ -598,6 +647,46 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("match".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::MatchGlob { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("matchGlob".to_string())));
+                add_predicate_value(&mut attributes, value);
+            }
+            PredicateFuncValue::AnyLineMatch { value, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("anyLineMatch".to_string()),
+                ));
+                add_predicate_value(&mut attributes, value);
+            }
+            PredicateFuncValue::MatchesAll { patterns } => {
+                attributes.push(("type".to_string(), JValue::String("matchesAll".to_string())));
+                let values = patterns
+                    .into_iter()
+                    .map(|(_, value)| {
+                        let mut attributes = vec![];
+                        add_predicate_value(&mut attributes, value);
+                        JValue::Object(attributes)
+                    })
+                    .collect();
+                attributes.push(("values".to_string(), JValue::List(values)));
+            }
+            PredicateFuncValue::HasLength { n, .. } => {
+                attributes.push(("type".to_string(), JValue::String("hasLength".to_string())));
+                attributes.push(("value".to_string(), JValue::Number(n.to_string())));
+            }
+            PredicateFuncValue::IsInRange { min, max, .. } => {
+                attributes.push(("type".to_string(), JValue::String("isInRange".to_string())));
+                let (min, _) = json_predicate_value(min);
+                let (max, _) = json_predicate_value(max);
+                attributes.push(("min".to_string(), min));
+                attributes.push(("max".to_string(), max));
+            }
+            PredicateFuncValue::IsCloseTo { value, epsilon, .. } => {
+                attributes.push(("type".to_string(), JValue::String("isCloseTo".to_string())));
+                add_predicate_value(&mut attributes, value);
+                let (epsilon, _) = json_predicate_value(epsilon);
+                attributes.push(("epsilon".to_string(), epsilon));
+            }
             PredicateFuncValue::IsInteger => {
                 attributes.push(("type".to_string(), JValue::String("isInteger".to_string())));
             }
@@ -631,6 +720,24 @@ impl ToJson for Predicate {
             PredicateFuncValue::IsNumber => {
                 attributes.push(("type".to_string(), JValue::String("isNumber".to_string())));
             }
+            PredicateFuncValue::IsNull => {
+                attributes.push(("type".to_string(), JValue::String("isNull".to_string())));
+            }
+            PredicateFuncValue::IsNotNull => {
+                attributes.push(("type".to_string(), JValue::String("isNotNull".to_string())));
+            }
+            PredicateFuncValue::IsTrue => {
+                attributes.push(("type".to_string(), JValue::String("isTrue".to_string())));
+            }
+            PredicateFuncValue::IsFalse => {
+                attributes.push(("type".to_string(), JValue::String("isFalse".to_string())));
+            }
+            PredicateFuncValue::IsCookieSecureCompliant => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("isCookieSecureCompliant".to_string()),
+                ));
+            }
         }
         JValue::Object(attributes)
     }
@@ -704,9 +811,27 @@ impl ToJson for FilterValue {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
         match self {
+            FilterValue::BytesLength => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("bytesLength".to_string()),
+                ));
+            }
+            FilterValue::Clamp { min, max, .. } => {
+                attributes.push(("type".to_string(), JValue::String("clamp".to_string())));
+                attributes.push(("min".to_string(), JValue::Number(min.to_string())));
+                attributes.push(("max".to_string(), JValue::Number(max.to_string())));
+            }
             FilterValue::Count => {
                 attributes.push(("type".to_string(), JValue::String("count".to_string())));
             }
+            FilterValue::CountMatches { pattern, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("countMatches".to_string()),
+                ));
+                attributes.push(("expr".to_string(), pattern.to_json()));
+            }
             FilterValue::DaysAfterNow => {
                 attributes.push((
                     "type".to_string(),
@@ -723,18 +848,62 @@ impl ToJson for FilterValue {
                 attributes.push(("type".to_string(), JValue::String("decode".to_string())));
                 attributes.push(("encoding".to_string(), JValue::String(encoding.to_string())));
             }
+            FilterValue::Diff { other, .. } => {
+                attributes.push(("type".to_string(), JValue::String("diff".to_string())));
+                attributes.push(("other".to_string(), other.to_json()));
+            }
             FilterValue::Format { fmt, .. } => {
                 attributes.push(("type".to_string(), JValue::String("format".to_string())));
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
-            FilterValue::JsonPath { expr, .. } => {
+            FilterValue::JsonPath {
+                expr,
+                expected_type,
+                ..
+            } => {
                 attributes.push(("type".to_string(), JValue::String("jsonpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+                if let Some((_, _, name)) = expected_type {
+                    attributes.push(("expected_type".to_string(), JValue::String(name.clone())));
+                }
+            }
+            FilterValue::JsonPathPointers { expr, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("jsonpathPointers".to_string()),
+                ));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::JsonMerge { other, .. } => {
+                attributes.push(("type".to_string(), JValue::String("jsonMerge".to_string())));
+                attributes.push(("other".to_string(), other.to_json()));
+            }
+            FilterValue::Ndjson => {
+                attributes.push(("type".to_string(), JValue::String("ndjson".to_string())));
+            }
+            FilterValue::RetryAfterSeconds => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("retryAfterSeconds".to_string()),
+                ));
+            }
+            FilterValue::NormalizeWhitespace => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("normalizeWhitespace".to_string()),
+                ));
             }
             FilterValue::Nth { n, .. } => {
                 attributes.push(("type".to_string(), JValue::String("nth".to_string())));
                 attributes.push(("n".to_string(), JValue::Number(n.to_string())));
             }
+            FilterValue::Optional => {
+                attributes.push(("type".to_string(), JValue::String("optional".to_string())));
+            }
+            FilterValue::ParseCsv { has_header, .. } => {
+                attributes.push(("type".to_string(), JValue::String("parseCsv".to_string())));
+                attributes.push(("has_header".to_string(), JValue::Boolean(*has_header)));
+            }
             FilterValue::HtmlEscape => {
                 attributes.push(("type".to_string(), JValue::String("htmlEscape".to_string())));
             }
@@ -744,10 +913,34 @@ impl ToJson for FilterValue {
                     JValue::String("htmlUnescape".to_string()),
                 ));
             }
+            FilterValue::JsonEscape => {
+                attributes.push(("type".to_string(), JValue::String("jsonEscape".to_string())));
+            }
+            FilterValue::JsonQuote => {
+                attributes.push(("type".to_string(), JValue::String("jsonQuote".to_string())));
+            }
+            FilterValue::JsonUnescape => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("jsonUnescape".to_string()),
+                ));
+            }
+            FilterValue::PercentOf { total, .. } => {
+                attributes.push(("type".to_string(), JValue::String("percentOf".to_string())));
+                attributes.push(("total".to_string(), total.to_json()));
+            }
             FilterValue::Regex { value, .. } => {
                 attributes.push(("type".to_string(), JValue::String("regex".to_string())));
                 attributes.push(("expr".to_string(), value.to_json()));
             }
+            FilterValue::RegexAll { value, .. } => {
+                attributes.push(("type".to_string(), JValue::String("regexAll".to_string())));
+                attributes.push(("expr".to_string(), value.to_json()));
+            }
+            FilterValue::Repeat { count, .. } => {
+                attributes.push(("type".to_string(), JValue::String("repeat".to_string())));
+                attributes.push(("count".to_string(), JValue::Number(count.to_string())));
+            }
             FilterValue::Replace {
                 old_value,
                 new_value,
@@ -760,29 +953,137 @@ impl ToJson for FilterValue {
                     JValue::String(new_value.to_string()),
                 ));
             }
-            FilterValue::UrlEncode => {
+            FilterValue::ReplaceLiteral {
+                old_value,
+                new_value,
+                ..
+            } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("replaceLiteral".to_string()),
+                ));
+                attributes.push((
+                    "old_value".to_string(),
+                    JValue::String(old_value.to_string()),
+                ));
+                attributes.push((
+                    "new_value".to_string(),
+                    JValue::String(new_value.to_string()),
+                ));
+            }
+            FilterValue::UrlEncode { mode } => {
                 attributes.push(("type".to_string(), JValue::String("urlEncode".to_string())));
+                if let Some((_, mode)) = mode {
+                    attributes.push(("mode".to_string(), JValue::String(mode.clone())));
+                }
             }
-            FilterValue::UrlDecode => {
+            FilterValue::UrlDecode { mode } => {
                 attributes.push(("type".to_string(), JValue::String("urlDecode".to_string())));
+                if let Some((_, mode)) = mode {
+                    attributes.push(("mode".to_string(), JValue::String(mode.clone())));
+                }
+            }
+            FilterValue::UrlHost => {
+                attributes.push(("type".to_string(), JValue::String("urlHost".to_string())));
+            }
+            FilterValue::UrlPath => {
+                attributes.push(("type".to_string(), JValue::String("urlPath".to_string())));
+            }
+            FilterValue::UrlQuery => {
+                attributes.push(("type".to_string(), JValue::String("urlQuery".to_string())));
+            }
+            FilterValue::UrlScheme => {
+                attributes.push(("type".to_string(), JValue::String("urlScheme".to_string())));
+            }
+            FilterValue::XmlToJson => {
+                attributes.push(("type".to_string(), JValue::String("xmlToJson".to_string())));
+            }
+            FilterValue::Join { sep, .. } => {
+                attributes.push(("type".to_string(), JValue::String("join".to_string())));
+                attributes.push(("sep".to_string(), JValue::String(sep.to_string())));
             }
             FilterValue::Split { sep, .. } => {
                 attributes.push(("type".to_string(), JValue::String("split".to_string())));
                 attributes.push(("sep".to_string(), JValue::String(sep.to_string())));
             }
+            FilterValue::StripPrefix { prefix, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("stripPrefix".to_string()),
+                ));
+                attributes.push(("prefix".to_string(), JValue::String(prefix.to_string())));
+            }
+            FilterValue::StripSuffix { suffix, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("stripSuffix".to_string()),
+                ));
+                attributes.push(("suffix".to_string(), JValue::String(suffix.to_string())));
+            }
+            FilterValue::TitleCase => {
+                attributes.push(("type".to_string(), JValue::String("titleCase".to_string())));
+            }
+            FilterValue::Base64UrlSafeDecode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64UrlSafeDecode".to_string()),
+                ));
+            }
+            FilterValue::Base64UrlSafeEncode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64UrlSafeEncode".to_string()),
+                ));
+            }
+            FilterValue::CamelCase => {
+                attributes.push(("type".to_string(), JValue::String("camelCase".to_string())));
+            }
+            FilterValue::KebabCase => {
+                attributes.push(("type".to_string(), JValue::String("kebabCase".to_string())));
+            }
+            FilterValue::SnakeCase => {
+                attributes.push(("type".to_string(), JValue::String("snakeCase".to_string())));
+            }
             FilterValue::ToDate { fmt, .. } => {
                 attributes.push(("type".to_string(), JValue::String("toDate".to_string())));
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
+            FilterValue::ToBool => {
+                attributes.push(("type".to_string(), JValue::String("toBool".to_string())));
+            }
             FilterValue::ToFloat => {
                 attributes.push(("type".to_string(), JValue::String("toFloat".to_string())));
             }
             FilterValue::ToInt => {
                 attributes.push(("type".to_string(), JValue::String("toInt".to_string())));
             }
-            FilterValue::XPath { expr, .. } => {
+            FilterValue::Type => {
+                attributes.push(("type".to_string(), JValue::String("type".to_string())));
+            }
+            FilterValue::XPath {
+                expr, namespaces, ..
+            } => {
                 attributes.push(("type".to_string(), JValue::String("xpath".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+                if let Some((_, _, namespaces)) = namespaces {
+                    attributes.push(("namespaces".to_string(), namespaces.to_json()));
+                }
+            }
+            FilterValue::XPathAttr { expr, attr, .. } => {
+                attributes.push(("type".to_string(), JValue::String("xpathAttr".to_string())));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+                attributes.push(("attr".to_string(), JValue::String(attr.to_string())));
+            }
+            FilterValue::XPathOuterHtml { expr, .. } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("xpathOuterHtml".to_string()),
+                ));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::XPathXml { expr, .. } => {
+                attributes.push(("type".to_string(), JValue::String("xpathXml".to_string())));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
         }
         JValue::Object(attributes)
@@ -801,6 +1102,15 @@ impl ToJson for Comment {
     }
 }
 
+impl ToJson for NumberValue {
+    fn to_json(&self) -> JValue {
+        match self {
+            NumberValue::Literal(value) => JValue::Number(value.to_string()),
+            NumberValue::Placeholder(placeholder) => placeholder.to_json(),
+        }
+    }
+}
+
 impl ToJson for NaturalOption {
     fn to_json(&self) -> JValue {
         match self {
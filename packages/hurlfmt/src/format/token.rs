@@ -21,7 +21,7 @@ use hurl_core::ast::{
     Expr, ExprKind, File, FileParam, FileValue, Filter, FilterValue, Function, GraphQl,
     GraphQlVariables, Hex, HurlFile, JsonListElement, JsonObjectElement, JsonValue, KeyValue,
     LineTerminator, Method, MultilineString, MultilineStringAttribute, MultilineStringKind,
-    MultipartParam, NaturalOption, OptionKind, Placeholder, Predicate, PredicateFunc,
+    MultipartParam, NaturalOption, NumberValue, OptionKind, Placeholder, Predicate, PredicateFunc,
     PredicateFuncValue, PredicateValue, Query, QueryValue, Regex, RegexValue, Request, Response,
     Section, SectionValue, Status, StatusValue, Template, TemplateElement, Text, Variable,
     VariableDefinition, VariableValue, Version, Whitespace,
@@ -422,6 +422,11 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut name.tokenize());
             }
+            QueryValue::Headers { space0, name } => {
+                tokens.push(Token::QueryType(String::from("headers")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+            }
             QueryValue::Cookie { space0, expr } => {
                 tokens.push(Token::QueryType(String::from("cookie")));
                 tokens.append(&mut space0.tokenize());
@@ -452,6 +457,25 @@ impl Tokenizable for QueryValue {
             }
             QueryValue::Duration => tokens.push(Token::QueryType(String::from("duration"))),
             QueryValue::Bytes => tokens.push(Token::QueryType(String::from("bytes"))),
+            QueryValue::RawBytes => tokens.push(Token::QueryType(String::from("rawBytes"))),
+            QueryValue::DetectedContentType => {
+                tokens.push(Token::QueryType(String::from("detectedContentType")));
+            }
+            QueryValue::TransferEncoding => {
+                tokens.push(Token::QueryType(String::from("transferEncoding")));
+            }
+            QueryValue::RawResponse => tokens.push(Token::QueryType(String::from("rawResponse"))),
+            QueryValue::RequestBody => tokens.push(Token::QueryType(String::from("requestBody"))),
+            QueryValue::RequestHeader { space0, name } => {
+                tokens.push(Token::QueryType(String::from("requestHeader")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+            }
+            QueryValue::QueryParam { space0, name } => {
+                tokens.push(Token::QueryType(String::from("queryParam")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+            }
             QueryValue::Sha256 => tokens.push(Token::QueryType(String::from("sha256"))),
             QueryValue::Md5 => tokens.push(Token::QueryType(String::from("md5"))),
             QueryValue::Certificate {
@@ -582,6 +606,11 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::ContainsSubset { space0, object } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut object.tokenize());
+            }
             PredicateFuncValue::Include { space0, value } => {
                 tokens.push(Token::PredicateType(self.name()));
                 tokens.append(&mut space0.tokenize());
@@ -592,6 +621,52 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::MatchGlob { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::AnyLineMatch { space0, value } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+            }
+            PredicateFuncValue::MatchesAll { patterns } => {
+                tokens.push(Token::PredicateType(self.name()));
+                for (space, value) in patterns {
+                    tokens.append(&mut space.tokenize());
+                    tokens.append(&mut value.tokenize());
+                }
+            }
+            PredicateFuncValue::HasLength { space0, n } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(n.to_string()));
+            }
+            PredicateFuncValue::IsInRange {
+                space0,
+                min,
+                space1,
+                max,
+            } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut min.tokenize());
+                tokens.append(&mut space1.tokenize());
+                tokens.append(&mut max.tokenize());
+            }
+            PredicateFuncValue::IsCloseTo {
+                space0,
+                value,
+                space1,
+                epsilon,
+            } => {
+                tokens.push(Token::PredicateType(self.name()));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+                tokens.append(&mut space1.tokenize());
+                tokens.append(&mut epsilon.tokenize());
+            }
 
             PredicateFuncValue::IsInteger => {
                 tokens.push(Token::PredicateType(self.name()));
@@ -623,6 +698,21 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsNumber => {
                 tokens.push(Token::PredicateType(self.name()));
             }
+            PredicateFuncValue::IsNull => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsNotNull => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsTrue => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsFalse => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsCookieSecureCompliant => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
         }
         tokens
     }
@@ -806,8 +896,35 @@ impl Tokenizable for Variable {
 impl Tokenizable for Function {
     fn tokenize(&self) -> Vec<Token> {
         match self {
+            Function::GetEnv {
+                space0,
+                name,
+                default,
+            } => {
+                let mut tokens = vec![Token::CodeVariable("getEnv".to_string())];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::CodeVariable(name.clone()));
+                if let Some((space1, default)) = default {
+                    tokens.append(&mut space1.tokenize());
+                    tokens.push(Token::String(default.clone()));
+                }
+                tokens
+            }
             Function::NewDate => vec![Token::CodeVariable("newDate".to_string())],
             Function::NewUuid => vec![Token::CodeVariable("newUuid".to_string())],
+            Function::RandomInt {
+                space0,
+                min,
+                space1,
+                max,
+            } => {
+                let mut tokens = vec![Token::CodeVariable("randomInt".to_string())];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(min.to_string()));
+                tokens.append(&mut space1.tokenize());
+                tokens.push(Token::Number(max.to_string()));
+                tokens
+            }
         }
     }
 }
@@ -991,6 +1108,15 @@ impl Tokenizable for BooleanOption {
     }
 }
 
+impl Tokenizable for NumberValue {
+    fn tokenize(&self) -> Vec<Token> {
+        match self {
+            NumberValue::Literal(value) => vec![Token::Number(value.to_string())],
+            NumberValue::Placeholder(expr) => expr.tokenize(),
+        }
+    }
+}
+
 impl Tokenizable for NaturalOption {
     fn tokenize(&self) -> Vec<Token> {
         match self {
@@ -1062,7 +1188,27 @@ impl Tokenizable for VariableValue {
 impl Tokenizable for Filter {
     fn tokenize(&self) -> Vec<Token> {
         match self.value.clone() {
+            FilterValue::BytesLength => vec![Token::FilterType(String::from("bytesLength"))],
+            FilterValue::Clamp {
+                space0,
+                min,
+                space1,
+                max,
+            } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("clamp"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(min.to_string()));
+                tokens.append(&mut space1.tokenize());
+                tokens.push(Token::Number(max.to_string()));
+                tokens
+            }
             FilterValue::Count => vec![Token::FilterType(String::from("count"))],
+            FilterValue::CountMatches { space0, pattern } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("countMatches"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut pattern.tokenize());
+                tokens
+            }
             FilterValue::DaysAfterNow => vec![Token::FilterType(String::from("daysAfterNow"))],
             FilterValue::DaysBeforeNow => vec![Token::FilterType(String::from("daysBeforeNow"))],
             FilterValue::Decode { space0, encoding } => {
@@ -1071,6 +1217,12 @@ impl Tokenizable for Filter {
                 tokens.append(&mut encoding.tokenize());
                 tokens
             }
+            FilterValue::Diff { space0, other } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("diff"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut other.tokenize());
+                tokens
+            }
             FilterValue::Format { space0, fmt } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("format"))];
                 tokens.append(&mut space0.tokenize());
@@ -1081,24 +1233,82 @@ impl Tokenizable for Filter {
             FilterValue::HtmlUnescape => {
                 vec![Token::FilterType(String::from("htmlUnescape"))]
             }
-            FilterValue::JsonPath { space0, expr } => {
+            FilterValue::JsonEscape => vec![Token::FilterType(String::from("jsonEscape"))],
+            FilterValue::JsonQuote => vec![Token::FilterType(String::from("jsonQuote"))],
+            FilterValue::JsonUnescape => vec![Token::FilterType(String::from("jsonUnescape"))],
+            FilterValue::JsonPath {
+                space0,
+                expr,
+                expected_type,
+            } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("jsonpath"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut expr.tokenize());
+                if let Some((space1, space2, name)) = expected_type {
+                    tokens.append(&mut space1.tokenize());
+                    tokens.push(Token::Keyword(String::from("as")));
+                    tokens.append(&mut space2.tokenize());
+                    tokens.push(Token::Keyword(name.clone()));
+                }
+                tokens
+            }
+            FilterValue::JsonPathPointers { space0, expr } => {
+                let mut tokens: Vec<Token> =
+                    vec![Token::FilterType(String::from("jsonpathPointers"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+                tokens
+            }
+            FilterValue::JsonMerge { space0, other } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("jsonMerge"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut other.tokenize());
                 tokens
             }
+            FilterValue::Ndjson => vec![Token::FilterType(String::from("ndjson"))],
+            FilterValue::RetryAfterSeconds => {
+                vec![Token::FilterType(String::from("retryAfterSeconds"))]
+            }
+            FilterValue::NormalizeWhitespace => {
+                vec![Token::FilterType(String::from("normalizeWhitespace"))]
+            }
             FilterValue::Nth { space0, n } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("nth"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.push(Token::Number(n.to_string()));
                 tokens
             }
+            FilterValue::Optional => vec![Token::FilterType(String::from("?"))],
+            FilterValue::ParseCsv { space0, has_header } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("parseCsv"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Boolean(has_header.to_string()));
+                tokens
+            }
+            FilterValue::PercentOf { space0, total } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("percentOf"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut total.tokenize());
+                tokens
+            }
             FilterValue::Regex { space0, value } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("regex"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
                 tokens
             }
+            FilterValue::RegexAll { space0, value } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("regexAll"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut value.tokenize());
+                tokens
+            }
+            FilterValue::Repeat { space0, count } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("repeat"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(count.to_string()));
+                tokens
+            }
             FilterValue::Replace {
                 space0,
                 old_value,
@@ -1112,14 +1322,76 @@ impl Tokenizable for Filter {
                 tokens.append(&mut new_value.tokenize());
                 tokens
             }
-            FilterValue::UrlEncode => vec![Token::FilterType(String::from("urlEncode"))],
-            FilterValue::UrlDecode => vec![Token::FilterType(String::from("urlDecode"))],
+            FilterValue::ReplaceLiteral {
+                space0,
+                old_value,
+                space1,
+                new_value,
+            } => {
+                let mut tokens: Vec<Token> =
+                    vec![Token::FilterType(String::from("replaceLiteral"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut old_value.tokenize());
+                tokens.append(&mut space1.tokenize());
+                tokens.append(&mut new_value.tokenize());
+                tokens
+            }
+            FilterValue::UrlEncode { mode } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("urlEncode"))];
+                if let Some((space0, mode)) = mode {
+                    tokens.append(&mut space0.tokenize());
+                    tokens.push(Token::Keyword(mode.clone()));
+                }
+                tokens
+            }
+            FilterValue::XmlToJson => vec![Token::FilterType(String::from("xmlToJson"))],
+            FilterValue::UrlDecode { mode } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("urlDecode"))];
+                if let Some((space0, mode)) = mode {
+                    tokens.append(&mut space0.tokenize());
+                    tokens.push(Token::Keyword(mode.clone()));
+                }
+                tokens
+            }
+            FilterValue::UrlHost => vec![Token::FilterType(String::from("urlHost"))],
+            FilterValue::UrlPath => vec![Token::FilterType(String::from("urlPath"))],
+            FilterValue::UrlQuery => vec![Token::FilterType(String::from("urlQuery"))],
+            FilterValue::UrlScheme => vec![Token::FilterType(String::from("urlScheme"))],
+            FilterValue::Join { space0, sep } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("join"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut sep.tokenize());
+                tokens
+            }
             FilterValue::Split { space0, sep } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("split"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut sep.tokenize());
                 tokens
             }
+            FilterValue::StripPrefix { space0, prefix } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("stripPrefix"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut prefix.tokenize());
+                tokens
+            }
+            FilterValue::StripSuffix { space0, suffix } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("stripSuffix"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut suffix.tokenize());
+                tokens
+            }
+            FilterValue::TitleCase => vec![Token::FilterType(String::from("titleCase"))],
+            FilterValue::CamelCase => vec![Token::FilterType(String::from("camelCase"))],
+            FilterValue::Base64UrlSafeDecode => {
+                vec![Token::FilterType(String::from("base64UrlSafeDecode"))]
+            }
+            FilterValue::Base64UrlSafeEncode => {
+                vec![Token::FilterType(String::from("base64UrlSafeEncode"))]
+            }
+            FilterValue::KebabCase => vec![Token::FilterType(String::from("kebabCase"))],
+            FilterValue::SnakeCase => vec![Token::FilterType(String::from("snakeCase"))],
+            FilterValue::ToBool => vec![Token::FilterType(String::from("toBool"))],
             FilterValue::ToDate { space0, fmt } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("toDate"))];
                 tokens.append(&mut space0.tokenize());
@@ -1128,10 +1400,47 @@ impl Tokenizable for Filter {
             }
             FilterValue::ToFloat => vec![Token::FilterType(String::from("toFloat"))],
             FilterValue::ToInt => vec![Token::FilterType(String::from("toInt"))],
-            FilterValue::XPath { space0, expr } => {
+            FilterValue::Type => vec![Token::FilterType(String::from("type"))],
+            FilterValue::XPath {
+                space0,
+                expr,
+                namespaces,
+            } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("xpath"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut expr.tokenize());
+                if let Some((space1, space2, namespaces)) = namespaces {
+                    tokens.append(&mut space1.tokenize());
+                    tokens.push(Token::Keyword(String::from("namespaces")));
+                    tokens.append(&mut space2.tokenize());
+                    tokens.append(&mut namespaces.tokenize());
+                }
+                tokens
+            }
+            FilterValue::XPathAttr {
+                space0,
+                expr,
+                space1,
+                attr,
+            } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("xpathAttr"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+                tokens.append(&mut space1.tokenize());
+                tokens.append(&mut attr.tokenize());
+                tokens
+            }
+            FilterValue::XPathOuterHtml { space0, expr } => {
+                let mut tokens: Vec<Token> =
+                    vec![Token::FilterType(String::from("xpathOuterHtml"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+                tokens
+            }
+            FilterValue::XPathXml { space0, expr } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("xpathXml"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
                 tokens
             }
         }
@@ -260,6 +260,10 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
     match query_value {
         QueryValue::Status => QueryValue::Status,
         QueryValue::Url => QueryValue::Url,
+        QueryValue::Headers { name, .. } => QueryValue::Headers {
+            name: name.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Header { name, .. } => QueryValue::Header {
             name: name.clone(),
             space0: one_whitespace(),
@@ -296,6 +300,19 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
         },
         QueryValue::Duration => QueryValue::Duration,
         QueryValue::Bytes => QueryValue::Bytes,
+        QueryValue::RawBytes => QueryValue::RawBytes,
+        QueryValue::DetectedContentType => QueryValue::DetectedContentType,
+        QueryValue::TransferEncoding => QueryValue::TransferEncoding,
+        QueryValue::RawResponse => QueryValue::RawResponse,
+        QueryValue::RequestBody => QueryValue::RequestBody,
+        QueryValue::RequestHeader { name, .. } => QueryValue::RequestHeader {
+            name: name.clone(),
+            space0: one_whitespace(),
+        },
+        QueryValue::QueryParam { name, .. } => QueryValue::QueryParam {
+            name: name.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Sha256 => QueryValue::Sha256,
         QueryValue::Md5 => QueryValue::Md5,
         QueryValue::Certificate {
@@ -397,6 +414,11 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             value: lint_predicate_value(value),
         },
 
+        PredicateFuncValue::ContainsSubset { object, .. } => PredicateFuncValue::ContainsSubset {
+            space0: one_whitespace(),
+            object: object.clone(),
+        },
+
         PredicateFuncValue::Include { value, .. } => PredicateFuncValue::Include {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -406,6 +428,36 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::MatchGlob { value, .. } => PredicateFuncValue::MatchGlob {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
+        PredicateFuncValue::AnyLineMatch { value, .. } => PredicateFuncValue::AnyLineMatch {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+        },
+        PredicateFuncValue::MatchesAll { patterns } => PredicateFuncValue::MatchesAll {
+            patterns: patterns
+                .iter()
+                .map(|(_, value)| (one_whitespace(), lint_predicate_value(value)))
+                .collect(),
+        },
+        PredicateFuncValue::HasLength { n, .. } => PredicateFuncValue::HasLength {
+            space0: one_whitespace(),
+            n: *n,
+        },
+        PredicateFuncValue::IsInRange { min, max, .. } => PredicateFuncValue::IsInRange {
+            space0: one_whitespace(),
+            min: lint_predicate_value(min),
+            space1: one_whitespace(),
+            max: lint_predicate_value(max),
+        },
+        PredicateFuncValue::IsCloseTo { value, epsilon, .. } => PredicateFuncValue::IsCloseTo {
+            space0: one_whitespace(),
+            value: lint_predicate_value(value),
+            space1: one_whitespace(),
+            epsilon: lint_predicate_value(epsilon),
+        },
         PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -424,6 +476,11 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
         PredicateFuncValue::Exist => PredicateFuncValue::Exist,
         PredicateFuncValue::IsEmpty => PredicateFuncValue::IsEmpty,
         PredicateFuncValue::IsNumber => PredicateFuncValue::IsNumber,
+        PredicateFuncValue::IsNull => PredicateFuncValue::IsNull,
+        PredicateFuncValue::IsNotNull => PredicateFuncValue::IsNotNull,
+        PredicateFuncValue::IsTrue => PredicateFuncValue::IsTrue,
+        PredicateFuncValue::IsFalse => PredicateFuncValue::IsFalse,
+        PredicateFuncValue::IsCookieSecureCompliant => PredicateFuncValue::IsCookieSecureCompliant,
     }
 }
 
@@ -726,10 +783,18 @@ fn lint_filter(filter: &Filter) -> Filter {
 
 fn lint_filter_value(filter_value: &FilterValue) -> FilterValue {
     match filter_value {
+        FilterValue::CountMatches { pattern, .. } => FilterValue::CountMatches {
+            space0: one_whitespace(),
+            pattern: lint_regex_value(pattern),
+        },
         FilterValue::Regex { value, .. } => FilterValue::Regex {
             space0: one_whitespace(),
             value: lint_regex_value(value),
         },
+        FilterValue::RegexAll { value, .. } => FilterValue::RegexAll {
+            space0: one_whitespace(),
+            value: lint_regex_value(value),
+        },
         f => f.clone(),
     }
 }